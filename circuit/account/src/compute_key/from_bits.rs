@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(console)]
+impl<A: Aleo> FromBits for ComputeKey<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new compute key from a list of **little-endian** bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let field_size_in_bits = console::Field::<A::Network>::size_in_bits();
+        if bits_le.len() != 2 * field_size_in_bits {
+            A::halt(format!("Expected {} bits for a compute key, found {}", 2 * field_size_in_bits, bits_le.len()))
+        }
+        // Recover `pk_sig` and `pr_sig` from their x-coordinates.
+        let pk_sig = Group::from_bits_le(&bits_le[..field_size_in_bits]);
+        let pr_sig = Group::from_bits_le(&bits_le[field_size_in_bits..]);
+        // Compute `sk_prf` := HashToScalar(G^sk_sig || G^r_sig).
+        let sk_prf = A::hash_to_scalar_psd4(&[pk_sig.to_x_coordinate(), pr_sig.to_x_coordinate()]);
+        // Output the compute key.
+        Self { pk_sig, pr_sig, sk_prf }
+    }
+
+    /// Initializes a new compute key from a list of **big-endian** bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let field_size_in_bits = console::Field::<A::Network>::size_in_bits();
+        if bits_be.len() != 2 * field_size_in_bits {
+            A::halt(format!("Expected {} bits for a compute key, found {}", 2 * field_size_in_bits, bits_be.len()))
+        }
+        // Recover `pk_sig` and `pr_sig` from their x-coordinates.
+        let pk_sig = Group::from_bits_be(&bits_be[..field_size_in_bits]);
+        let pr_sig = Group::from_bits_be(&bits_be[field_size_in_bits..]);
+        // Compute `sk_prf` := HashToScalar(G^sk_sig || G^r_sig).
+        let sk_prf = A::hash_to_scalar_psd4(&[pk_sig.to_x_coordinate(), pr_sig.to_x_coordinate()]);
+        // Output the compute key.
+        Self { pk_sig, pr_sig, sk_prf }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_from_bits(mode: Mode) -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (_private_key, compute_key, _view_key, _address) = generate_account()?;
+
+            // Initialize the compute key.
+            let candidate = ComputeKey::<Circuit>::new(mode, compute_key);
+            // Check the little-endian and big-endian bit representations.
+            assert_eq!(compute_key, ComputeKey::from_bits_le(&candidate.to_bits_le()).eject_value());
+            assert_eq!(compute_key, ComputeKey::from_bits_be(&candidate.to_bits_be()).eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bits_constant() -> Result<()> {
+        check_from_bits(Mode::Constant)
+    }
+
+    #[test]
+    fn test_from_bits_public() -> Result<()> {
+        check_from_bits(Mode::Public)
+    }
+
+    #[test]
+    fn test_from_bits_private() -> Result<()> {
+        check_from_bits(Mode::Private)
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> ToBits for ComputeKey<A> {
+    type Boolean = Boolean<A>;
+
+    /// Returns the little-endian bits of the compute key.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        // Write the `pk_sig` and `pr_sig` x-coordinates.
+        let mut bits_le = self.pk_sig.to_x_coordinate().to_bits_le();
+        bits_le.extend(self.pr_sig.to_x_coordinate().to_bits_le());
+        bits_le
+    }
+
+    /// Returns the big-endian bits of the compute key.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        // Write the `pk_sig` and `pr_sig` x-coordinates.
+        let mut bits_be = self.pk_sig.to_x_coordinate().to_bits_be();
+        bits_be.extend(self.pr_sig.to_x_coordinate().to_bits_be());
+        bits_be
+    }
+}
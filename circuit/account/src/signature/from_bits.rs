@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(console)]
+impl<A: Aleo> FromBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new signature from a list of **little-endian** bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let size_in_bits = console::Signature::<A::Network>::size_in_bits();
+        if bits_le.len() != size_in_bits {
+            A::halt(format!("Expected {size_in_bits} bits for a signature, found {}", bits_le.len()))
+        }
+        // Split the bits into the challenge, response, and compute key.
+        let scalar_size_in_bits = console::Scalar::<A::Network>::size_in_bits();
+        let (challenge_bits, bits_le) = bits_le.split_at(scalar_size_in_bits);
+        let (response_bits, compute_key_bits) = bits_le.split_at(scalar_size_in_bits);
+        // Output the signature.
+        Self {
+            challenge: Scalar::from_bits_le(challenge_bits),
+            response: Scalar::from_bits_le(response_bits),
+            compute_key: ComputeKey::from_bits_le(compute_key_bits),
+        }
+    }
+
+    /// Initializes a new signature from a list of **big-endian** bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let size_in_bits = console::Signature::<A::Network>::size_in_bits();
+        if bits_be.len() != size_in_bits {
+            A::halt(format!("Expected {size_in_bits} bits for a signature, found {}", bits_be.len()))
+        }
+        // Split the bits into the challenge, response, and compute key.
+        let scalar_size_in_bits = console::Scalar::<A::Network>::size_in_bits();
+        let (challenge_bits, bits_be) = bits_be.split_at(scalar_size_in_bits);
+        let (response_bits, compute_key_bits) = bits_be.split_at(scalar_size_in_bits);
+        // Output the signature.
+        Self {
+            challenge: Scalar::from_bits_be(challenge_bits),
+            response: Scalar::from_bits_be(response_bits),
+            compute_key: ComputeKey::from_bits_be(compute_key_bits),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_from_bits(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Generate a private key, compute key, view key, and address.
+        let (private_key, _compute_key, _view_key, _address) = generate_account()?;
+
+        for i in 0..ITERATIONS {
+            // Generate a signature.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = console::Signature::sign(&private_key, &message, rng)?;
+
+            // Initialize the signature.
+            let candidate = Signature::<Circuit>::new(mode, signature);
+            // Check the little-endian and big-endian bit representations.
+            assert_eq!(signature, Signature::from_bits_le(&candidate.to_bits_le()).eject_value());
+            assert_eq!(signature, Signature::from_bits_be(&candidate.to_bits_be()).eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bits_constant() -> Result<()> {
+        check_from_bits(Mode::Constant)
+    }
+
+    #[test]
+    fn test_from_bits_public() -> Result<()> {
+        check_from_bits(Mode::Public)
+    }
+
+    #[test]
+    fn test_from_bits_private() -> Result<()> {
+        check_from_bits(Mode::Private)
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Hash for Keccak<E, TYPE, VARIANT> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the Keccak hash of the given input as a list of `VARIANT` bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Ensure the output fits within a single squeeze of the sponge.
+        if VARIANT == 0 || VARIANT > Self::rate() {
+            E::halt(format!("Invalid Keccak variant: {VARIANT}"))
+        }
+
+        // Absorb the padded input into the state.
+        let mut state = vec![U64::zero(); NUM_LANES];
+        for block in Self::pad(input).chunks(Self::rate()) {
+            for (lane, bits_le) in state.iter_mut().zip(block.chunks(64)) {
+                *lane = &*lane ^ &U64::from_bits_le(bits_le);
+            }
+            self.permute(&mut state);
+        }
+
+        // Squeeze the output from the state.
+        state.iter().flat_map(|lane| lane.to_bits_le()).take(VARIANT).collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: usize = 3;
+
+    fn check_hash<const TYPE: u8>(
+        mode: Mode,
+        num_inputs: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Keccak::<TYPE, 256>::default();
+        let keccak = Keccak::<Circuit, TYPE, 256>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Keccak {mode} {i}"), || {
+                let candidate = keccak.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
+                assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_keccak256_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Constant, 1, 1600, 0, 0, 0, &mut rng)?;
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Constant, 1088, 3136, 0, 0, 0, &mut rng)
+    }
+
+    #[test]
+    fn test_keccak256_public() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Public, 1, 1600, 0, 138170, 138170, &mut rng)?;
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Public, 1088, 3136, 0, 306176, 306176, &mut rng)
+    }
+
+    #[test]
+    fn test_keccak256_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Private, 1, 1600, 0, 138170, 138170, &mut rng)?;
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Private, 1088, 3136, 0, 306176, 306176, &mut rng)
+    }
+
+    #[test]
+    fn test_sha3_256_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Private, 1, 1600, 0, 138303, 138303, &mut rng)?;
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Private, 1088, 3136, 0, 306176, 306176, &mut rng)
+    }
+}
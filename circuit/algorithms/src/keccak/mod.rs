@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+#[cfg(all(test, console))]
+use snarkvm_circuit_types::environment::assert_scope;
+
+use crate::Hash;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, U64};
+
+/// Keccak256 is the Keccak hash function with a 256-bit output, as used in Ethereum.
+pub type Keccak256<E> = Keccak<E, { KeccakType::Keccak as u8 }, 256>;
+/// SHA3-256 is the Keccak hash function with a 256-bit output, as standardized in FIPS 202.
+pub type Sha3_256<E> = Keccak<E, { KeccakType::Sha3 as u8 }, 256>;
+
+/// The number of lanes in the Keccak-f[1600] state.
+const NUM_LANES: usize = 25;
+/// The width of the Keccak-f[1600] permutation in bits.
+const PERMUTATION_WIDTH: usize = 1600;
+
+/// The round constants for the iota step, one for each round.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets for the rho step, indexed by lane `x + 5 * y`.
+const ROTATIONS: [usize; NUM_LANES] =
+    [0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
+
+/// The padding rule of the Keccak hash function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeccakType {
+    /// The original Keccak padding (i.e. `pad10*1`), as used in Ethereum.
+    Keccak,
+    /// The SHA-3 padding, which prepends the `01` domain suffix to `pad10*1`.
+    Sha3,
+}
+
+/// Keccak is a sponge-based hash function over the Keccak-f[1600] permutation.
+#[derive(Clone)]
+pub struct Keccak<E: Environment, const TYPE: u8, const VARIANT: usize> {
+    /// The round constants for the iota step.
+    round_constants: Vec<U64<E>>,
+}
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Initializes a new instance of Keccak.
+    pub fn new() -> Self {
+        let round_constants = ROUND_CONSTANTS
+            .iter()
+            .map(|constant| {
+                U64::from_bits_le(&(0..64).map(|i| Boolean::constant((constant >> i) & 1 == 1)).collect::<Vec<_>>())
+            })
+            .collect();
+        Self { round_constants }
+    }
+
+    /// Returns the rate of the sponge in bits.
+    const fn rate() -> usize {
+        PERMUTATION_WIDTH - 2 * VARIANT
+    }
+
+    /// Returns the padded input, as defined by the padding rule of `TYPE`.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        let rate = Self::rate();
+
+        let mut padded = Vec::with_capacity(input.len() + 2 * rate);
+        padded.extend_from_slice(input);
+        // Append the domain suffix for SHA-3.
+        if TYPE == KeccakType::Sha3 as u8 {
+            padded.extend([Boolean::constant(false), Boolean::constant(true)]);
+        }
+        // Append the `pad10*1` rule.
+        padded.push(Boolean::constant(true));
+        while (padded.len() + 1) % rate != 0 {
+            padded.push(Boolean::constant(false));
+        }
+        padded.push(Boolean::constant(true));
+        padded
+    }
+
+    /// Applies the Keccak-f[1600] permutation to the given state.
+    fn permute(&self, state: &mut [U64<E>]) {
+        for round_constant in &self.round_constants {
+            // Theta step.
+            let c = (0..5)
+                .map(|x| &state[x] ^ &state[x + 5] ^ &state[x + 10] ^ &state[x + 15] ^ &state[x + 20])
+                .collect::<Vec<_>>();
+            for x in 0..5 {
+                let d = &c[(x + 4) % 5] ^ &Self::rotate_left(&c[(x + 1) % 5], 1);
+                for y in 0..5 {
+                    state[x + 5 * y] = &state[x + 5 * y] ^ &d;
+                }
+            }
+            // Rho and pi steps.
+            let mut b = vec![U64::zero(); NUM_LANES];
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] = Self::rotate_left(&state[x + 5 * y], ROTATIONS[x + 5 * y]);
+                }
+            }
+            // Chi step.
+            for x in 0..5 {
+                for y in 0..5 {
+                    state[x + 5 * y] = &b[x + 5 * y] ^ &(&!&b[(x + 1) % 5 + 5 * y] & &b[(x + 2) % 5 + 5 * y]);
+                }
+            }
+            // Iota step.
+            state[0] = &state[0] ^ round_constant;
+        }
+    }
+
+    /// Returns the given lane rotated left by `n` bits.
+    fn rotate_left(lane: &U64<E>, n: usize) -> U64<E> {
+        let bits_le = lane.to_bits_le();
+        U64::from_bits_le(&[&bits_le[64 - n..], &bits_le[..64 - n]].concat())
+    }
+}
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Default for Keccak<E, TYPE, VARIANT> {
+    /// Initializes a new instance of Keccak.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -21,11 +21,17 @@ pub use bhp::*;
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
+pub mod keccak;
+pub use keccak::*;
+
 pub mod pedersen;
 pub use pedersen::*;
 
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod sha256;
+pub use sha256::*;
+
 pub mod traits;
 pub use traits::*;
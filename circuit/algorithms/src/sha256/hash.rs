@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha256<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-256 hash of the given input as a list of 256 bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Compress each block of the padded input.
+        let mut state = self.initial_state.clone();
+        for block in Self::pad(input).chunks(BLOCK_SIZE_IN_BITS) {
+            self.compress(&mut state, block);
+        }
+
+        // Output the digest in big-endian byte order, with each byte in little-endian bit order.
+        state
+            .iter()
+            .flat_map(|word| {
+                let bits_be = word.to_bits_be();
+                bits_be.chunks(8).flat_map(|byte| byte.iter().rev().cloned().collect::<Vec<_>>()).collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: usize = 3;
+
+    fn check_hash(
+        mode: Mode,
+        num_inputs: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Sha256;
+        let sha256 = Sha256::<Circuit>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("SHA-256 {mode} {i}"), || {
+                let candidate = sha256.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
+                assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash(Mode::Constant, 1, 19200, 0, 0, 0, &mut rng)?;
+        check_hash(Mode::Constant, 512, 38400, 0, 0, 0, &mut rng)
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash(Mode::Public, 1, 288, 0, 36282, 36527, &mut rng)?;
+        check_hash(Mode::Public, 512, 4608, 0, 69120, 69568, &mut rng)
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_hash(Mode::Private, 1, 288, 0, 36282, 36527, &mut rng)?;
+        check_hash(Mode::Private, 512, 4608, 0, 69120, 69568, &mut rng)
+    }
+}
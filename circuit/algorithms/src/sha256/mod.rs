@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+#[cfg(all(test, console))]
+use snarkvm_circuit_types::environment::assert_scope;
+
+use crate::Hash;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U32};
+
+/// The size of a SHA-256 message block in bits.
+const BLOCK_SIZE_IN_BITS: usize = 512;
+
+/// The initial hash values, as defined in FIPS 180-4.
+const INITIAL_STATE: [u32; 8] =
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// The round constants, as defined in FIPS 180-4.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+/// SHA-256 is the Merkle-Damgard hash function with a 256-bit output, as standardized in FIPS 180-4.
+#[derive(Clone)]
+pub struct Sha256<E: Environment> {
+    /// The initial hash values.
+    initial_state: Vec<U32<E>>,
+    /// The round constants.
+    round_constants: Vec<U32<E>>,
+}
+
+impl<E: Environment> Sha256<E> {
+    /// Initializes a new instance of SHA-256.
+    pub fn new() -> Self {
+        Self {
+            initial_state: INITIAL_STATE.iter().map(|word| Self::constant_word(*word)).collect(),
+            round_constants: ROUND_CONSTANTS.iter().map(|word| Self::constant_word(*word)).collect(),
+        }
+    }
+
+    /// Returns the given word as a constant.
+    fn constant_word(word: u32) -> U32<E> {
+        U32::from_bits_le(&(0..32).map(|i| Boolean::constant((word >> i) & 1 == 1)).collect::<Vec<_>>())
+    }
+
+    /// Returns the message bits in big-endian order per byte, followed by the padding of FIPS 180-4.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        let mut padded = Vec::with_capacity(input.len() + 2 * BLOCK_SIZE_IN_BITS);
+        // Reverse each byte of the input into big-endian bit order.
+        for byte in input.chunks(8) {
+            padded.extend(byte.iter().rev().cloned());
+        }
+        // Append a single one bit, followed by zero bits up to the length field.
+        padded.push(Boolean::constant(true));
+        while padded.len() % BLOCK_SIZE_IN_BITS != BLOCK_SIZE_IN_BITS - 64 {
+            padded.push(Boolean::constant(false));
+        }
+        // Append the length of the input in bits, as a big-endian 64-bit integer.
+        padded.extend((0..64).rev().map(|i| Boolean::constant((input.len() as u64 >> i) & 1 == 1)));
+        padded
+    }
+
+    /// Applies the SHA-256 compression function to the given state and message block.
+    fn compress(&self, state: &mut [U32<E>], block: &[Boolean<E>]) {
+        // Prepare the message schedule.
+        let mut w = block.chunks(32).map(U32::from_bits_be).collect::<Vec<_>>();
+        for i in 16..64 {
+            let s0 = &(&Self::rotate_right(&w[i - 15], 7) ^ &Self::rotate_right(&w[i - 15], 18))
+                ^ &Self::shift_right(&w[i - 15], 3);
+            let s1 = &(&Self::rotate_right(&w[i - 2], 17) ^ &Self::rotate_right(&w[i - 2], 19))
+                ^ &Self::shift_right(&w[i - 2], 10);
+            w.push(Self::add_many(&[w[i - 16].clone(), s0, w[i - 7].clone(), s1]));
+        }
+
+        // Compute the rounds.
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h]: [U32<E>; 8] =
+            state.to_vec().try_into().unwrap_or_else(|_| E::halt("Invalid SHA-256 state"));
+        for (round_constant, w) in self.round_constants.iter().zip_eq(&w) {
+            let s1 = &(&Self::rotate_right(&e, 6) ^ &Self::rotate_right(&e, 11)) ^ &Self::rotate_right(&e, 25);
+            let ch = &(&e & &f) ^ &(&!&e & &g);
+            let temp1 = Self::add_many(&[h, s1, ch, round_constant.clone(), w.clone()]);
+            let s0 = &(&Self::rotate_right(&a, 2) ^ &Self::rotate_right(&a, 13)) ^ &Self::rotate_right(&a, 22);
+            let maj = &(&(&a & &b) ^ &(&a & &c)) ^ &(&b & &c);
+
+            h = g;
+            g = f;
+            f = e;
+            e = Self::add_many(&[d, temp1.clone()]);
+            d = c;
+            c = b;
+            b = a;
+            a = Self::add_many(&[temp1, s0, maj]);
+        }
+
+        // Update the state.
+        for (state, value) in state.iter_mut().zip_eq([a, b, c, d, e, f, g, h]) {
+            *state = Self::add_many(&[state.clone(), value]);
+        }
+    }
+
+    /// Returns the sum of the given words, modulo `2^32`.
+    fn add_many(words: &[U32<E>]) -> U32<E> {
+        // If all of the words are constant, sum them directly.
+        if words.iter().all(|word| word.is_constant()) {
+            return words.iter().skip(1).fold(words[0].clone(), |sum, word| sum.add_wrapped(word));
+        }
+        // Instead of adding the words pairwise, the words are summed as field elements,
+        // and the sum is decomposed once into the integer bits and the carry bits.
+        // Note: This is safe as the field is larger than the sum of the words.
+        let sum = words.iter().fold(Field::zero(), |sum, word| sum + word.to_field());
+        // Extract the integer bits from the field element, with the carry bits.
+        let num_carry_bits = (usize::BITS - (words.len() - 1).leading_zeros()) as usize;
+        let bits_le = sum.to_lower_bits_le(32 + num_carry_bits);
+        // Drop the carry bits as the operation is wrapped addition.
+        U32::from_bits_le(&bits_le[..32])
+    }
+
+    /// Returns the given word rotated right by `n` bits.
+    fn rotate_right(word: &U32<E>, n: usize) -> U32<E> {
+        let bits_le = word.to_bits_le();
+        U32::from_bits_le(&[&bits_le[n..], &bits_le[..n]].concat())
+    }
+
+    /// Returns the given word shifted right by `n` bits.
+    fn shift_right(word: &U32<E>, n: usize) -> U32<E> {
+        U32::from_bits_le(&word.to_bits_le()[n..])
+    }
+}
+
+impl<E: Environment> Default for Sha256<E> {
+    /// Initializes a new instance of SHA-256.
+    fn default() -> Self {
+        Self::new()
+    }
+}
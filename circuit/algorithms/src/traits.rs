@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use snarkvm_circuit_types::{
-    environment::{Eject, Inject, ScalarTrait, Ternary, ToBits},
+    environment::{Eject, Inject, ScalarTrait, ToBits},
     GroupTrait,
 };
 
@@ -40,7 +40,7 @@ pub trait CommitUncompressed {
 /// A trait for a hash function.
 pub trait Hash {
     type Input: Inject + Eject + Clone;
-    type Output: Inject + Eject + Ternary<Output = Self::Output> + ToBits + Clone;
+    type Output: Inject + Eject + ToBits + Clone;
 
     /// Returns the hash of the given input.
     fn hash(&self, input: &[Self::Input]) -> Self::Output;
@@ -20,11 +20,14 @@ use snarkvm_circuit_algorithms::{
     HashMany,
     HashToGroup,
     HashToScalar,
+    Keccak256,
     Pedersen128,
     Pedersen64,
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha256,
+    Sha3_256,
     BHP1024,
     BHP256,
     BHP512,
@@ -63,6 +66,9 @@ thread_local! {
     /// The BHP hash function, which can take an input of up to 1024 bits.
     static BHP_1024: BHP1024<AleoV0> = BHP1024::<AleoV0>::constant(console::BHP_1024.clone());
 
+    /// The Keccak hash function, which outputs 256 bits.
+    static KECCAK_256: Keccak256<AleoV0> = Keccak256::<AleoV0>::new();
+
     /// The Pedersen hash function, which can take an input of up to 64 bits.
     static PEDERSEN_64: Pedersen64<AleoV0> = Pedersen64::<AleoV0>::constant(console::PEDERSEN_64.clone());
     /// The Pedersen hash function, which can take an input of up to 128 bits.
@@ -74,6 +80,11 @@ thread_local! {
     static POSEIDON_4: Poseidon4<AleoV0> = Poseidon4::<AleoV0>::constant(console::POSEIDON_4.clone());
     /// The Poseidon hash function, using a rate of 8.
     static POSEIDON_8: Poseidon8<AleoV0> = Poseidon8::<AleoV0>::constant(console::POSEIDON_8.clone());
+
+    /// The SHA-256 hash function, which outputs 256 bits.
+    static SHA_256: Sha256<AleoV0> = Sha256::<AleoV0>::new();
+    /// The SHA3 hash function, which outputs 256 bits.
+    static SHA3_256: Sha3_256<AleoV0> = Sha3_256::<AleoV0>::new();
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -156,6 +167,11 @@ impl Aleo for AleoV0 {
         BHP_1024.with(|bhp| bhp.hash(input))
     }
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        KECCAK_256.with(|keccak| keccak.hash(input))
+    }
+
     /// Returns the Pedersen hash for a given (up to) 64-bit input.
     fn hash_ped64(input: &[Boolean<Self>]) -> Field<Self> {
         PEDERSEN_64.with(|pedersen| pedersen.hash(input))
@@ -181,6 +197,16 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash(input))
     }
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        SHA_256.with(|sha| sha.hash(input))
+    }
+
+    /// Returns the SHA3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        SHA3_256.with(|sha| sha.hash(input))
+    }
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
         POSEIDON_2.with(|poseidon| poseidon.hash_many(input, num_outputs))
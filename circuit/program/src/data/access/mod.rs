@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Identifier;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, U32};
+
+/// A single step in a register access path.
+#[derive(Clone)]
+pub enum Access<A: Aleo> {
+    /// An access to a member of a struct or record.
+    Member(Identifier<A>),
+    /// An access to an element of an array.
+    Index(U32<A>),
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for Access<A> {
+    type Primitive = console::Access<A::Network>;
+
+    /// Initializes a new access circuit from a primitive.
+    fn new(mode: Mode, access: Self::Primitive) -> Self {
+        match access {
+            Self::Primitive::Member(identifier) => Self::Member(Identifier::new(mode, identifier)),
+            Self::Primitive::Index(index) => Self::Index(U32::new(mode, index)),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for Access<A> {
+    type Primitive = console::Access<A::Network>;
+
+    /// Ejects the mode of the access.
+    fn eject_mode(&self) -> Mode {
+        match self {
+            Self::Member(identifier) => identifier.eject_mode(),
+            Self::Index(index) => index.eject_mode(),
+        }
+    }
+
+    /// Ejects the access.
+    fn eject_value(&self) -> Self::Primitive {
+        match self {
+            Self::Member(identifier) => console::Access::Member(identifier.eject_value()),
+            Self::Index(index) => console::Access::Index(index.eject_value()),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Debug for Access<A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Display for Access<A> {
+    /// Prints the access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.eject_value())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use anyhow::Result;
+
+    #[test]
+    fn test_access() -> Result<()> {
+        for string in [".owner", "[0]", "[31]"] {
+            let expected = console::Access::<<Circuit as Environment>::Network>::from_str(string)?;
+            let candidate = Access::<Circuit>::constant(expected);
+            assert_eq!(Mode::Constant, candidate.eject_mode());
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(string, candidate.to_string());
+        }
+        Ok(())
+    }
+}
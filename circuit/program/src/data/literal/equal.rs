@@ -36,6 +36,7 @@ impl<A: Aleo> Equal<Self> for Literal<A> {
             (Self::U128(a), Self::U128(b)) => a.is_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_equal(b),
             (Self::String(a), Self::String(b)) => a.is_equal(b),
+            (Self::Signature(a), Self::Signature(b)) => a.is_equal(b),
             _ => Boolean::constant(false),
         }
     }
@@ -59,6 +60,7 @@ impl<A: Aleo> Equal<Self> for Literal<A> {
             (Self::U128(a), Self::U128(b)) => a.is_not_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_not_equal(b),
             (Self::String(a), Self::String(b)) => a.is_not_equal(b),
+            (Self::Signature(a), Self::Signature(b)) => a.is_not_equal(b),
             _ => Boolean::constant(true),
         }
     }
@@ -50,6 +50,7 @@ impl<A: Aleo> ToBits for &Literal<A> {
             Literal::U128(literal) => literal.to_bits_le(),
             Literal::Scalar(literal) => literal.to_bits_le(),
             Literal::String(literal) => literal.to_bits_le(),
+            Literal::Signature(literal) => literal.to_bits_le(),
         }
     }
 
@@ -72,6 +73,7 @@ impl<A: Aleo> ToBits for &Literal<A> {
             Literal::U128(literal) => literal.to_bits_be(),
             Literal::Scalar(literal) => literal.to_bits_be(),
             Literal::String(literal) => literal.to_bits_be(),
+            Literal::Signature(literal) => literal.to_bits_be(),
         }
     }
 }
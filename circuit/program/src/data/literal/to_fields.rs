@@ -45,6 +45,9 @@ impl<A: Aleo> ToFields for &Literal<A> {
             Literal::U128(literal) => vec![literal.to_field()],
             Literal::Scalar(literal) => vec![literal.to_field()],
             Literal::String(literal) => literal.to_fields(),
+            Literal::Signature(literal) => {
+                literal.to_bits_le().chunks(A::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect()
+            }
         }
     }
 }
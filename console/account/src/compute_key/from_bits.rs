@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBits for ComputeKey<N> {
+    /// Initializes a new compute key from a list of **little-endian** bits.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        let field_size_in_bits = Field::<N>::size_in_bits();
+        ensure!(bits_le.len() == 2 * field_size_in_bits, "Expected {} bits for a compute key", 2 * field_size_in_bits);
+        // Recover `pk_sig` and `pr_sig` from their x-coordinates.
+        let pk_sig = Group::from_x_coordinate(Field::from_bits_le(&bits_le[..field_size_in_bits])?)?;
+        let pr_sig = Group::from_x_coordinate(Field::from_bits_le(&bits_le[field_size_in_bits..])?)?;
+        // Output the compute key.
+        Self::try_from((pk_sig, pr_sig))
+    }
+
+    /// Initializes a new compute key from a list of **big-endian** bits.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        let field_size_in_bits = Field::<N>::size_in_bits();
+        ensure!(bits_be.len() == 2 * field_size_in_bits, "Expected {} bits for a compute key", 2 * field_size_in_bits);
+        // Recover `pk_sig` and `pr_sig` from their x-coordinates.
+        let pk_sig = Group::from_x_coordinate(Field::from_bits_be(&bits_be[..field_size_in_bits])?)?;
+        let pr_sig = Group::from_x_coordinate(Field::from_bits_be(&bits_be[field_size_in_bits..])?)?;
+        // Output the compute key.
+        Self::try_from((pk_sig, pr_sig))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new compute key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let expected = ComputeKey::try_from(private_key)?;
+
+            // Check the little-endian bit representation.
+            let bits_le = expected.to_bits_le();
+            assert_eq!(ComputeKey::<CurrentNetwork>::size_in_bits(), bits_le.len());
+            assert_eq!(expected, ComputeKey::from_bits_le(&bits_le)?);
+
+            // Check the big-endian bit representation.
+            let bits_be = expected.to_bits_be();
+            assert_eq!(ComputeKey::<CurrentNetwork>::size_in_bits(), bits_be.len());
+            assert_eq!(expected, ComputeKey::from_bits_be(&bits_be)?);
+        }
+        Ok(())
+    }
+}
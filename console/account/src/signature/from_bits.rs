@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBits for Signature<N> {
+    /// Initializes a new signature from a list of **little-endian** bits.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        ensure!(bits_le.len() == Self::size_in_bits(), "Expected {} bits for a signature", Self::size_in_bits());
+        // Split the bits into the challenge, response, and compute key.
+        let scalar_size_in_bits = Scalar::<N>::size_in_bits();
+        let (challenge_bits, bits_le) = bits_le.split_at(scalar_size_in_bits);
+        let (response_bits, compute_key_bits) = bits_le.split_at(scalar_size_in_bits);
+        // Output the signature.
+        Ok(Self {
+            challenge: Scalar::from_bits_le(challenge_bits)?,
+            response: Scalar::from_bits_le(response_bits)?,
+            compute_key: ComputeKey::from_bits_le(compute_key_bits)?,
+        })
+    }
+
+    /// Initializes a new signature from a list of **big-endian** bits.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        ensure!(bits_be.len() == Self::size_in_bits(), "Expected {} bits for a signature", Self::size_in_bits());
+        // Split the bits into the challenge, response, and compute key.
+        let scalar_size_in_bits = Scalar::<N>::size_in_bits();
+        let (challenge_bits, bits_be) = bits_be.split_at(scalar_size_in_bits);
+        let (response_bits, compute_key_bits) = bits_be.split_at(scalar_size_in_bits);
+        // Output the signature.
+        Ok(Self {
+            challenge: Scalar::from_bits_be(challenge_bits)?,
+            response: Scalar::from_bits_be(response_bits)?,
+            compute_key: ComputeKey::from_bits_be(compute_key_bits)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new signature.
+            let expected = test_helpers::sample_signature(i, &mut rng);
+
+            // Check the little-endian bit representation.
+            let bits_le = expected.to_bits_le();
+            assert_eq!(Signature::<CurrentNetwork>::size_in_bits(), bits_le.len());
+            assert_eq!(expected, Signature::from_bits_le(&bits_le)?);
+
+            // Check the big-endian bit representation.
+            let bits_be = expected.to_bits_be();
+            assert_eq!(Signature::<CurrentNetwork>::size_in_bits(), bits_be.len());
+            assert_eq!(expected, Signature::from_bits_be(&bits_be)?);
+        }
+        Ok(())
+    }
+}
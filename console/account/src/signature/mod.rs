@@ -13,8 +13,11 @@
 // limitations under the License.
 
 mod bytes;
+mod from_bits;
 mod parse;
 mod serialize;
+mod size_in_bits;
+mod to_bits;
 mod verify;
 
 #[cfg(feature = "private_key")]
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<const TYPE: u8, const VARIANT: usize> Hash for Keccak<TYPE, VARIANT> {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the Keccak hash of the given input as a list of `VARIANT` bits.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Ensure the output fits within a single squeeze of the sponge.
+        ensure!(VARIANT > 0 && VARIANT <= Self::rate(), "Invalid Keccak variant: {VARIANT}");
+
+        // Absorb the padded input into the state.
+        let mut state = [0u64; NUM_LANES];
+        for block in Self::pad(input).chunks(Self::rate()) {
+            for (i, bit) in block.iter().enumerate() {
+                state[i / 64] ^= (*bit as u64) << (i % 64);
+            }
+            Self::permute(&mut state);
+        }
+
+        // Squeeze the output from the state.
+        Ok((0..VARIANT).map(|i| (state[i / 64] >> (i % 64)) & 1 == 1).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the little-endian bits of the given bytes.
+    fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the bytes of the given little-endian bits.
+    fn bits_le_to_bytes(bits: &[bool]) -> Vec<u8> {
+        bits.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8))).collect()
+    }
+
+    fn check_hash<H: Hash<Input = bool, Output = Vec<bool>>>(hasher: &H, input: &[u8], expected: &str) {
+        let output = hasher.hash(&bytes_to_bits_le(input)).unwrap();
+        assert_eq!(256, output.len());
+        assert_eq!(expected, hex::encode(bits_le_to_bytes(&output)));
+    }
+
+    #[test]
+    fn test_keccak256() {
+        let hasher = Keccak256::default();
+        check_hash(&hasher, b"", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+        check_hash(&hasher, b"abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45");
+        check_hash(
+            &hasher,
+            b"The quick brown fox jumps over the lazy dog",
+            "4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15",
+        );
+    }
+
+    #[test]
+    fn test_sha3_256() {
+        let hasher = Sha3_256::default();
+        check_hash(&hasher, b"", "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+        check_hash(&hasher, b"abc", "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+        // Note: This input spans multiple blocks of the sponge.
+        check_hash(&hasher, &[b'a'; 1_000_000], "5c8875ae474a3634ba4fd55ec85bffd661f32aca75c6d699d0cdcb6c115891c1");
+    }
+
+    #[test]
+    fn test_unaligned_input() {
+        let mut rng = TestRng::default();
+
+        // Ensure inputs that are not byte-aligned are hashed without collisions on their padding.
+        for num_bits in 1..16 {
+            let input = (0..num_bits).map(|_| rng.gen()).collect::<Vec<_>>();
+            let mut extended = input.clone();
+            extended.push(false);
+            assert_ne!(Keccak256::default().hash(&input).unwrap(), Keccak256::default().hash(&extended).unwrap());
+            assert_ne!(Sha3_256::default().hash(&input).unwrap(), Sha3_256::default().hash(&extended).unwrap());
+        }
+    }
+}
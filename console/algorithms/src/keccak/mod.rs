@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+use snarkvm_console_types::prelude::*;
+
+/// Keccak256 is the Keccak hash function with a 256-bit output, as used in Ethereum.
+pub type Keccak256 = Keccak<{ KeccakType::Keccak as u8 }, 256>;
+/// SHA3-256 is the Keccak hash function with a 256-bit output, as standardized in FIPS 202.
+pub type Sha3_256 = Keccak<{ KeccakType::Sha3 as u8 }, 256>;
+
+/// The number of lanes in the Keccak-f[1600] state.
+const NUM_LANES: usize = 25;
+/// The number of rounds in the Keccak-f[1600] permutation.
+const NUM_ROUNDS: usize = 24;
+/// The width of the Keccak-f[1600] permutation in bits.
+const PERMUTATION_WIDTH: usize = 1600;
+
+/// The round constants for the iota step, one for each round.
+const ROUND_CONSTANTS: [u64; NUM_ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets for the rho step, indexed by lane `x + 5 * y`.
+const ROTATIONS: [u32; NUM_LANES] =
+    [0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
+
+/// The padding rule of the Keccak hash function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeccakType {
+    /// The original Keccak padding (i.e. `pad10*1`), as used in Ethereum.
+    Keccak,
+    /// The SHA-3 padding, which prepends the `01` domain suffix to `pad10*1`.
+    Sha3,
+}
+
+/// Keccak is a sponge-based hash function over the Keccak-f[1600] permutation.
+/// The input is absorbed as a sequence of bits, where each byte is read in little-endian bit order,
+/// and the output is the first `VARIANT` bits of the state.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Keccak<const TYPE: u8, const VARIANT: usize>;
+
+impl<const TYPE: u8, const VARIANT: usize> Keccak<TYPE, VARIANT> {
+    /// Returns the rate of the sponge in bits.
+    const fn rate() -> usize {
+        PERMUTATION_WIDTH - 2 * VARIANT
+    }
+
+    /// Returns the padded input, as defined by the padding rule of `TYPE`.
+    fn pad(input: &[bool]) -> Vec<bool> {
+        let rate = Self::rate();
+
+        let mut padded = Vec::with_capacity(input.len() + 2 * rate);
+        padded.extend_from_slice(input);
+        // Append the domain suffix for SHA-3.
+        if TYPE == KeccakType::Sha3 as u8 {
+            padded.extend([false, true]);
+        }
+        // Append the `pad10*1` rule.
+        padded.push(true);
+        while (padded.len() + 1) % rate != 0 {
+            padded.push(false);
+        }
+        padded.push(true);
+        padded
+    }
+
+    /// Applies the Keccak-f[1600] permutation to the given state.
+    fn permute(state: &mut [u64; NUM_LANES]) {
+        for round_constant in ROUND_CONSTANTS {
+            // Theta step.
+            let mut c = [0u64; 5];
+            for (x, c) in c.iter_mut().enumerate() {
+                *c = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+            }
+            for x in 0..5 {
+                let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+                for y in 0..5 {
+                    state[x + 5 * y] ^= d;
+                }
+            }
+            // Rho and pi steps.
+            let mut b = [0u64; NUM_LANES];
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] = state[x + 5 * y].rotate_left(ROTATIONS[x + 5 * y]);
+                }
+            }
+            // Chi step.
+            for x in 0..5 {
+                for y in 0..5 {
+                    state[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+                }
+            }
+            // Iota step.
+            state[0] ^= round_constant;
+        }
+    }
+}
@@ -28,8 +28,14 @@ pub use blake2xs::Blake2Xs;
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod keccak;
+pub use keccak::{Keccak, Keccak256, KeccakType, Sha3_256};
+
 mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+mod sha256;
+pub use sha256::Sha256;
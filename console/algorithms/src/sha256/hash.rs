@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Hash for Sha256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-256 hash of the given input as a list of 256 bits.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Compress each block of the padded input.
+        let mut state = INITIAL_STATE;
+        for block in Self::pad(input).chunks(BLOCK_SIZE_IN_BITS) {
+            Self::compress(&mut state, block);
+        }
+
+        // Output the digest in big-endian byte order, with each byte in little-endian bit order.
+        Ok(state
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the little-endian bits of the given bytes.
+    fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the bytes of the given little-endian bits.
+    fn bits_le_to_bytes(bits: &[bool]) -> Vec<u8> {
+        bits.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8))).collect()
+    }
+
+    fn check_hash(input: &[u8], expected: &str) {
+        let output = Sha256.hash(&bytes_to_bits_le(input)).unwrap();
+        assert_eq!(256, output.len());
+        assert_eq!(expected, hex::encode(bits_le_to_bytes(&output)));
+    }
+
+    #[test]
+    fn test_sha256() {
+        check_hash(b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        check_hash(b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        // Note: This input spans multiple blocks.
+        check_hash(
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        );
+    }
+
+    #[test]
+    fn test_unaligned_input() {
+        let mut rng = TestRng::default();
+
+        // Ensure inputs that are not byte-aligned are hashed without collisions on their padding.
+        for num_bits in 1..16 {
+            let input = (0..num_bits).map(|_| rng.gen()).collect::<Vec<_>>();
+            let mut extended = input.clone();
+            extended.push(false);
+            assert_ne!(Sha256.hash(&input).unwrap(), Sha256.hash(&extended).unwrap());
+        }
+    }
+}
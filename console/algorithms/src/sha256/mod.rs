@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+use snarkvm_console_types::prelude::*;
+
+/// The size of a SHA-256 message block in bits.
+const BLOCK_SIZE_IN_BITS: usize = 512;
+
+/// The initial hash values, as defined in FIPS 180-4.
+const INITIAL_STATE: [u32; 8] =
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// The round constants, as defined in FIPS 180-4.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+/// SHA-256 is the Merkle-Damgard hash function with a 256-bit output, as standardized in FIPS 180-4.
+/// The input is read as a sequence of bits, where each byte is read in little-endian bit order,
+/// and the output is the digest in little-endian bit order per byte.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sha256;
+
+impl Sha256 {
+    /// Returns the message bits in big-endian order per byte, followed by the padding of FIPS 180-4.
+    fn pad(input: &[bool]) -> Vec<bool> {
+        let mut padded = Vec::with_capacity(input.len() + 2 * BLOCK_SIZE_IN_BITS);
+        // Reverse each byte of the input into big-endian bit order.
+        for byte in input.chunks(8) {
+            padded.extend(byte.iter().rev());
+        }
+        // Append a single one bit, followed by zero bits up to the length field.
+        padded.push(true);
+        while padded.len() % BLOCK_SIZE_IN_BITS != BLOCK_SIZE_IN_BITS - 64 {
+            padded.push(false);
+        }
+        // Append the length of the input in bits, as a big-endian 64-bit integer.
+        padded.extend((0..64).rev().map(|i| (input.len() as u64 >> i) & 1 == 1));
+        padded
+    }
+
+    /// Applies the SHA-256 compression function to the given state and message block.
+    fn compress(state: &mut [u32; 8], block: &[bool]) {
+        // Prepare the message schedule.
+        let mut w = [0u32; 64];
+        for (i, word) in block.chunks(32).enumerate() {
+            w[i] = word.iter().fold(0u32, |word, bit| (word << 1) | (*bit as u32));
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        // Compute the rounds.
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let temp1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(ROUND_CONSTANTS[i]).wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        // Update the state.
+        for (state, value) in state.iter_mut().zip_eq([a, b, c, d, e, f, g, h]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for Access<N> {
+    /// Parses a string into an access.
+    /// The access is of the form `.{identifier}` or `[{index}]`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an index of the form `[{index}]` or `[{index}u32]`.
+        fn parse_index<N: Network>(string: &str) -> ParserResult<U32<N>> {
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the index from the string.
+            let (string, index) =
+                map_res(recognize(many1(one_of("0123456789"))), |index: &str| index.parse::<u32>())(string)?;
+            // Parse the optional "u32" suffix from the string.
+            let (string, _) = opt(tag("u32"))(string)?;
+            // Parse the "]" from the string.
+            let (string, _) = tag("]")(string)?;
+            // Return the index.
+            Ok((string, U32::new(index)))
+        }
+
+        alt((
+            map(pair(tag("."), Identifier::parse), |(_, identifier)| Self::Member(identifier)),
+            map(parse_index, Self::Index),
+        ))(string)
+    }
+}
+
+impl<N: Network> FromStr for Access<N> {
+    type Err = Error;
+
+    /// Parses a string into an access.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Access<N> {
+    /// Prints the access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Access<N> {
+    /// Prints the access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            // Prints the member access, i.e. .owner
+            Self::Member(identifier) => write!(f, ".{identifier}"),
+            // Prints the index access, i.e. [3]
+            Self::Index(index) => write!(f, "[{}]", **index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(Access::parse(".owner"), Ok(("", Access::<CurrentNetwork>::Member(Identifier::from_str("owner")?))));
+        assert_eq!(Access::parse("[0]"), Ok(("", Access::<CurrentNetwork>::Index(U32::new(0)))));
+        assert_eq!(Access::parse("[17u32]"), Ok(("", Access::<CurrentNetwork>::Index(U32::new(17)))));
+        assert_eq!(Access::parse("[3].a"), Ok((".a", Access::<CurrentNetwork>::Index(U32::new(3)))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        assert!(Access::<CurrentNetwork>::parse("").is_err());
+        assert!(Access::<CurrentNetwork>::parse(".").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[a]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[-1]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[1u8]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[4294967296]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("owner").is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(Access::<CurrentNetwork>::Member(Identifier::from_str("owner")?).to_string(), ".owner");
+        assert_eq!(Access::<CurrentNetwork>::Index(U32::new(3)).to_string(), "[3]");
+        assert_eq!(Access::<CurrentNetwork>::from_str("[3u32]")?.to_string(), "[3]");
+        Ok(())
+    }
+}
@@ -35,7 +35,8 @@ impl<N: Network> FromBytes for Literal<N> {
             13 => Self::U128(U128::read_le(&mut reader)?),
             14 => Self::Scalar(Scalar::read_le(&mut reader)?),
             15 => Self::String(StringType::read_le(&mut reader)?),
-            16.. => return Err(error(format!("Failed to decode literal variant {index}"))),
+            16 => Self::Signature(Box::new(Signature::read_le(&mut reader)?)),
+            17.. => return Err(error(format!("Failed to decode literal variant {index}"))),
         };
         Ok(literal)
     }
@@ -110,6 +111,10 @@ impl<N: Network> ToBytes for Literal<N> {
                 (15 as Size).write_le(&mut writer)?;
                 primitive.write_le(&mut writer)
             }
+            Self::Signature(primitive) => {
+                (16 as Size).write_le(&mut writer)?;
+                primitive.write_le(&mut writer)
+            }
         }
     }
 }
@@ -171,6 +176,11 @@ mod tests {
             check_bytes(Literal::<CurrentNetwork>::Scalar(Uniform::rand(rng)))?;
             // String
             check_bytes(Literal::<CurrentNetwork>::String(StringType::rand(rng)))?;
+            // Signature
+            // Note: The signature is not checked against a truncated buffer, as its leading scalar
+            // may be reinterpreted as a valid literal of a different variant.
+            let expected = Literal::<CurrentNetwork>::sample(LiteralType::Signature, rng);
+            assert_eq!(expected, Literal::read_le(&expected.to_bytes_le()?[..])?);
         }
         Ok(())
     }
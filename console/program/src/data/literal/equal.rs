@@ -42,6 +42,7 @@ impl<N: Network> core::hash::Hash for Literal<N> {
             Self::U128(a) => a.hash(state),
             Self::Scalar(a) => a.hash(state),
             Self::String(a) => a.hash(state),
+            Self::Signature(a) => a.hash(state),
         }
     }
 }
@@ -68,6 +69,7 @@ impl<N: Network> Equal for Literal<N> {
             (Self::U128(a), Self::U128(b)) => a.is_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_equal(b),
             (Self::String(a), Self::String(b)) => a.is_equal(b),
+            (Self::Signature(a), Self::Signature(b)) => Boolean::new(a == b),
             _ => Boolean::new(false),
         }
     }
@@ -91,6 +93,7 @@ impl<N: Network> Equal for Literal<N> {
             (Self::U128(a), Self::U128(b)) => a.is_not_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_not_equal(b),
             (Self::String(a), Self::String(b)) => a.is_not_equal(b),
+            (Self::Signature(a), Self::Signature(b)) => Boolean::new(a != b),
             _ => Boolean::new(true),
         }
     }
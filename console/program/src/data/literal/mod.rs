@@ -24,6 +24,7 @@ mod to_type;
 mod variant;
 
 use crate::LiteralType;
+use snarkvm_console_account::{ComputeKey, Signature};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::{prelude::*, Boolean};
 
@@ -62,4 +63,6 @@ pub enum Literal<N: Network> {
     Scalar(Scalar<N>),
     /// The string type.
     String(StringType<N>),
+    /// The signature type.
+    Signature(Box<Signature<N>>),
 }
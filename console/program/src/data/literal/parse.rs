@@ -35,6 +35,7 @@ impl<N: Network> Parser for Literal<N> {
             map(U128::<N>::parse, |literal| Self::U128(literal)),
             map(Scalar::<N>::parse, |literal| Self::Scalar(literal)),
             map(StringType::<N>::parse, |literal| Self::String(literal)),
+            map(Signature::<N>::parse, |literal| Self::Signature(Box::new(literal))),
         ))(string)
     }
 }
@@ -82,6 +83,7 @@ impl<N: Network> Display for Literal<N> {
             Self::U128(literal) => Display::fmt(literal, f),
             Self::Scalar(literal) => Display::fmt(literal, f),
             Self::String(literal) => Display::fmt(literal, f),
+            Self::Signature(literal) => Display::fmt(literal, f),
         }
     }
 }
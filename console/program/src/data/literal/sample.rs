@@ -34,6 +34,12 @@ impl<N: Network> Literal<N> {
             LiteralType::U128 => Literal::U128(U128::rand(rng)),
             LiteralType::Scalar => Literal::Scalar(Scalar::rand(rng)),
             LiteralType::String => Literal::String(StringType::rand(rng)),
+            LiteralType::Signature => Literal::Signature(Box::new(Signature::from((
+                Scalar::rand(rng),
+                Scalar::rand(rng),
+                ComputeKey::try_from((Group::rand(rng), Group::rand(rng)))
+                    .or_halt_with::<N>("Failed to sample a compute key."),
+            )))),
         }
     }
 }
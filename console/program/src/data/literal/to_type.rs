@@ -34,6 +34,7 @@ impl<N: Network> Literal<N> {
             Self::U128(..) => LiteralType::U128,
             Self::Scalar(..) => LiteralType::Scalar,
             Self::String(..) => LiteralType::String,
+            Self::Signature(..) => LiteralType::Signature,
         }
     }
 }
@@ -34,6 +34,7 @@ impl<N: Network> Literal<N> {
             Self::U128(..) => 13,
             Self::Scalar(..) => 14,
             Self::String(..) => 15,
+            Self::Signature(..) => 16,
         }
     }
 }
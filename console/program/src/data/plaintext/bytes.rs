@@ -42,7 +42,30 @@ impl<N: Network> FromBytes for Plaintext<N> {
                 // Return the struct.
                 Self::Struct(members, Default::default())
             }
-            2.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
+            2 => {
+                // Read the number of elements in the array.
+                let num_elements = u32::read_le(&mut reader)?;
+                // Ensure the number of elements is nonzero, and within the maximum limit.
+                if num_elements == 0 {
+                    return Err(error("Plaintext array must contain at least one element"));
+                }
+                if num_elements as usize > N::MAX_DATA_ENTRIES {
+                    return Err(error(format!("Plaintext array exceeds {} elements", N::MAX_DATA_ENTRIES)));
+                }
+                // Read the elements.
+                let mut elements = Vec::with_capacity(num_elements as usize);
+                for _ in 0..num_elements {
+                    // Read the plaintext value (in 2 steps to prevent infinite recursion).
+                    let num_bytes = u16::read_le(&mut reader)?;
+                    // Read the plaintext bytes.
+                    let bytes = (0..num_bytes).map(|_| u8::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                    // Recover the plaintext value.
+                    elements.push(Plaintext::read_le(&mut bytes.as_slice())?);
+                }
+                // Return the array.
+                Self::Array(elements, Default::default())
+            }
+            3.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
         };
         Ok(plaintext)
     }
@@ -80,6 +103,27 @@ impl<N: Network> ToBytes for Plaintext<N> {
                 }
                 Ok(())
             }
+            Self::Array(elements, ..) => {
+                2u8.write_le(&mut writer)?;
+
+                // Write the number of elements in the array.
+                u32::try_from(elements.len())
+                    .or_halt_with::<N>("Plaintext array length exceeds u32::MAX.")
+                    .write_le(&mut writer)?;
+
+                // Write each element.
+                for element in elements {
+                    // Write the element (performed in 2 steps to prevent infinite recursion).
+                    let bytes = element.to_bytes_le().map_err(|e| error(e.to_string()))?;
+                    // Write the number of bytes.
+                    u16::try_from(bytes.len())
+                        .or_halt_with::<N>("Plaintext element exceeds u16::MAX bytes.")
+                        .write_le(&mut writer)?;
+                    // Write the bytes.
+                    bytes.write_le(&mut writer)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -192,6 +236,21 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
         assert!(Plaintext::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the array manually.
+        let expected = Plaintext::<CurrentNetwork>::from_str(
+            "[{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amounts: [1u64, 2u64] }]",
+        )?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
+
+        // Ensure an empty array is rejected, as it cannot be parsed.
+        let empty = Plaintext::<CurrentNetwork>::Array(vec![], Default::default());
+        assert!(Plaintext::<CurrentNetwork>::read_le(&empty.to_bytes_le()?[..]).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_le(&empty.to_bits_le()).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_be(&empty.to_bits_be()).is_err());
         Ok(())
     }
 }
@@ -15,52 +15,73 @@
 use super::*;
 
 impl<N: Network> Plaintext<N> {
-    /// Returns the plaintext member from the given path.
-    pub fn find(&self, path: &[Identifier<N>]) -> Result<Plaintext<N>> {
+    /// Returns the plaintext member or element from the given path.
+    pub fn find(&self, path: &[Access<N>]) -> Result<Plaintext<N>> {
         // Ensure the path is not empty.
         ensure!(!path.is_empty(), "Attempted to find member with an empty path.");
 
-        match self {
-            // Halts if the value is not a struct.
-            Self::Literal(..) => bail!("'{self}' is not a struct"),
-            // Retrieve the value of the member (from the value).
-            Self::Struct(members, ..) => {
-                // Initialize the members starting from the top-level.
-                let mut submembers = members;
+        // Initialize the plaintext starting from the top-level.
+        let mut plaintext = self;
 
-                // Initialize the output.
-                let mut output = None;
+        // Iterate through the path to retrieve the value.
+        for access in path.iter() {
+            plaintext = match (plaintext, access) {
+                // Retrieve the member and update `plaintext` for the next iteration.
+                (Self::Struct(members, ..), Access::Member(identifier)) => match members.get(identifier) {
+                    Some(member) => member,
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{identifier}' in '{self}'"),
+                },
+                // Retrieve the element and update `plaintext` for the next iteration.
+                (Self::Array(elements, ..), Access::Index(index)) => match elements.get(**index as usize) {
+                    Some(element) => element,
+                    // Halts if the index is out of bounds.
+                    None => bail!("Index '{index}' is out of bounds in '{self}'"),
+                },
+                // Halts if the value is not a struct.
+                (_, Access::Member(identifier)) => bail!("'{plaintext}' is not a struct, cannot access '{identifier}'"),
+                // Halts if the value is not an array.
+                (_, Access::Index(index)) => bail!("'{plaintext}' is not an array, cannot access '[{index}]'"),
+            };
+        }
 
-                // Iterate through the path to retrieve the value.
-                for (i, identifier) in path.iter().enumerate() {
-                    // If this is not the last item in the path, ensure the value is a struct.
-                    if i != path.len() - 1 {
-                        match submembers.get(identifier) {
-                            // Halts if the member is not a struct.
-                            Some(Self::Literal(..)) => bail!("'{identifier}' must be a struct"),
-                            // Retrieve the member and update `submembers` for the next iteration.
-                            Some(Self::Struct(members, ..)) => submembers = members,
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in '{self}'"),
-                        }
-                    }
-                    // Otherwise, return the final member.
-                    else {
-                        match submembers.get(identifier) {
-                            // Return the plaintext member.
-                            Some(plaintext) => output = Some(plaintext.clone()),
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in '{self}'"),
-                        }
-                    }
-                }
+        // Return the output.
+        Ok(plaintext.clone())
+    }
+}
 
-                // Return the output.
-                match output {
-                    Some(output) => Ok(output),
-                    None => bail!("Failed to locate member from path in '{self}': {:?}", path),
-                }
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Register;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_find() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: [{ b: 1u8 }, { b: 2u8 }], c: [[true, false]] }")?;
+
+        // Finds the plaintext at the given path, using the register syntax to parse the path.
+        let find = |path: &str| match Register::<CurrentNetwork>::from_str(&format!("r0.{path}"))? {
+            Register::Access(_, path) => plaintext.find(&path),
+            Register::Locator(..) => bail!("Expected a register access"),
+        };
+
+        assert_eq!(find("a[0].b")?, Plaintext::from_str("1u8")?);
+        assert_eq!(find("a[1].b")?, Plaintext::from_str("2u8")?);
+        assert_eq!(find("a[1]")?, Plaintext::from_str("{ b: 2u8 }")?);
+        assert_eq!(find("c[0][1]")?, Plaintext::from_str("false")?);
+        assert_eq!(find("c[0]")?, Plaintext::from_str("[true, false]")?);
+
+        // Out of bounds.
+        assert!(find("a[2]").is_err());
+        // Mismatched accesses.
+        assert!(find("a.b").is_err());
+        assert!(find("a[0][0]").is_err());
+        assert!(find("c[0][1].d").is_err());
+        // Empty path.
+        assert!(plaintext.find(&[]).is_err());
+        Ok(())
     }
 }
@@ -94,6 +94,41 @@ impl<N: Network> FromBits for Plaintext<N> {
                 Err(_) => bail!("Failed to store the plaintext bits in the cache."),
             }
         }
+        // Array
+        else if variant == [true, false] {
+            if 32 + counter > bits_le.len() {
+                bail!("Array error error.");
+            }
+            let num_elements = u32::from_bits_le(&bits_le[counter..counter + 32])?;
+            counter += 32;
+            if num_elements == 0 {
+                bail!("Array must contain at least one element.");
+            }
+            if num_elements as usize > N::MAX_DATA_ENTRIES {
+                bail!("Array exceeds {} elements.", N::MAX_DATA_ENTRIES);
+            }
+            let mut elements = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                if 16 + counter > bits_le.len() {
+                    bail!("Array error error.");
+                }
+                let element_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
+                counter += 16;
+                if (element_size as usize) + counter > bits_le.len() {
+                    bail!("Array error error.");
+                }
+                let element = Plaintext::from_bits_le(&bits_le[counter..counter + element_size as usize])?;
+                counter += element_size as usize;
+                elements.push(element);
+            }
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the array.
+                Ok(_) => Ok(Self::Array(elements, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant.");
@@ -157,6 +192,45 @@ impl<N: Network> FromBits for Plaintext<N> {
                 Err(_) => bail!("Failed to store the plaintext bits in the cache."),
             }
         }
+        // Array
+        else if variant == [true, false] {
+            if 32 + counter > bits_be.len() {
+                bail!("Array error error.");
+            }
+            let num_elements = u32::from_bits_be(&bits_be[counter..counter + 32])?;
+            counter += 32;
+            if num_elements == 0 {
+                bail!("Array must contain at least one element.");
+            }
+            if num_elements as usize > N::MAX_DATA_ENTRIES {
+                bail!("Array exceeds {} elements.", N::MAX_DATA_ENTRIES);
+            }
+
+            let mut elements = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                if 16 + counter > bits_be.len() {
+                    bail!("Array error error.");
+                }
+                let element_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
+                counter += 16;
+                if (element_size as usize) + counter > bits_be.len() {
+                    bail!("Array error error.");
+                }
+
+                let element = Plaintext::from_bits_be(&bits_be[counter..counter + element_size as usize])?;
+                counter += element_size as usize;
+
+                elements.push(element);
+            }
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_be.to_vec()) {
+                // Return the array.
+                Ok(_) => Ok(Self::Array(elements, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant.");
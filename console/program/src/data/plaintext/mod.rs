@@ -25,7 +25,7 @@ mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Literal};
+use crate::{Access, Ciphertext, Identifier, Literal};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -38,6 +38,8 @@ pub enum Plaintext<N: Network> {
     Literal(Literal<N>, OnceCell<Vec<bool>>),
     /// A struct.
     Struct(IndexMap<Identifier<N>, Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// An array.
+    Array(Vec<Plaintext<N>>, OnceCell<Vec<bool>>),
 }
 
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
@@ -151,6 +153,44 @@ mod tests {
             OnceCell::new(),
         );
         assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+
+        let value = Plaintext::<CurrentNetwork>::Array(
+            vec![
+                Plaintext::<CurrentNetwork>::from_str("true")?,
+                Plaintext::<CurrentNetwork>::Literal(
+                    Literal::Field(Field::new(Uniform::rand(&mut rng))),
+                    OnceCell::new(),
+                ),
+            ],
+            OnceCell::new(),
+        );
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+
+        let value =
+            Plaintext::<CurrentNetwork>::from_str("{ a: [1u8, 2u8, 3u8], b: [{ c: [true] }, { c: [false] }] }")?;
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+        assert_eq!(value, Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_from_bits_be_fails() -> Result<()> {
+        let value = Plaintext::<CurrentNetwork>::from_str("[1u8, 2u8, 3u8]")?;
+        assert_eq!(value, Plaintext::<CurrentNetwork>::from_bits_be(&value.to_bits_be())?);
+
+        // Ensure an array with more than `MAX_DATA_ENTRIES` elements fails.
+        let mut bits_be = vec![true, false];
+        bits_be.extend((CurrentNetwork::MAX_DATA_ENTRIES as u32 + 1).to_bits_be());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_be(&bits_be).is_err());
+        let mut bits_be = vec![true, false];
+        bits_be.extend(u32::MAX.to_bits_be());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_be(&bits_be).is_err());
+
+        // Ensure a truncated array fails.
+        let bits_be = value.to_bits_be();
+        for length in [10, 34, 40, bits_be.len() - 1] {
+            assert!(Plaintext::<CurrentNetwork>::from_bits_be(&bits_be[..length]).is_err());
+        }
         Ok(())
     }
 }
@@ -60,6 +60,28 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default())))
         }
 
+        /// Parses a plaintext as an array: `[plaintext_0, ..., plaintext_n]`.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, elements) = map_res(separated_list1(tag(","), Plaintext::parse), |elements: Vec<_>| {
+                // Ensure the number of elements is within the maximum limit.
+                match elements.len() <= N::MAX_DATA_ENTRIES {
+                    true => Ok(elements),
+                    false => Err(error(format!("Found a plaintext array that exceeds size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Array(elements, Default::default())))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -68,6 +90,8 @@ impl<N: Network> Parser for Plaintext<N> {
             map(Literal::parse, |literal| Self::Literal(literal, Default::default())),
             // Parse a plaintext struct.
             parse_struct,
+            // Parse a plaintext array.
+            parse_array,
         ))(string)
     }
 }
@@ -117,33 +141,42 @@ impl<N: Network> Plaintext<N> {
                 // Print the opening brace.
                 write!(f, "{{")?;
                 // Print the members.
-                struct_.iter().enumerate().try_for_each(|(i, (name, plaintext))| {
+                for (i, (name, plaintext)) in struct_.iter().enumerate() {
+                    // Print the member name.
+                    write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
+                    // Print the member.
                     match plaintext {
-                        Self::Literal(literal, ..) => match i == struct_.len() - 1 {
-                            true => {
-                                // Print the last member without a comma.
-                                write!(f, "\n{:indent$}{name}: {literal}", "", indent = (depth + 1) * INDENT)?;
-                                // Print the closing brace.
-                                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
-                            }
-                            // Print the member with a comma.
-                            false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
-                        },
-                        Self::Struct(..) => {
-                            // Print the member name.
-                            write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
-                            // Print the member.
-                            plaintext.fmt_internal(f, depth + 1)?;
-                            // Print the closing brace.
-                            match i == struct_.len() - 1 {
-                                // Print the last member without a comma.
-                                true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
-                                // Print the member with a comma.
-                                false => write!(f, ","),
-                            }
-                        }
+                        Self::Literal(literal, ..) => write!(f, "{literal}")?,
+                        Self::Struct(..) | Self::Array(..) => plaintext.fmt_internal(f, depth + 1)?,
                     }
-                })
+                    // Print the member with a comma, unless it is the last member.
+                    if i != struct_.len() - 1 {
+                        write!(f, ",")?;
+                    }
+                }
+                // Print the closing brace.
+                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
+            }
+            // Prints the array, i.e. [ 10i64, 198u64 ]
+            Self::Array(elements, ..) => {
+                // Print the opening bracket.
+                write!(f, "[")?;
+                // Print the elements.
+                for (i, element) in elements.iter().enumerate() {
+                    // Print the indentation.
+                    write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                    // Print the element.
+                    match element {
+                        Self::Literal(literal, ..) => write!(f, "{literal}")?,
+                        Self::Struct(..) | Self::Array(..) => element.fmt_internal(f, depth + 1)?,
+                    }
+                    // Print the element with a comma, unless it is the last element.
+                    if i != elements.len() - 1 {
+                        write!(f, ",")?;
+                    }
+                }
+                // Print the closing bracket.
+                write!(f, "\n{:indent$}]", "", indent = depth * INDENT)
             }
         }
     }
@@ -211,11 +244,65 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        // Sanity check.
+        let expected = r"[
+  1u8,
+  2u8,
+  3u8
+]";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("[1u8, 2u8, 3u8]")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: [
+    {
+      bar: [
+        true,
+        false
+      ]
+    },
+    {
+      bar: [
+        false
+      ]
+    }
+  ],
+  baz: [
+    [
+      1field
+    ],
+    [
+      2field,
+      3field
+    ]
+  ]
+}";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse(
+            "{ foo: [{ bar: [true, false] }, { bar: [false] }], baz: [[1field], [ 2field, 3field ]] }",
+        )?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Ensure the display output parses back into the same plaintext.
+        assert_eq!(candidate, Plaintext::<CurrentNetwork>::from_str(expected)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
         assert!(Plaintext::<CurrentNetwork>::parse("").is_err());
         assert!(Plaintext::<CurrentNetwork>::parse("{}").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("[]").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("[1u8,]").is_err());
+
+        // Arrays must not exceed the maximum number of entries.
+        let elements = vec!["1u8"; CurrentNetwork::MAX_DATA_ENTRIES + 1].join(", ");
+        assert!(Plaintext::<CurrentNetwork>::parse(&format!("[{elements}]")).is_err());
 
         // Invalid characters.
         assert!(Plaintext::<CurrentNetwork>::parse("_").is_err());
@@ -49,6 +49,26 @@ impl<N: Network> ToBits for Plaintext<N> {
                     bits_le
                 })
                 .clone(),
+            Self::Array(elements, bits_le) => bits_le
+                .get_or_init(|| {
+                    let mut bits_le = vec![true, false]; // Variant bits.
+                    bits_le.extend(
+                        u32::try_from(elements.len())
+                            .or_halt_with::<N>("Plaintext array length exceeds u32::MAX")
+                            .to_bits_le(),
+                    );
+                    for element in elements {
+                        let element_bits = element.to_bits_le();
+                        bits_le.extend(
+                            u16::try_from(element_bits.len())
+                                .or_halt_with::<N>("Plaintext element exceeds u16::MAX bits")
+                                .to_bits_le(),
+                        );
+                        bits_le.extend(element_bits);
+                    }
+                    bits_le
+                })
+                .clone(),
         }
     }
 
@@ -86,6 +106,26 @@ impl<N: Network> ToBits for Plaintext<N> {
                     bits_be
                 })
                 .clone(),
+            Self::Array(elements, bits_be) => bits_be
+                .get_or_init(|| {
+                    let mut bits_be = vec![true, false]; // Variant bits.
+                    bits_be.extend(
+                        u32::try_from(elements.len())
+                            .or_halt_with::<N>("Plaintext array length exceeds u32::MAX")
+                            .to_bits_be(),
+                    );
+                    for element in elements {
+                        let element_bits = element.to_bits_be();
+                        bits_be.extend(
+                            u16::try_from(element_bits.len())
+                                .or_halt_with::<N>("Plaintext element exceeds u16::MAX bits")
+                                .to_bits_be(),
+                        );
+                        bits_be.extend(element_bits);
+                    }
+                    bits_be
+                })
+                .clone(),
         }
     }
 }
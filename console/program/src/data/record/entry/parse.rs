@@ -53,6 +53,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
                 parse_literal,
                 // Parse a struct.
                 parse_struct,
+                // Parse an array.
+                parse_array,
             ))(string)?;
             // Return the identifier, plaintext, and visibility.
             Ok((string, (identifier, plaintext, mode)))
@@ -94,6 +96,44 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             Ok((string, (Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default()), mode)))
         }
 
+        /// Parses an entry as an array: `[plaintext_0.visibility, ..., plaintext_n.visibility]`.
+        /// Observe the `visibility` is the same for all elements of the plaintext value.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            /// Parses a sanitized element: `entry`.
+            fn parse_element<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+                // Parse the whitespace and comments from the string.
+                let (string, _) = Sanitizer::parse(string)?;
+                // Parse the plaintext and visibility from the string.
+                alt((parse_literal, parse_struct, parse_array))(string)
+            }
+
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, (elements, mode)) = map_res(separated_list1(tag(","), parse_element), |elements: Vec<_>| {
+                // Ensure the elements all have the same visibility.
+                let mode = elements.iter().map(|(_, mode)| mode).dedup().collect::<Vec<_>>();
+                let mode = match mode.len() == 1 {
+                    true => *mode[0],
+                    false => return Err(error("Elements of array in entry have different visibilities")),
+                };
+                // Ensure the number of elements is within the maximum limit.
+                match elements.len() <= N::MAX_DATA_ENTRIES {
+                    // Return the elements and the visibility.
+                    true => Ok((elements.into_iter().map(|(p, _)| p).collect::<Vec<_>>(), mode)),
+                    false => Err(error(format!("Found a plaintext array that exceeds size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext and visibility.
+            Ok((string, (Plaintext::Array(elements, Default::default()), mode)))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the entry (order matters).
@@ -102,6 +142,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             parse_literal,
             // Parse a struct.
             parse_struct,
+            // Parse an array.
+            parse_array,
         ))(string)?;
 
         // Return the entry.
@@ -156,6 +198,13 @@ impl<N: Network> Entry<N, Plaintext<N>> {
             Self::Private(private) => (private, "private"),
         };
 
+        // Wraps the given plaintext with the visibility of this entry.
+        let to_entry = |plaintext: &Plaintext<N>| match self {
+            Self::Constant(..) => Self::Constant(plaintext.clone()),
+            Self::Public(..) => Self::Public(plaintext.clone()),
+            Self::Private(..) => Self::Private(plaintext.clone()),
+        };
+
         match plaintext {
             // Prints the literal, i.e. 10field.public
             Plaintext::Literal(literal, ..) => {
@@ -166,38 +215,44 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                 // Print the opening brace.
                 write!(f, "{{")?;
                 // Print the members.
-                struct_.iter().enumerate().try_for_each(|(i, (name, plaintext))| {
+                for (i, (name, plaintext)) in struct_.iter().enumerate() {
+                    // Print the member name.
+                    write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
+                    // Print the member.
                     match plaintext {
-                        #[rustfmt::skip]
-                        Plaintext::Literal(literal, ..) => match i == struct_.len() - 1 {
-                            true => {
-                                // Print the last member without a comma.
-                                write!(f, "\n{:indent$}{name}: {literal}.{visibility}", "", indent = (depth + 1) * INDENT)?;
-                                // Print the closing brace.
-                                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
-                            }
-                            // Print the member with a comma.
-                            false => write!(f, "\n{:indent$}{name}: {literal}.{visibility},", "", indent = (depth + 1) * INDENT),
-                        },
-                        Plaintext::Struct(..) => {
-                            // Print the member name.
-                            write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
-                            // Print the member.
-                            match self {
-                                Self::Constant(..) => Self::Constant(plaintext.clone()).fmt_internal(f, depth + 1)?,
-                                Self::Public(..) => Self::Public(plaintext.clone()).fmt_internal(f, depth + 1)?,
-                                Self::Private(..) => Self::Private(plaintext.clone()).fmt_internal(f, depth + 1)?,
-                            }
-                            // Print the closing brace.
-                            match i == struct_.len() - 1 {
-                                // Print the last member without a comma.
-                                true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
-                                // Print the member with a comma.
-                                false => write!(f, "\n{:indent$}}},", "", indent = depth * INDENT),
-                            }
+                        Plaintext::Literal(literal, ..) => write!(f, "{literal}.{visibility}")?,
+                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                            to_entry(plaintext).fmt_internal(f, depth + 1)?
                         }
                     }
-                })
+                    // Print the member with a comma, unless it is the last member.
+                    if i != struct_.len() - 1 {
+                        write!(f, ",")?;
+                    }
+                }
+                // Print the closing brace.
+                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
+            }
+            // Prints the array, i.e. [ 10i64.private, 198u64.private ]
+            Plaintext::Array(elements, ..) => {
+                // Print the opening bracket.
+                write!(f, "[")?;
+                // Print the elements.
+                for (i, element) in elements.iter().enumerate() {
+                    // Print the indentation.
+                    write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                    // Print the element.
+                    match element {
+                        Plaintext::Literal(literal, ..) => write!(f, "{literal}.{visibility}")?,
+                        Plaintext::Struct(..) | Plaintext::Array(..) => to_entry(element).fmt_internal(f, depth + 1)?,
+                    }
+                    // Print the element with a comma, unless it is the last element.
+                    if i != elements.len() - 1 {
+                        write!(f, ",")?;
+                    }
+                }
+                // Print the closing bracket.
+                write!(f, "\n{:indent$}]", "", indent = depth * INDENT)
             }
         }
     }
@@ -155,10 +155,10 @@ impl<N: Network> Record<N, Plaintext<N>> {
                 Entry::Constant(Plaintext::Literal(..))
                 | Entry::Public(Plaintext::Literal(..))
                 | Entry::Private(Plaintext::Literal(..)) => write!(f, "{entry}")?,
-                // If the entry is a struct, print the entry with indentation.
-                Entry::Constant(Plaintext::Struct(..))
-                | Entry::Public(Plaintext::Struct(..))
-                | Entry::Private(Plaintext::Struct(..)) => entry.fmt_internal(f, depth + 1)?,
+                // If the entry is a struct or an array, print the entry with indentation.
+                Entry::Constant(Plaintext::Struct(..) | Plaintext::Array(..))
+                | Entry::Public(Plaintext::Struct(..) | Plaintext::Array(..))
+                | Entry::Private(Plaintext::Struct(..) | Plaintext::Array(..)) => entry.fmt_internal(f, depth + 1)?,
             }
             // Print the comma.
             write!(f, ",")?;
@@ -237,6 +237,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_with_array_entry() -> Result<()> {
+        let expected = r"{
+  owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public,
+  foo: [
+    5u8.public,
+    6u8.public
+  ],
+  bar: [
+    {
+      baz: 7u8.private
+    },
+    {
+      baz: 8u8.private
+    }
+  ],
+  qux: {
+    quux: [
+      [
+        9u8.constant
+      ]
+    ],
+    corge: 10u8.constant
+  },
+  _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public
+}";
+        let (remainder, candidate) = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(expected)?;
+        println!("\nExpected: {expected}\n\nFound: {candidate}\n");
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Elements of an array must have the same visibility.
+        let mismatched = expected.replace("6u8.public", "6u8.private");
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(&mismatched).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() -> Result<()> {
         // Missing owner.
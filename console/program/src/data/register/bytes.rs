@@ -24,14 +24,36 @@ impl<N: Network> FromBytes for Register<N> {
             1 => {
                 // Read the number of identifiers.
                 let num_identifiers = u16::read_le(&mut reader)?;
+                // Ensure the number of identifiers is within the limit.
+                if num_identifiers as usize > N::MAX_DATA_DEPTH {
+                    return Err(error(format!("Register \'r{locator}\' has too many identifiers ({num_identifiers})")));
+                }
                 // Read the identifiers.
-                let mut identifiers = Vec::with_capacity(num_identifiers as usize);
+                let mut accesses = Vec::with_capacity(num_identifiers as usize);
                 for _ in 0..num_identifiers {
-                    identifiers.push(Identifier::read_le(&mut reader)?);
+                    accesses.push(Access::Member(Identifier::read_le(&mut reader)?));
+                }
+                Ok(Self::Access(locator, accesses))
+            }
+            2 => {
+                // Read the number of accesses.
+                let num_accesses = u16::read_le(&mut reader)?;
+                // Ensure the number of accesses is within the limit.
+                if num_accesses as usize > N::MAX_DATA_DEPTH {
+                    return Err(error(format!("Register \'r{locator}\' has too many accesses ({num_accesses})")));
                 }
-                Ok(Self::Member(locator, identifiers))
+                // Read the accesses.
+                let mut accesses = Vec::with_capacity(num_accesses as usize);
+                for _ in 0..num_accesses {
+                    accesses.push(Access::read_le(&mut reader)?);
+                }
+                // Ensure the path contains an index, as a path of only members is encoded as variant 1.
+                if accesses.iter().all(|access| matches!(access, Access::Member(..))) {
+                    return Err(error(format!("Register \'r{locator}\' has a non-canonical access path")));
+                }
+                Ok(Self::Access(locator, accesses))
             }
-            2.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
+            3.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
         }
     }
 }
@@ -44,19 +66,68 @@ impl<N: Network> ToBytes for Register<N> {
                 u8::write_le(&0u8, &mut writer)?;
                 variable_length_integer(locator).write_le(&mut writer)
             }
-            Self::Member(locator, identifiers) => {
-                // Ensure the number of identifiers is within the limit.
-                if identifiers.len() > N::MAX_DATA_DEPTH {
-                    return Err(error("Failed to serialize register: too many identifiers"));
+            Self::Access(locator, accesses) => {
+                // Ensure the number of accesses is within the limit.
+                if accesses.len() > N::MAX_DATA_DEPTH {
+                    return Err(error("Failed to serialize register: too many accesses"));
                 }
 
-                u8::write_le(&1u8, &mut writer)?;
-                variable_length_integer(locator).write_le(&mut writer)?;
-                u16::try_from(identifiers.len())
-                    .or_halt_with::<N>("Register path length exceeds u16::MAX")
-                    .write_le(&mut writer)?;
-                identifiers.write_le(&mut writer)
+                // If every access is a member, write the path as identifiers.
+                // Note: This preserves the encoding of registers that were written before index accesses existed.
+                let members = accesses.iter().map(|access| match access {
+                    Access::Member(identifier) => Some(*identifier),
+                    Access::Index(..) => None,
+                });
+                match members.collect::<Option<Vec<_>>>() {
+                    Some(identifiers) => {
+                        u8::write_le(&1u8, &mut writer)?;
+                        variable_length_integer(locator).write_le(&mut writer)?;
+                        u16::try_from(identifiers.len())
+                            .or_halt_with::<N>("Register path length exceeds u16::MAX")
+                            .write_le(&mut writer)?;
+                        identifiers.write_le(&mut writer)
+                    }
+                    None => {
+                        u8::write_le(&2u8, &mut writer)?;
+                        variable_length_integer(locator).write_le(&mut writer)?;
+                        u16::try_from(accesses.len())
+                            .or_halt_with::<N>("Register path length exceeds u16::MAX")
+                            .write_le(&mut writer)?;
+                        accesses.write_le(&mut writer)
+                    }
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["r0", "r1.owner", "r2.a.b.c", "r3[0]", "r4.a[1].b", "r5[1][2]"] {
+            let expected = Register::<CurrentNetwork>::from_str(string)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Register::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_rejects_non_canonical_path() -> Result<()> {
+        // Encode the member-only path of 'r1.owner' as variant 2.
+        let owner = Identifier::<CurrentNetwork>::from_str("owner")?;
+        let mut bytes = vec![2u8];
+        variable_length_integer(&1u64).write_le(&mut bytes)?;
+        1u16.write_le(&mut bytes)?;
+        Access::Member(owner).write_le(&mut bytes)?;
+        // Ensure the non-canonical encoding is rejected.
+        assert!(Register::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::PlaintextType;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::U32;
+
+/// An array type consists of an element type and a length.
+/// The format of the type is `[<element_type>; <length>]`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType<N: Network> {
+    /// The element type.
+    element_type: Box<PlaintextType<N>>,
+    /// The number of elements in the array.
+    length: U32<N>,
+}
+
+impl<N: Network> ArrayType<N> {
+    /// Initializes a new array type from the given element type and length.
+    pub fn new(element_type: PlaintextType<N>, length: u32) -> Result<Self> {
+        // Ensure the array is not empty.
+        ensure!(length > 0, "An array must have at least one element");
+        // Ensure the length does not exceed the maximum number of data entries.
+        ensure!(
+            length as usize <= N::MAX_DATA_ENTRIES,
+            "An array cannot exceed {} elements, found {length}",
+            N::MAX_DATA_ENTRIES
+        );
+        // Initialize the array type.
+        let array_type = Self { element_type: Box::new(element_type), length: U32::new(length) };
+        // Ensure the nesting depth does not exceed the maximum data depth.
+        ensure!(
+            array_type.depth() <= N::MAX_DATA_DEPTH,
+            "An array cannot exceed a depth of {}, found {}",
+            N::MAX_DATA_DEPTH,
+            array_type.depth()
+        );
+        Ok(array_type)
+    }
+
+    /// Returns the element type.
+    pub fn element_type(&self) -> &PlaintextType<N> {
+        &self.element_type
+    }
+
+    /// Returns the number of elements in the array.
+    pub const fn length(&self) -> &U32<N> {
+        &self.length
+    }
+
+    /// Returns the innermost element type, i.e. `u8` for `[[u8; 2]; 3]`.
+    pub fn base_element_type(&self) -> &PlaintextType<N> {
+        match self.element_type.as_ref() {
+            PlaintextType::Array(array_type) => array_type.base_element_type(),
+            element_type => element_type,
+        }
+    }
+
+    /// Returns the number of nested array dimensions, i.e. `2` for `[[u8; 2]; 3]`.
+    pub fn depth(&self) -> usize {
+        match self.element_type.as_ref() {
+            PlaintextType::Array(array_type) => array_type.depth().saturating_add(1),
+            _ => 1,
+        }
+    }
+}
+
+impl<N: Network> From<ArrayType<N>> for PlaintextType<N> {
+    /// Initializes a plaintext type from an array type.
+    fn from(array_type: ArrayType<N>) -> Self {
+        PlaintextType::Array(array_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LiteralType;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let array = ArrayType::<CurrentNetwork>::new(PlaintextType::Literal(LiteralType::U8), 4)?;
+        assert_eq!(array.element_type(), &PlaintextType::Literal(LiteralType::U8));
+        assert_eq!(**array.length(), 4);
+        assert_eq!(array.depth(), 1);
+
+        let nested = ArrayType::<CurrentNetwork>::new(PlaintextType::Array(array), 2)?;
+        assert_eq!(nested.base_element_type(), &PlaintextType::Literal(LiteralType::U8));
+        assert_eq!(nested.depth(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_fails() {
+        let element_type = PlaintextType::<CurrentNetwork>::Literal(LiteralType::Field);
+        // Arrays must be non-empty.
+        assert!(ArrayType::new(element_type.clone(), 0).is_err());
+        // Arrays must not exceed the maximum number of entries.
+        let max_entries = u32::try_from(CurrentNetwork::MAX_DATA_ENTRIES).unwrap();
+        assert!(ArrayType::new(element_type.clone(), max_entries).is_ok());
+        assert!(ArrayType::new(element_type.clone(), max_entries + 1).is_err());
+
+        // Arrays must not exceed the maximum depth.
+        let mut plaintext_type = element_type;
+        for _ in 0..CurrentNetwork::MAX_DATA_DEPTH {
+            plaintext_type = PlaintextType::Array(ArrayType::new(plaintext_type, 1).unwrap());
+        }
+        assert!(ArrayType::new(plaintext_type, 1).is_err());
+    }
+}
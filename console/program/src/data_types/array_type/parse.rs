@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for ArrayType<N> {
+    /// Parses a string into an array type.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opening bracket from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the element type from the string.
+        let (string, element_type) = PlaintextType::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the length from the string.
+        let (string, length) =
+            map_res(recognize(many1(one_of("0123456789"))), |length: &str| length.parse::<u32>())(string)?;
+        // Parse the optional "u32" suffix from the string.
+        let (string, _) = opt(tag("u32"))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the closing bracket from the string, and return the array type.
+        map_res(tag("]"), move |_| Self::new(element_type.clone(), length))(string)
+    }
+}
+
+impl<N: Network> FromStr for ArrayType<N> {
+    type Err = Error;
+
+    /// Returns an array type from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ArrayType<N> {
+    /// Prints the array type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ArrayType<N> {
+    /// Prints the array type as a string, i.e. `[u8; 4]`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[{}; {}]", self.element_type, *self.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, LiteralType};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(
+            ArrayType::parse("[u8; 4]"),
+            Ok(("", ArrayType::<CurrentNetwork>::new(PlaintextType::Literal(LiteralType::U8), 4)?))
+        );
+        assert_eq!(
+            ArrayType::parse("[ field;32u32 ]"),
+            Ok(("", ArrayType::<CurrentNetwork>::new(PlaintextType::Literal(LiteralType::Field), 32)?))
+        );
+        assert_eq!(
+            ArrayType::parse("[foo; 2].private"),
+            Ok((".private", ArrayType::<CurrentNetwork>::new(PlaintextType::Struct(Identifier::from_str("foo")?), 2)?))
+        );
+        assert_eq!(
+            ArrayType::parse("[[boolean; 2]; 3]"),
+            Ok((
+                "",
+                ArrayType::<CurrentNetwork>::new(
+                    PlaintextType::Array(ArrayType::new(PlaintextType::Literal(LiteralType::Boolean), 2)?),
+                    3
+                )?
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        assert!(ArrayType::<CurrentNetwork>::parse("").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; ]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 0]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 33]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; -1]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 4u8]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 4").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8.private; 4]").is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(ArrayType::<CurrentNetwork>::from_str("[u8;4]")?.to_string(), "[u8; 4]");
+        assert_eq!(ArrayType::<CurrentNetwork>::from_str("[ [ foo ; 2u32 ] ; 3 ]")?.to_string(), "[[foo; 2]; 3]");
+        Ok(())
+    }
+}
@@ -56,6 +56,8 @@ pub enum LiteralType {
     Scalar,
     /// The string type.
     String,
+    /// The signature type.
+    Signature,
 }
 
 impl LiteralType {
@@ -78,6 +80,7 @@ impl LiteralType {
             Self::U128 => "u128",
             Self::Scalar => "scalar",
             Self::String => "string",
+            Self::Signature => "signature",
         }
     }
 }
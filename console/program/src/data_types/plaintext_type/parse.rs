@@ -20,6 +20,7 @@ impl<N: Network> Parser for PlaintextType<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse to determine the plaintext type (order matters).
         alt((
+            map(ArrayType::parse, |type_| Self::Array(type_)),
             map(LiteralType::parse, |type_| Self::Literal(type_)),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
         ))(string)
@@ -58,6 +59,8 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Literal(literal) => Display::fmt(literal, f),
             // Prints the struct, i.e. signature
             Self::Struct(struct_) => Display::fmt(struct_, f),
+            // Prints the array type, i.e. [u8; 4]
+            Self::Array(array) => Display::fmt(array, f),
         }
     }
 }
@@ -77,7 +80,18 @@ mod tests {
         );
         assert_eq!(
             PlaintextType::parse("signature"),
-            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?)))
+            Ok(("", PlaintextType::<CurrentNetwork>::Literal(LiteralType::Signature)))
+        );
+        assert_eq!(
+            PlaintextType::parse("message"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("message")?)))
+        );
+        assert_eq!(
+            PlaintextType::parse("[u8; 4]"),
+            Ok((
+                "",
+                PlaintextType::<CurrentNetwork>::Array(ArrayType::new(PlaintextType::Literal(LiteralType::U8), 4)?)
+            ))
         );
         Ok(())
     }
@@ -151,6 +165,11 @@ mod tests {
             PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?).to_string(),
             "signature"
         );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::Array(ArrayType::new(PlaintextType::Literal(LiteralType::U8), 4)?)
+                .to_string(),
+            "[u8; 4]"
+        );
         Ok(())
     }
 }
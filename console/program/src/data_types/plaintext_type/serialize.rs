@@ -67,6 +67,10 @@ mod tests {
         "passport",
         "object",
         "array",
+        // Array
+        "[u8; 4]",
+        "[signature; 2]",
+        "[[field; 2]; 32]",
     ];
 
     fn check_serde_json<
@@ -0,0 +1,282 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer::{block::Metadata, coinbase::ProverSolution};
+
+use indexmap::IndexSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a candidate for the next block in the ledger, signed by the given private key.
+    ///
+    /// The valid candidate transactions are speculated into the confirmed transactions of the block,
+    /// and the valid prover solutions are aggregated into a coinbase solution, if they meet the coinbase target.
+    /// Invalid candidate transactions and prover solutions are skipped.
+    /// Note: The block is **not** added to the ledger; use `Ledger::add_next_block` to do so.
+    pub fn prepare_advance_to_next_block<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        candidate_transactions: Vec<Transaction<N>>,
+        candidate_solutions: Vec<ProverSolution<N>>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        let timer = timer!("Ledger::prepare_advance_to_next_block");
+
+        // Retrieve the latest block.
+        let latest_block = self.latest_block();
+
+        // Prepare the height, round, and timestamp of the next block.
+        let height = latest_block.height().saturating_add(1);
+        let round = latest_block.round().saturating_add(1);
+        let timestamp = i64::try_from(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())?
+            .max(latest_block.timestamp().saturating_add(1));
+
+        // Select the valid candidate transactions.
+        let mut candidate_transactions = self.select_transactions(candidate_transactions)?;
+        ensure!(
+            !candidate_transactions.is_empty(),
+            "Cannot prepare block {height}, as there are no valid transactions"
+        );
+        lap!(timer, "Select the transactions");
+
+        // Speculate the transactions.
+        // Note: The random seed of finalize depends on the transitions in the block, so if a transaction is dropped,
+        // the remaining transactions are speculated again, until the seed matches the transactions in the block.
+        let transactions = loop {
+            let transition_ids = candidate_transactions.iter().flat_map(Transaction::transition_ids);
+            let state = FinalizeGlobalState::new::<N>(height, timestamp, latest_block.hash(), transition_ids)?;
+            let transactions = self.vm.speculate(state, candidate_transactions.iter())?;
+            // If no transaction was dropped, the block is complete.
+            if transactions.len() == candidate_transactions.len() {
+                break transactions;
+            }
+            // Otherwise, retain the transactions that were not dropped.
+            let transition_ids =
+                transactions.iter().flat_map(ConfirmedTransaction::unconfirmed_transition_ids).collect::<IndexSet<_>>();
+            candidate_transactions
+                .retain(|transaction| transaction.transition_ids().all(|id| transition_ids.contains(id)));
+        };
+        lap!(timer, "Speculate the transactions");
+
+        // Compute the coinbase target and proof target.
+        let coinbase_target = coinbase_target::<N>(
+            latest_block.last_coinbase_target(),
+            latest_block.last_coinbase_timestamp(),
+            timestamp,
+        )?;
+        let proof_target = proof_target::<N>(coinbase_target);
+
+        // Aggregate the valid prover solutions into the coinbase solution.
+        let coinbase = self.prepare_coinbase(candidate_solutions, coinbase_target, proof_target)?;
+        // Compute the cumulative weight, the accumulator point, and the target and timestamp of the last coinbase.
+        let (cumulative_weight, coinbase_accumulator_point, last_coinbase_target, last_coinbase_timestamp) =
+            match &coinbase {
+                Some(coinbase) => (
+                    latest_block
+                        .cumulative_weight()
+                        .checked_add(coinbase.to_cumulative_proof_target()?)
+                        .ok_or_else(|| anyhow!("The cumulative weight overflowed at block {height}"))?,
+                    coinbase.to_accumulator_point()?,
+                    coinbase_target,
+                    timestamp,
+                ),
+                None => (
+                    latest_block.cumulative_weight(),
+                    Field::zero(),
+                    latest_block.last_coinbase_target(),
+                    latest_block.last_coinbase_timestamp(),
+                ),
+            };
+        lap!(timer, "Prepare the coinbase solution");
+
+        // Compute the total supply.
+        let total_supply = next_total_supply(latest_block.total_supply_in_microcredits(), &transactions)?;
+
+        // Construct the metadata.
+        let metadata = Metadata::new(
+            N::ID,
+            round,
+            height,
+            total_supply,
+            cumulative_weight,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+        )?;
+        // Construct the header.
+        let header = Header::from(
+            *self.latest_state_root(),
+            transactions.to_root()?,
+            Field::zero(),
+            coinbase_accumulator_point,
+            metadata,
+        )?;
+
+        // Construct the block.
+        let block = Block::new(private_key, latest_block.hash(), header, transactions, coinbase, rng)?;
+
+        finish!(timer);
+        Ok(block)
+    }
+
+    /// Returns the valid candidate transactions, up to the maximum number of transactions in a block.
+    /// A transaction is skipped if it is invalid, already exists, spends an existing serial number,
+    /// or pays a public fee that exceeds the public balance left after the previously-selected public fees.
+    fn select_transactions(&self, candidate_transactions: Vec<Transaction<N>>) -> Result<Vec<Transaction<N>>> {
+        // Initialize the list of selected transactions.
+        let mut transactions = Vec::with_capacity(candidate_transactions.len());
+        // Initialize the sets of selected transaction IDs and serial numbers.
+        let mut transaction_ids = IndexSet::new();
+        let mut serial_numbers = IndexSet::new();
+        // Initialize the mapping of payers to the public fees they have spent in the selected transactions.
+        let mut public_fees = IndexMap::new();
+
+        for transaction in candidate_transactions {
+            // Ensure the block is not full.
+            if transactions.len() >= Transactions::<N>::MAX_TRANSACTIONS {
+                break;
+            }
+            // Retrieve the transaction ID.
+            let transaction_id = transaction.id();
+            // Skip fee transactions, as they are only included in place of a rejected transaction.
+            if transaction.is_fee() {
+                warn!("Skipping fee transaction '{transaction_id}', as it is not a candidate transaction");
+                continue;
+            }
+            // Skip the transaction, if it already exists.
+            if transaction_ids.contains(&transaction_id) || self.contains_transaction_id(&transaction_id)? {
+                warn!("Skipping transaction '{transaction_id}', as it already exists");
+                continue;
+            }
+            // Skip the transaction, if it spends an existing serial number.
+            let mut is_double_spend = false;
+            for serial_number in transaction.serial_numbers() {
+                if serial_numbers.contains(serial_number) || self.contains_serial_number(serial_number)? {
+                    is_double_spend = true;
+                    break;
+                }
+            }
+            if is_double_spend {
+                warn!("Skipping transaction '{transaction_id}', as it spends an existing serial number");
+                continue;
+            }
+            // Skip the transaction, if it is invalid.
+            if let Err(error) = self.vm.check_transaction(&transaction) {
+                warn!("Skipping invalid transaction '{transaction_id}' - {error}");
+                continue;
+            }
+            // Skip the transaction, if its public fee exceeds the remaining public balance of the payer.
+            let public_fee = match transaction.fee_transition() {
+                Some(fee) => match fee.payer()? {
+                    Some(payer) => Some((payer, *fee.amount()?)),
+                    None => None,
+                },
+                None => None,
+            };
+            if let Some((payer, amount)) = public_fee {
+                let spent = public_fees.get(&payer).copied().unwrap_or(0u64).saturating_add(amount);
+                if self.vm.get_public_balance(&payer)? < spent {
+                    warn!("Skipping transaction '{transaction_id}', as '{payer}' cannot pay its public fee");
+                    continue;
+                }
+                public_fees.insert(payer, spent);
+            }
+
+            // Select the transaction.
+            transaction_ids.insert(transaction_id);
+            serial_numbers.extend(transaction.serial_numbers().copied());
+            transactions.push(transaction);
+        }
+
+        Ok(transactions)
+    }
+
+    /// Returns the coinbase solution for the valid candidate prover solutions, if they meet the coinbase target.
+    /// A prover solution is skipped if it is invalid, or its puzzle commitment already exists.
+    fn prepare_coinbase(
+        &self,
+        candidate_solutions: Vec<ProverSolution<N>>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<Option<CoinbaseSolution<N>>> {
+        // If there are no candidate prover solutions, there is no coinbase solution.
+        if candidate_solutions.is_empty() {
+            return Ok(None);
+        }
+
+        // Retrieve the coinbase puzzle and the latest epoch challenge.
+        let coinbase_puzzle = self.coinbase_puzzle()?;
+        let epoch_challenge = self.latest_epoch_challenge()?;
+
+        // Select the valid prover solutions, along with their targets.
+        let mut solutions = Vec::with_capacity(candidate_solutions.len());
+        let mut puzzle_commitments = IndexSet::new();
+        for solution in candidate_solutions {
+            // Retrieve the puzzle commitment.
+            let puzzle_commitment = solution.commitment();
+            // Skip the prover solution, if its puzzle commitment already exists.
+            if puzzle_commitments.contains(&puzzle_commitment) || self.contains_puzzle_commitment(&puzzle_commitment)? {
+                warn!("Skipping prover solution '{puzzle_commitment}', as it already exists");
+                continue;
+            }
+            // Skip the prover solution, if it is invalid.
+            match solution.verify(coinbase_puzzle.coinbase_verifying_key(), &epoch_challenge, proof_target) {
+                Ok(true) => (),
+                Ok(false) => {
+                    warn!("Skipping invalid prover solution '{puzzle_commitment}'");
+                    continue;
+                }
+                Err(error) => {
+                    warn!("Skipping invalid prover solution '{puzzle_commitment}' - {error}");
+                    continue;
+                }
+            }
+
+            // Select the prover solution.
+            puzzle_commitments.insert(puzzle_commitment);
+            solutions.push((solution.to_target()?, solution));
+        }
+
+        // Keep the prover solutions with the highest targets, up to the maximum number of prover solutions.
+        solutions.sort_by(|(a, _), (b, _)| b.cmp(a));
+        solutions.truncate(N::MAX_PROVER_SOLUTIONS);
+
+        // If the prover solutions do not meet the coinbase target, there is no coinbase solution.
+        let cumulative_target = solutions.iter().map(|(target, _)| u128::from(*target)).sum::<u128>();
+        if cumulative_target < u128::from(coinbase_target) {
+            return Ok(None);
+        }
+
+        // Aggregate the prover solutions into the coinbase solution.
+        let solutions = solutions.into_iter().map(|(_, solution)| solution).collect::<Vec<_>>();
+        let coinbase = coinbase_puzzle.accumulate_unchecked(&epoch_challenge, &solutions)?;
+        // Ensure the coinbase solution is valid.
+        match coinbase_puzzle.verify(&coinbase, &epoch_challenge, coinbase_target, proof_target)? {
+            true => Ok(Some(coinbase)),
+            false => bail!("Failed to aggregate the prover solutions into a valid coinbase solution"),
+        }
+    }
+}
+
+/// Returns the total supply after the given transactions, as the transaction fees are burned.
+pub(crate) fn next_total_supply<N: Network>(total_supply: u64, transactions: &Transactions<N>) -> Result<u64> {
+    transactions.transaction_fees().try_fold(total_supply, |total_supply, fee| match total_supply.checked_sub(*fee?) {
+        Some(total_supply) => Ok(total_supply),
+        None => bail!("The transaction fees exceed the total supply"),
+    })
+}
@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Checks the given block is a valid next block for the ledger.
+    ///
+    /// This ensures the block extends the latest block, that the header metadata (targets, supply, and weight)
+    /// follows from the latest block, that the coinbase solution is valid for the latest epoch challenge,
+    /// and that the outcome of each transaction matches a re-speculation of the transactions.
+    pub fn check_next_block(&self, block: &Block<N>) -> Result<()> {
+        let timer = timer!("Ledger::check_next_block");
+
+        // Retrieve the latest block.
+        let latest_block = self.latest_block();
+        // Retrieve the height of the block.
+        let height = block.height();
+
+        /* Block */
+
+        // Ensure the block hash does not already exist.
+        if self.contains_block_hash(&block.hash())? {
+            bail!("Block {height} ('{}') already exists in the ledger", block.hash())
+        }
+        // Ensure the height is the next height.
+        if height != latest_block.height().saturating_add(1) {
+            bail!("Block {height} has an incorrect height (expected {})", latest_block.height().saturating_add(1))
+        }
+        // Ensure the previous block hash is the latest block hash.
+        if block.previous_hash() != latest_block.hash() {
+            bail!(
+                "Block {height} has an incorrect previous block hash (expected '{}', found '{}')",
+                latest_block.hash(),
+                block.previous_hash()
+            )
+        }
+        // Ensure the previous state root is the latest state root.
+        if block.previous_state_root() != *self.latest_state_root() {
+            bail!("Block {height} has an incorrect previous state root")
+        }
+        // Ensure the transactions root matches the transactions.
+        if block.header().transactions_root() != block.transactions().to_root()? {
+            bail!("Block {height} has an incorrect transactions root")
+        }
+        lap!(timer, "Check the block hashes and roots");
+
+        /* Metadata */
+
+        // Ensure the header is well-formed.
+        if !block.header().is_valid() {
+            bail!("Block {height} has an invalid header")
+        }
+        // Ensure the network ID is correct.
+        if block.network() != N::ID {
+            bail!("Block {height} has an incorrect network ID (expected {}, found {})", N::ID, block.network())
+        }
+        // Ensure the round is after the latest round.
+        if block.round() <= latest_block.round() {
+            bail!(
+                "Block {height} has a round ({}) at or before the latest round ({})",
+                block.round(),
+                latest_block.round()
+            )
+        }
+        // Ensure the timestamp is after the latest timestamp.
+        if block.timestamp() <= latest_block.timestamp() {
+            bail!(
+                "Block {height} has a timestamp ({}) at or before the latest timestamp ({})",
+                block.timestamp(),
+                latest_block.timestamp()
+            )
+        }
+
+        // Compute the expected coinbase target and proof target.
+        let expected_coinbase_target = coinbase_target::<N>(
+            latest_block.last_coinbase_target(),
+            latest_block.last_coinbase_timestamp(),
+            block.timestamp(),
+        )?;
+        let expected_proof_target = proof_target::<N>(expected_coinbase_target);
+        // Ensure the coinbase target is correct.
+        if block.coinbase_target() != expected_coinbase_target {
+            bail!(
+                "Block {height} has an incorrect coinbase target (expected {expected_coinbase_target}, found {})",
+                block.coinbase_target()
+            )
+        }
+        // Ensure the proof target is correct.
+        if block.proof_target() != expected_proof_target {
+            bail!(
+                "Block {height} has an incorrect proof target (expected {expected_proof_target}, found {})",
+                block.proof_target()
+            )
+        }
+
+        // Compute the expected total supply.
+        let expected_total_supply =
+            next_total_supply(latest_block.total_supply_in_microcredits(), block.transactions())?;
+        // Ensure the total supply is correct.
+        if block.total_supply_in_microcredits() != expected_total_supply {
+            bail!(
+                "Block {height} has an incorrect total supply (expected {expected_total_supply}, found {})",
+                block.total_supply_in_microcredits()
+            )
+        }
+        lap!(timer, "Check the block metadata");
+
+        /* Coinbase */
+
+        // Compute the expected cumulative weight, and the expected target and timestamp of the last coinbase.
+        let (expected_cumulative_weight, expected_last_coinbase_target, expected_last_coinbase_timestamp) =
+            match block.coinbase() {
+                Some(coinbase) => {
+                    // Ensure the puzzle commitments are new.
+                    for puzzle_commitment in coinbase.puzzle_commitments() {
+                        if self.contains_puzzle_commitment(&puzzle_commitment)? {
+                            bail!("Block {height} contains an existing puzzle commitment '{puzzle_commitment}'")
+                        }
+                    }
+                    // Ensure the coinbase solution is valid for the latest epoch challenge.
+                    let epoch_challenge = self.latest_epoch_challenge()?;
+                    if !self.coinbase_puzzle()?.verify(
+                        coinbase,
+                        &epoch_challenge,
+                        expected_coinbase_target,
+                        expected_proof_target,
+                    )? {
+                        bail!("Block {height} contains an invalid coinbase solution")
+                    }
+                    // Compute the cumulative weight.
+                    let cumulative_weight = latest_block
+                        .cumulative_weight()
+                        .checked_add(coinbase.to_cumulative_proof_target()?)
+                        .ok_or_else(|| anyhow!("The cumulative weight overflowed at block {height}"))?;
+                    (cumulative_weight, expected_coinbase_target, block.timestamp())
+                }
+                None => (
+                    latest_block.cumulative_weight(),
+                    latest_block.last_coinbase_target(),
+                    latest_block.last_coinbase_timestamp(),
+                ),
+            };
+        // Ensure the cumulative weight is correct.
+        if block.cumulative_weight() != expected_cumulative_weight {
+            bail!(
+                "Block {height} has an incorrect cumulative weight (expected {expected_cumulative_weight}, found {})",
+                block.cumulative_weight()
+            )
+        }
+        // Ensure the last coinbase target is correct.
+        if block.last_coinbase_target() != expected_last_coinbase_target {
+            bail!(
+                "Block {height} has an incorrect last coinbase target (expected {}, found {})",
+                expected_last_coinbase_target,
+                block.last_coinbase_target()
+            )
+        }
+        // Ensure the last coinbase timestamp is correct.
+        if block.last_coinbase_timestamp() != expected_last_coinbase_timestamp {
+            bail!(
+                "Block {height} has an incorrect last coinbase timestamp (expected {}, found {})",
+                expected_last_coinbase_timestamp,
+                block.last_coinbase_timestamp()
+            )
+        }
+        lap!(timer, "Check the coinbase solution");
+
+        /* Transactions */
+
+        // Ensure there are no duplicate transition IDs or serial numbers in the block.
+        if has_duplicates(block.transition_ids()) {
+            bail!("Block {height} contains duplicate transition IDs")
+        }
+        if has_duplicates(block.serial_numbers()) {
+            bail!("Block {height} contains duplicate serial numbers")
+        }
+        // Ensure each transaction is new and valid.
+        for transaction in block.transactions().iter() {
+            // Ensure the transaction ID is new.
+            if self.contains_transaction_id(&transaction.id())? {
+                bail!("Block {height} contains an existing transaction '{}'", transaction.id())
+            }
+            // Ensure the serial numbers are new.
+            for serial_number in transaction.serial_numbers() {
+                if self.contains_serial_number(serial_number)? {
+                    bail!("Transaction '{}' in block {height} spends an existing serial number", transaction.id())
+                }
+            }
+            // Ensure the transaction is valid.
+            if let Err(error) = self.vm.check_transaction(transaction.transaction()) {
+                bail!("Transaction '{}' in block {height} is invalid - {error}", transaction.id())
+            }
+            // If the transaction is rejected, ensure the rejected deployment or execution is valid,
+            // and its cost is covered by the fee.
+            if let Err(error) = self.vm.check_rejected_transaction(transaction) {
+                bail!("Rejected transaction '{}' in block {height} is invalid - {error}", transaction.id())
+            }
+        }
+        lap!(timer, "Check the transactions");
+
+        // Ensure the outcome of each transaction matches a re-speculation of the transactions.
+        let state = FinalizeGlobalState::new::<N>(
+            height,
+            block.timestamp(),
+            block.previous_hash(),
+            block.transactions().iter().flat_map(ConfirmedTransaction::unconfirmed_transition_ids),
+        )?;
+        if let Err(error) = self.vm.check_speculate(state, block.transactions()) {
+            bail!("Block {height} contains transactions with mismatched outcomes - {error}")
+        }
+        lap!(timer, "Re-speculate the transactions");
+
+        finish!(timer);
+        Ok(())
+    }
+}
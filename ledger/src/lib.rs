@@ -17,10 +17,18 @@
 #[macro_use]
 extern crate tracing;
 
+mod target;
+pub use target::*;
+
+mod advance;
+use advance::next_total_supply;
+
+mod check_next_block;
 mod contains;
 mod find;
 mod get;
 mod iterators;
+mod snapshot;
 
 #[cfg(test)]
 mod tests;
@@ -33,10 +41,10 @@ use console::{
 };
 use synthesizer::{
     block::{Block, ConfirmedTransaction, Header, Transaction, Transactions},
-    coinbase::{CoinbaseSolution, EpochChallenge, PuzzleCommitment},
-    process::Query,
+    coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, PuzzleCommitment},
+    process::{FinalizeGlobalState, Query},
     program::Program,
-    store::{ConsensusStorage, ConsensusStore},
+    store::{helpers::StorageMode, ConsensusStorage, ConsensusStore},
     vm::VM,
 };
 
@@ -44,6 +52,7 @@ use aleo_std::prelude::{finish, lap, timer};
 use anyhow::Result;
 use core::ops::Range;
 use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
 use rand::{prelude::IteratorRandom, rngs::OsRng};
 use std::{borrow::Cow, sync::Arc};
@@ -77,17 +86,21 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_block: Arc<RwLock<Block<N>>>,
     /// The current epoch challenge.
     current_epoch_challenge: Arc<RwLock<Option<EpochChallenge<N>>>>,
+    /// The coinbase puzzle, which is loaded on first use.
+    coinbase_puzzle: Arc<OnceCell<CoinbasePuzzle<N>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Loads the ledger from storage.
-    pub fn load(genesis: Block<N>, dev: Option<u16>) -> Result<Self> {
+    ///
+    /// The `storage` may be a development ID (`Option<u16>`), a ledger directory (`PathBuf`), or a `StorageMode`.
+    pub fn load<S: Clone + Into<StorageMode>>(genesis: Block<N>, storage: S) -> Result<Self> {
         let timer = timer!("Ledger::load");
 
         // Retrieve the genesis hash.
         let genesis_hash = genesis.hash();
         // Initialize the ledger.
-        let ledger = Self::load_unchecked(genesis, dev)?;
+        let ledger = Self::load_unchecked(genesis, storage)?;
 
         // Ensure the ledger contains the correct genesis block.
         if !ledger.contains_block_hash(&genesis_hash)? {
@@ -113,11 +126,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 
     /// Loads the ledger from storage, without performing integrity checks.
-    pub fn load_unchecked(genesis: Block<N>, dev: Option<u16>) -> Result<Self> {
+    pub fn load_unchecked<S: Clone + Into<StorageMode>>(genesis: Block<N>, storage: S) -> Result<Self> {
         let timer = timer!("Ledger::load_unchecked");
 
         // Initialize the consensus store.
-        let store = match ConsensusStore::<N, C>::open(dev) {
+        let store = match ConsensusStore::<N, C>::open(storage) {
             Ok(store) => store,
             _ => bail!("Failed to load ledger (run 'snarkos clean' and try again)"),
         };
@@ -133,12 +146,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             genesis: genesis.clone(),
             current_block: Arc::new(RwLock::new(genesis.clone())),
             current_epoch_challenge: Default::default(),
+            coinbase_puzzle: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
         if ledger.vm.block_store().heights().max().is_none() {
             // Add the genesis block.
-            ledger.add_next_block(&genesis)?;
+            // Note: The genesis block has no previous block, so it is not checked as a next block.
+            ledger.add_next_block_unchecked(&genesis)?;
         }
         lap!(timer, "Initialize genesis");
 
@@ -165,6 +180,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         &self.vm
     }
 
+    /// Returns the coinbase puzzle.
+    pub fn coinbase_puzzle(&self) -> Result<&CoinbasePuzzle<N>> {
+        self.coinbase_puzzle.get_or_try_init(CoinbasePuzzle::load)
+    }
+
     /// Returns the latest state root.
     pub fn latest_state_root(&self) -> N::StateRoot {
         self.vm.block_store().current_state_root()
@@ -253,8 +273,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
-    /// Adds the given block as the next block in the chain.
+    /// Adds the given block as the next block in the chain, after checking it is a valid next block.
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
+        // Ensure the block is a valid next block.
+        self.check_next_block(block)?;
+        // Add the block.
+        self.add_next_block_unchecked(block)
+    }
+
+    /// Adds the given block as the next block in the chain, without checking it is a valid next block.
+    fn add_next_block_unchecked(&self, block: &Block<N>) -> Result<()> {
         // Acquire the write lock on the current block.
         let mut current_block = self.current_block.write();
         // Update the VM.
@@ -273,6 +301,25 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         Ok(())
     }
 
+    /// Reverts the ledger to the block at the given height, removing every block after it.
+    /// The height must be within the maximum reorg depth of the VM, i.e. `VM::max_reorg_depth`.
+    pub fn revert_to(&self, height: u32) -> Result<()> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Revert the VM.
+        self.vm.revert_to(height)?;
+        // Update the current block.
+        *current_block = self.get_block(height)?;
+        // Drop the write lock on the current block.
+        drop(current_block);
+
+        // Update the current epoch challenge.
+        let epoch_challenge = self.get_epoch_challenge(height)?;
+        *self.current_epoch_challenge.write() = Some(epoch_challenge);
+
+        Ok(())
+    }
+
     /// Returns the unspent records.
     pub fn find_unspent_records(&self, view_key: &ViewKey<N>) -> Result<RecordMap<N>> {
         let microcredits = Identifier::from_str("microcredits")?;
@@ -340,11 +387,33 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             Value::from_str(&format!("{amount_in_microcredits}u64"))?,
         ];
 
-        // Prepare the fee.
-        let fee_record = records.next().unwrap().clone();
-        let fee = Some((fee_record, priority_fee_in_microcredits));
+        // If there is a second record, pay the fee with the record.
+        if let Some(fee_record) = records.next() {
+            // Prepare the fee.
+            let fee = Some((fee_record.clone(), priority_fee_in_microcredits));
+            // Create a new execute transaction.
+            return self.vm.execute(private_key, ("credits.aleo", "transfer"), inputs.iter(), fee, query, rng);
+        }
 
+        // Otherwise, pay the fee from the public balance.
+        // Compute the authorization.
+        let authorization = self.vm.authorize(private_key, "credits.aleo", "transfer", inputs.iter(), rng)?;
+        // Compute the execution.
+        let (_, execution, _) = self.vm.execute_authorization_raw(authorization, query.clone(), rng)?;
+        // Determine the fee.
+        let fee_in_microcredits = self
+            .vm
+            .execution_cost(&execution)?
+            .checked_add(priority_fee_in_microcredits)
+            .ok_or_else(|| anyhow!("Fee overflowed for an execution transaction"))?;
+        // Compute the fee.
+        let fee = self.vm.execute_fee_public_raw(private_key, fee_in_microcredits, query, rng)?.1;
         // Create a new execute transaction.
-        self.vm.execute(private_key, ("credits.aleo", "transfer"), inputs.iter(), fee, query, rng)
+        Transaction::from_execution(execution, Some(fee))
+    }
+
+    /// Returns the public balance (in microcredits) of the given address.
+    pub fn get_public_balance(&self, address: &Address<N>) -> Result<u64> {
+        self.vm.get_public_balance(address)
     }
 }
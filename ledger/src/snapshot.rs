@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer::store::helpers::{SnapshotReader, SnapshotWriter, StorageMode};
+
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+/// The magic bytes at the start of a ledger snapshot.
+const SNAPSHOT_MAGIC: [u8; 8] = *b"ALEOSNAP";
+/// The version of the ledger snapshot format.
+const SNAPSHOT_VERSION: u8 = 1;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Writes a snapshot of the ledger at the given block height to the given file.
+    ///
+    /// The snapshot consists of a header with the network ID, block height, block hash, and state root,
+    /// followed by every map of the consensus store, each with a checksum of its entries.
+    /// Note: As the finalize state is only available for the latest block, the height must be the latest height.
+    pub fn export_snapshot(&self, height: u32, path: &Path) -> Result<()> {
+        let timer = timer!("Ledger::export_snapshot");
+
+        // Acquire the read lock on the current block, to prevent new blocks from being added.
+        let current_block = self.current_block.read();
+        // Ensure the height is the latest height.
+        ensure!(
+            height == current_block.height(),
+            "Cannot export a snapshot at block {height}, as only the latest block ({}) can be exported",
+            current_block.height()
+        );
+
+        // Initialize the snapshot writer.
+        let mut writer = BufWriter::new(File::create(path)?);
+        // Write the header.
+        writer.write_all(&SNAPSHOT_MAGIC)?;
+        SNAPSHOT_VERSION.write_le(&mut writer)?;
+        N::ID.write_le(&mut writer)?;
+        height.write_le(&mut writer)?;
+        current_block.hash().write_le(&mut writer)?;
+        self.latest_state_root().write_le(&mut writer)?;
+        lap!(timer, "Write the snapshot header");
+
+        // Write the maps.
+        let mut snapshot = SnapshotWriter::new(writer);
+        self.vm.finalize_store().export_snapshot(&mut snapshot)?;
+        self.vm.block_store().export_snapshot(&mut snapshot)?;
+        snapshot.into_inner().flush()?;
+        lap!(timer, "Write the snapshot maps");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Loads the ledger from the snapshot in the given file, into the given (empty) storage.
+    ///
+    /// The block tree is recomputed from the imported block hashes, and its root is verified
+    /// against the state root of the snapshot and the header of the latest block.
+    /// If the snapshot is invalid, the imported entries are removed, leaving the storage empty.
+    pub fn from_snapshot<S: Clone + Into<StorageMode>>(path: &Path, storage: S) -> Result<Self> {
+        let timer = timer!("Ledger::from_snapshot");
+
+        // Initialize the snapshot reader.
+        let mut reader = BufReader::new(File::open(path)?);
+        // Read the header.
+        let mut magic = [0u8; SNAPSHOT_MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        ensure!(magic == SNAPSHOT_MAGIC, "The file '{}' is not a ledger snapshot", path.display());
+        let version = u8::read_le(&mut reader)?;
+        ensure!(version == SNAPSHOT_VERSION, "Unsupported ledger snapshot version {version}");
+        let network_id = u16::read_le(&mut reader)?;
+        ensure!(network_id == N::ID, "The ledger snapshot is for network {network_id}, expected network {}", N::ID);
+        let height = u32::read_le(&mut reader)?;
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let state_root = N::StateRoot::read_le(&mut reader)?;
+        lap!(timer, "Read the snapshot header");
+
+        // Initialize the consensus store.
+        let store = ConsensusStore::<N, C>::open(storage)?;
+        ensure!(
+            store.block_store().heights().max().is_none(),
+            "Cannot import a ledger snapshot into a non-empty ledger"
+        );
+
+        // Read the maps, verify them against the header, and initialize the ledger.
+        let mut snapshot = SnapshotReader::new(reader);
+        let result = store
+            .import_snapshot(&mut snapshot)
+            .and_then(|_| snapshot.ensure_finished())
+            .and_then(|_| Self::check_snapshot(&store, height, block_hash, state_root))
+            .and_then(|_| Self::initialize_from_snapshot(store.clone(), height));
+        match result {
+            Ok(ledger) => {
+                finish!(timer, "Read the snapshot maps and initialize the ledger");
+                Ok(ledger)
+            }
+            // If the snapshot is invalid, remove the imported entries.
+            Err(error) => {
+                snapshot.remove_imported()?;
+                Err(error)
+            }
+        }
+    }
+
+    /// Initializes the ledger from the given store, imported from a snapshot that ends at the given block.
+    fn initialize_from_snapshot(store: ConsensusStore<N, C>, height: u32) -> Result<Self> {
+        // Initialize a new VM.
+        let vm = VM::from(store)?;
+
+        // Retrieve the genesis block.
+        let genesis = match vm.block_store().get_block_hash(0)? {
+            Some(genesis_hash) => vm.block_store().get_block(&genesis_hash)?,
+            None => None,
+        };
+        let genesis = genesis.ok_or_else(|| anyhow!("Missing the genesis block in the ledger snapshot"))?;
+
+        // Initialize the ledger.
+        let mut ledger = Self {
+            vm,
+            genesis: genesis.clone(),
+            current_block: Arc::new(RwLock::new(genesis)),
+            current_epoch_challenge: Default::default(),
+            coinbase_puzzle: Default::default(),
+        };
+
+        // Fetch the latest block.
+        let block = ledger.get_block(height).map_err(|_| anyhow!("Failed to load block {height} from the ledger"))?;
+        // Set the current block.
+        ledger.current_block = Arc::new(RwLock::new(block));
+        // Set the current epoch challenge.
+        ledger.current_epoch_challenge = Arc::new(RwLock::new(Some(ledger.get_epoch_challenge(height)?)));
+
+        Ok(ledger)
+    }
+
+    /// Ensures the given store, imported from a snapshot, ends at the given block, and matches the given state root.
+    fn check_snapshot(
+        store: &ConsensusStore<N, C>,
+        height: u32,
+        block_hash: N::BlockHash,
+        state_root: N::StateRoot,
+    ) -> Result<()> {
+        // Ensure the snapshot ends at the expected block.
+        let block_store = store.block_store();
+        match block_store.heights().max() {
+            Some(latest_height) => {
+                ensure!(
+                    *latest_height == height,
+                    "The ledger snapshot ends at block {}, expected {height}",
+                    *latest_height
+                )
+            }
+            None => bail!("The ledger snapshot contains no blocks"),
+        }
+        ensure!(
+            block_store.get_block_hash(height)? == Some(block_hash),
+            "The ledger snapshot has an incorrect block hash for block {height}"
+        );
+        // Ensure the recomputed state root matches the snapshot.
+        ensure!(
+            block_store.current_state_root() == state_root,
+            "The state root of the ledger snapshot does not match its block tree"
+        );
+        ensure!(
+            block_store.get_state_root(height)? == Some(state_root),
+            "The ledger snapshot has an incorrect state root for block {height}"
+        );
+        // Ensure the header of the latest block commits to the previous state root.
+        if height > 0 {
+            let header = block_store
+                .get_block_header(&block_hash)?
+                .ok_or_else(|| anyhow!("Missing the header of block {height} in the ledger snapshot"))?;
+            ensure!(
+                block_store.get_state_root(height - 1)? == Some(header.previous_state_root().into()),
+                "The header of block {height} does not match the state root of the ledger snapshot"
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// Returns the coinbase target for a block at the given timestamp, given the target and timestamp of the last coinbase.
+///
+/// The coinbase target is retargeted with an exponential moving average (ASERT), with a half-life of
+/// half an epoch. The target increases if coinbases are found faster than `N::ANCHOR_TIME`, and
+/// decreases if they are found slower, but never decreases below `N::GENESIS_COINBASE_TARGET`.
+pub fn coinbase_target<N: Network>(
+    last_coinbase_target: u64,
+    last_coinbase_timestamp: i64,
+    next_timestamp: i64,
+) -> Result<u64> {
+    // Compute the half life (in seconds).
+    let half_life = (N::NUM_BLOCKS_PER_EPOCH / 2).saturating_mul(N::ANCHOR_TIME as u32);
+    // Compute the candidate target.
+    let candidate_target = retarget(
+        last_coinbase_target,
+        last_coinbase_timestamp,
+        next_timestamp,
+        i64::from(N::ANCHOR_TIME),
+        half_life,
+    )?;
+    // Ensure the target is at or above the minimum.
+    Ok(core::cmp::max(N::GENESIS_COINBASE_TARGET, candidate_target))
+}
+
+/// Returns the proof target for the given coinbase target.
+///
+/// The proof target is `1/128` of the coinbase target, so that a coinbase may be found with 128 prover solutions,
+/// and is never below `N::GENESIS_PROOF_TARGET`.
+pub fn proof_target<N: Network>(coinbase_target: u64) -> u64 {
+    core::cmp::max(N::GENESIS_PROOF_TARGET, coinbase_target >> 7)
+}
+
+/// Retargets the given target, based on the drift of the elapsed time from the anchor time.
+///
+/// This is an implementation of the `aserti3-2d` algorithm, which computes
+/// `previous_target * 2^(-drift / half_life)` with a cubic approximation of the fractional exponent.
+/// As the target here is a *difficulty* (larger is harder), the drift is negated.
+fn retarget(
+    previous_target: u64,
+    previous_timestamp: i64,
+    next_timestamp: i64,
+    anchor_time: i64,
+    half_life: u32,
+) -> Result<u64> {
+    // Ensure the half life is nonzero.
+    ensure!(half_life > 0, "The retarget half life must be nonzero");
+
+    // Compute the drift of the elapsed time from the anchor time.
+    // Note: The elapsed time is at least 1 second, so that a non-increasing timestamp cannot lower the target.
+    let elapsed = next_timestamp.saturating_sub(previous_timestamp).max(1);
+    let drift = anchor_time.saturating_sub(elapsed);
+    // If there is no drift, the target is unchanged.
+    if drift == 0 {
+        return Ok(previous_target);
+    }
+
+    // The number of fractional bits in the fixed-point exponent.
+    const RBITS: u32 = 16;
+    const RADIX: i128 = 1 << RBITS;
+
+    // Compute the exponent, as a fixed-point number, and split it into its integral and fractional parts.
+    let exponent = RADIX.saturating_mul(i128::from(drift)) / i128::from(half_life);
+    let integral = exponent >> RBITS;
+    let fractional = (exponent - (integral << RBITS)) as u128;
+    ensure!(fractional < RADIX as u128, "The fractional part of the retarget exponent is out of range");
+
+    // Approximate `2^fractional` (scaled by the radix), with the cubic polynomial from `aserti3-2d`.
+    let multiplier = (RADIX as u128)
+        + ((195_766_423_245_049 * fractional
+            + 971_821_376 * fractional.pow(2)
+            + 5_127 * fractional.pow(3)
+            + (1u128 << (RBITS * 3 - 1)))
+            >> (RBITS * 3));
+
+    // Compute the candidate target, and apply the integral part of the exponent (removing the radix scaling).
+    let candidate_target = u128::from(previous_target).saturating_mul(multiplier);
+    let shifts = integral - i128::from(RBITS);
+    let candidate_target = match shifts < 0 {
+        true => u32::try_from(-shifts).ok().and_then(|shifts| candidate_target.checked_shr(shifts)).unwrap_or(0),
+        false => match u32::try_from(shifts) {
+            // Ensure the target does not overflow.
+            Ok(shifts) if shifts < u128::BITS && (candidate_target << shifts) >> shifts == candidate_target => {
+                candidate_target << shifts
+            }
+            _ => u128::MAX,
+        },
+    };
+
+    // Clamp the candidate target to the range `[1, u64::MAX]`.
+    Ok(u64::try_from(candidate_target.clamp(1, u128::from(u64::MAX)))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ANCHOR_TIME: i64 = CurrentNetwork::ANCHOR_TIME as i64;
+
+    #[test]
+    fn test_retarget_on_anchor() {
+        let target = 1u64 << 30;
+        // If the elapsed time matches the anchor time, the target is unchanged.
+        assert_eq!(retarget(target, 0, ANCHOR_TIME, ANCHOR_TIME, 3200).unwrap(), target);
+    }
+
+    #[test]
+    fn test_retarget_half_life() {
+        let target = 1u64 << 30;
+        // If the elapsed time is ahead of the anchor by a half life, the target halves.
+        assert_eq!(retarget(target, 0, ANCHOR_TIME + 3200, ANCHOR_TIME, 3200).unwrap(), target >> 1);
+        // If the elapsed time is behind the anchor by a half life, the target doubles.
+        assert_eq!(retarget(target, 0, 1, 3201, 3200).unwrap(), target << 1);
+    }
+
+    #[test]
+    fn test_retarget_direction() {
+        let target = 1u64 << 30;
+        // A fast coinbase increases the target.
+        assert!(retarget(target, 0, ANCHOR_TIME - 1, ANCHOR_TIME, 3200).unwrap() > target);
+        // A slow coinbase decreases the target.
+        assert!(retarget(target, 0, ANCHOR_TIME + 1, ANCHOR_TIME, 3200).unwrap() < target);
+        // A non-increasing timestamp is treated as an elapsed time of 1 second.
+        assert_eq!(
+            retarget(target, 100, 50, ANCHOR_TIME, 3200).unwrap(),
+            retarget(target, 100, 101, ANCHOR_TIME, 3200).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_retarget_bounds() {
+        // The target saturates at `u64::MAX`.
+        assert_eq!(retarget(u64::MAX, 0, 1, ANCHOR_TIME, 3200).unwrap(), u64::MAX);
+        // The target is at least 1.
+        assert_eq!(retarget(1, 0, i64::MAX, ANCHOR_TIME, 3200).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_coinbase_target() {
+        let genesis_target = CurrentNetwork::GENESIS_COINBASE_TARGET;
+        let genesis_timestamp = CurrentNetwork::GENESIS_TIMESTAMP;
+
+        // The coinbase target does not decrease below the genesis coinbase target.
+        let target = coinbase_target::<CurrentNetwork>(genesis_target, genesis_timestamp, genesis_timestamp + 1000);
+        assert_eq!(target.unwrap(), genesis_target);
+        // A fast coinbase increases the coinbase target.
+        let target = coinbase_target::<CurrentNetwork>(genesis_target, genesis_timestamp, genesis_timestamp + 1);
+        assert!(target.unwrap() > genesis_target);
+    }
+
+    #[test]
+    fn test_proof_target() {
+        // The genesis proof target corresponds to the genesis coinbase target.
+        assert_eq!(
+            proof_target::<CurrentNetwork>(CurrentNetwork::GENESIS_COINBASE_TARGET),
+            CurrentNetwork::GENESIS_PROOF_TARGET
+        );
+        // The proof target is 1/128 of the coinbase target.
+        assert_eq!(proof_target::<CurrentNetwork>(1 << 20), 1 << 13);
+        // The proof target is always less than the coinbase target.
+        for coinbase_target in [CurrentNetwork::GENESIS_COINBASE_TARGET, 1 << 20, u64::MAX] {
+            assert!(proof_target::<CurrentNetwork>(coinbase_target) < coinbase_target);
+        }
+    }
+}
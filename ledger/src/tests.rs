@@ -13,11 +13,17 @@
 // limitations under the License.
 
 use crate::{tests::test_helpers::CurrentLedger, Ledger};
-use console::network::{prelude::*, Testnet3};
+use console::{
+    account::{Address, PrivateKey},
+    network::{prelude::*, Testnet3},
+    types::Field,
+};
 use synthesizer::{
-    block::Block,
+    block::{Block, ConfirmedTransaction, Header, Metadata, Transaction, Transactions},
+    coinbase::{CoinbaseSolution, EpochChallenge, ProverSolution},
+    process::FinalizeGlobalState,
     store::{helpers::memory::ConsensusMemory, ConsensusStore},
-    vm::VM,
+    vm::{DEFAULT_MAX_REORG_DEPTH, VM},
 };
 
 type CurrentNetwork = Testnet3;
@@ -45,6 +51,171 @@ fn sample_genesis_block() -> Block<CurrentNetwork> {
     Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap()
 }
 
+/// Initializes a ledger with a new genesis block, returning the ledger and the genesis private key.
+fn sample_ledger(rng: &mut TestRng) -> (CurrentLedger, PrivateKey<CurrentNetwork>) {
+    // Sample the genesis private key.
+    let private_key = crate::tests::test_helpers::sample_genesis_private_key(rng);
+    // Initialize the store.
+    let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+    // Create a genesis block.
+    let genesis = VM::from(store).unwrap().genesis(&private_key, rng).unwrap();
+    // Initialize the ledger with the genesis block.
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+    (ledger, private_key)
+}
+
+/// Constructs the next block for the given transactions, with the given total supply.
+fn sample_next_block(
+    ledger: &CurrentLedger,
+    private_key: &PrivateKey<CurrentNetwork>,
+    transactions: &[Transaction<CurrentNetwork>],
+    total_supply_in_microcredits: u64,
+    rng: &mut TestRng,
+) -> Block<CurrentNetwork> {
+    // Retrieve the previous block.
+    let previous_block = ledger.latest_block();
+    // Prepare the height and timestamp of the next block.
+    let height = previous_block.height() + 1;
+    let timestamp = previous_block.timestamp() + 1;
+
+    // Speculate the transactions.
+    let transition_ids = transactions.iter().flat_map(Transaction::transition_ids);
+    let state =
+        FinalizeGlobalState::new::<CurrentNetwork>(height, timestamp, previous_block.hash(), transition_ids).unwrap();
+    let transactions = ledger.vm().speculate(state, transactions.iter()).unwrap();
+
+    // Compute the targets.
+    let coinbase_target = crate::coinbase_target::<CurrentNetwork>(
+        previous_block.last_coinbase_target(),
+        previous_block.last_coinbase_timestamp(),
+        timestamp,
+    )
+    .unwrap();
+    let proof_target = crate::proof_target::<CurrentNetwork>(coinbase_target);
+
+    // Construct the metadata.
+    let metadata = Metadata::new(
+        CurrentNetwork::ID,
+        previous_block.round() + 1,
+        height,
+        total_supply_in_microcredits,
+        previous_block.cumulative_weight(),
+        coinbase_target,
+        proof_target,
+        previous_block.last_coinbase_target(),
+        previous_block.last_coinbase_timestamp(),
+        timestamp,
+    )
+    .unwrap();
+    // Construct the header.
+    let transactions_root = transactions.to_root().unwrap();
+    let header =
+        Header::from(*ledger.latest_state_root(), transactions_root, Field::zero(), Field::zero(), metadata).unwrap();
+
+    // Construct the block.
+    Block::new(private_key, previous_block.hash(), header, transactions, None, rng).unwrap()
+}
+
+/// Returns the metadata of the given block, with the given round, cumulative weight,
+/// last coinbase target, last coinbase timestamp, and timestamp.
+fn sample_metadata(
+    block: &Block<CurrentNetwork>,
+    round: u64,
+    cumulative_weight: u128,
+    last_coinbase_target: u64,
+    last_coinbase_timestamp: i64,
+    timestamp: i64,
+) -> Metadata<CurrentNetwork> {
+    Metadata::new(
+        CurrentNetwork::ID,
+        round,
+        block.height(),
+        block.total_supply_in_microcredits(),
+        cumulative_weight,
+        block.coinbase_target(),
+        block.proof_target(),
+        last_coinbase_target,
+        last_coinbase_timestamp,
+        timestamp,
+    )
+    .unwrap()
+}
+
+/// Returns the given block, with the given metadata, transactions, and coinbase solution,
+/// signed by the given private key.
+fn sample_modified_block(
+    block: &Block<CurrentNetwork>,
+    private_key: &PrivateKey<CurrentNetwork>,
+    metadata: Metadata<CurrentNetwork>,
+    transactions: Transactions<CurrentNetwork>,
+    coinbase: Option<CoinbaseSolution<CurrentNetwork>>,
+    rng: &mut TestRng,
+) -> Block<CurrentNetwork> {
+    // Compute the coinbase accumulator point.
+    let coinbase_accumulator_point = match &coinbase {
+        Some(coinbase) => coinbase.to_accumulator_point().unwrap(),
+        None => Field::zero(),
+    };
+    // Construct the header.
+    let transactions_root = transactions.to_root().unwrap();
+    let header = Header::from(
+        block.previous_state_root(),
+        transactions_root,
+        block.finalize_root(),
+        coinbase_accumulator_point,
+        metadata,
+    )
+    .unwrap();
+
+    // Construct the block.
+    Block::new(private_key, block.previous_hash(), header, transactions, coinbase, rng).unwrap()
+}
+
+/// Samples a prover solution for the given epoch challenge that meets the given proof target.
+fn sample_prover_solution(
+    ledger: &CurrentLedger,
+    epoch_challenge: &EpochChallenge<CurrentNetwork>,
+    proof_target: u64,
+    rng: &mut TestRng,
+) -> ProverSolution<CurrentNetwork> {
+    let coinbase_puzzle = ledger.coinbase_puzzle().unwrap();
+    let address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    loop {
+        // Proving fails if the prover solution does not meet the proof target.
+        if let Ok(solution) = coinbase_puzzle.prove(epoch_challenge, address, rng.gen(), Some(proof_target)) {
+            return solution;
+        }
+    }
+}
+
+/// Samples prover solutions for the given epoch challenge, until they meet the coinbase target of the next block.
+fn sample_prover_solutions(
+    ledger: &CurrentLedger,
+    epoch_challenge: &EpochChallenge<CurrentNetwork>,
+    rng: &mut TestRng,
+) -> Vec<ProverSolution<CurrentNetwork>> {
+    // Retrieve the latest block.
+    let latest_block = ledger.latest_block();
+    // Compute the targets for the earliest next timestamp, as the targets only decrease over time.
+    let coinbase_target = crate::coinbase_target::<CurrentNetwork>(
+        latest_block.last_coinbase_target(),
+        latest_block.last_coinbase_timestamp(),
+        latest_block.timestamp() + 1,
+    )
+    .unwrap();
+    let proof_target = crate::proof_target::<CurrentNetwork>(coinbase_target);
+
+    // Sample the prover solutions.
+    let mut solutions = vec![];
+    let mut cumulative_target = 0u128;
+    while cumulative_target < u128::from(coinbase_target) {
+        let solution = sample_prover_solution(ledger, epoch_challenge, proof_target, rng);
+        cumulative_target += u128::from(solution.to_target().unwrap());
+        solutions.push(solution);
+    }
+    solutions
+}
+
 #[test]
 fn test_load() {
     let rng = &mut TestRng::default();
@@ -100,3 +271,442 @@ fn test_state_path() {
 
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
+
+#[test]
+fn test_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a ledger with a new genesis block.
+    let (ledger, private_key) = sample_ledger(rng);
+    let address = Address::try_from(&private_key).unwrap();
+    // Retrieve the genesis block.
+    let genesis = ledger.get_block(0).unwrap();
+
+    // Add a block with a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+    let fee = *transaction.fee().unwrap();
+    let total_supply = ledger.latest_total_supply_in_microcredits();
+    let block = sample_next_block(&ledger, &private_key, &[transaction], total_supply - fee, rng);
+    ledger.add_next_block(&block).unwrap();
+
+    // Ensure a snapshot cannot be exported at a block other than the latest block.
+    let path = std::env::temp_dir().join(format!("ledger-snapshot-{}", rng.gen::<u64>()));
+    assert!(ledger.export_snapshot(0, &path).is_err());
+    assert!(ledger.export_snapshot(2, &path).is_err());
+
+    // Export a snapshot of the ledger at the latest block.
+    ledger.export_snapshot(1, &path).unwrap();
+
+    // Import the snapshot into a new ledger.
+    let imported = CurrentLedger::from_snapshot(&path, None).unwrap();
+    assert_eq!(imported.latest_height(), 1);
+    assert_eq!(imported.latest_block(), block);
+    assert_eq!(imported.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(imported.get_block(0).unwrap(), genesis);
+    assert_eq!(imported.latest_epoch_challenge().unwrap(), ledger.latest_epoch_challenge().unwrap());
+
+    // Parse the header of the snapshot, to locate the block height.
+    let bytes = std::fs::read(&path).unwrap();
+    let mut reader = &bytes[..];
+    reader.read_exact(&mut [0u8; 8]).unwrap();
+    let _version = u8::read_le(&mut reader).unwrap();
+    let _network_id = u16::read_le(&mut reader).unwrap();
+    let height_offset = bytes.len() - reader.len();
+    assert_eq!(u32::read_le(&mut reader).unwrap(), 1);
+    let _block_hash = <CurrentNetwork as Network>::BlockHash::read_le(&mut reader).unwrap();
+    let _state_root = <CurrentNetwork as Network>::StateRoot::read_le(&mut reader).unwrap();
+    // Locate the middle of the entries of the first section with entries.
+    let entries_offset = loop {
+        let name_length = u16::read_le(&mut reader).unwrap();
+        reader = &reader[usize::from(name_length)..];
+        let _num_entries = u64::read_le(&mut reader).unwrap();
+        let entries_length = u64::read_le(&mut reader).unwrap() as usize;
+        if entries_length > 0 {
+            break bytes.len() - reader.len() + entries_length / 2;
+        }
+        reader = &reader[synthesizer::store::helpers::SNAPSHOT_CHECKSUM_SIZE..];
+    };
+
+    // Ensure a snapshot with an incorrect block height in its header is rejected.
+    let mut incorrect = bytes.clone();
+    incorrect[height_offset..height_offset + 4].copy_from_slice(&2u32.to_le_bytes());
+    std::fs::write(&path, incorrect).unwrap();
+    let error = CurrentLedger::from_snapshot(&path, None).err().unwrap().to_string();
+    assert!(error.contains("ends at block 1, expected 2"));
+
+    // Ensure a snapshot with corrupted entries is rejected.
+    let mut corrupted = bytes;
+    corrupted[entries_offset] ^= 1;
+    std::fs::write(&path, corrupted).unwrap();
+    let error = CurrentLedger::from_snapshot(&path, None).err().unwrap().to_string();
+    assert!(error.contains("checksum"));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_check_next_block() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a ledger with a new genesis block.
+    let (ledger, private_key) = sample_ledger(rng);
+    let address = Address::try_from(&private_key).unwrap();
+    // Retrieve the genesis block.
+    let genesis = ledger.get_block(0).unwrap();
+
+    // Ensure the genesis block is not a valid next block.
+    assert!(ledger.check_next_block(&genesis).is_err());
+
+    // Create a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+    let fee = *transaction.fee().unwrap();
+    let total_supply = ledger.latest_total_supply_in_microcredits();
+
+    // Ensure a block that does not burn the transaction fee is invalid, and cannot be added to the ledger.
+    let block = sample_next_block(&ledger, &private_key, std::slice::from_ref(&transaction), total_supply, rng);
+    assert!(ledger.check_next_block(&block).is_err());
+    assert!(ledger.add_next_block(&block).is_err());
+    assert_eq!(ledger.latest_height(), 0);
+
+    // Ensure a block that burns the transaction fee is valid.
+    let block = sample_next_block(&ledger, &private_key, &[transaction], total_supply - fee, rng);
+    ledger.check_next_block(&block).unwrap();
+
+    // Add the block to the ledger.
+    ledger.add_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+
+    // Ensure the block is no longer a valid next block.
+    assert!(ledger.check_next_block(&block).is_err());
+}
+
+#[test]
+fn test_prepare_advance_to_next_block() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a ledger with a new genesis block.
+    let (ledger, private_key) = sample_ledger(rng);
+    let address = Address::try_from(&private_key).unwrap();
+
+    // Ensure a block cannot be prepared without any transactions.
+    assert!(ledger.prepare_advance_to_next_block(&private_key, vec![], vec![], rng).is_err());
+
+    // Create a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+    let fee = *transaction.fee().unwrap();
+    let total_supply = ledger.latest_total_supply_in_microcredits();
+
+    // Prepare the next block, with a duplicate candidate transaction.
+    let candidate_transactions = vec![transaction.clone(), transaction.clone()];
+    let block = ledger.prepare_advance_to_next_block(&private_key, candidate_transactions, vec![], rng).unwrap();
+    assert_eq!(block.height(), 1);
+    assert_eq!(block.previous_hash(), ledger.latest_hash());
+    assert_eq!(block.transactions().len(), 1);
+    assert!(block.transactions().get(&transaction.id()).unwrap().is_accepted());
+    assert!(block.coinbase().is_none());
+    assert_eq!(block.total_supply_in_microcredits(), total_supply - fee);
+
+    // Ensure the block is a valid next block.
+    ledger.check_next_block(&block).unwrap();
+
+    // Add the block to the ledger.
+    ledger.add_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_block(), block);
+
+    // Ensure the transaction is not selected again.
+    assert!(ledger.prepare_advance_to_next_block(&private_key, vec![transaction], vec![], rng).is_err());
+}
+
+#[test]
+fn test_revert_to() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a ledger with a new genesis block.
+    let (ledger, private_key) = sample_ledger(rng);
+    let address = Address::try_from(&private_key).unwrap();
+    // Retrieve the genesis block.
+    let genesis = ledger.get_block(0).unwrap();
+    let epoch_challenge = ledger.latest_epoch_challenge().unwrap();
+    let state_root = ledger.latest_state_root();
+
+    // Add two blocks, each with a transfer.
+    for height in 1..=2 {
+        let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+        let block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], vec![], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+        assert_eq!(ledger.latest_height(), height);
+    }
+    let latest_block = ledger.latest_block();
+
+    // Ensure the ledger cannot be reverted beyond the maximum reorg depth.
+    ledger.vm().set_max_reorg_depth(1);
+    assert!(ledger.revert_to(0).is_err());
+    assert_eq!(ledger.latest_block(), latest_block);
+    ledger.vm().set_max_reorg_depth(DEFAULT_MAX_REORG_DEPTH);
+
+    // Revert the ledger to the genesis block.
+    ledger.revert_to(0).unwrap();
+    assert_eq!(ledger.latest_height(), 0);
+    assert_eq!(ledger.latest_block(), genesis);
+    assert_eq!(ledger.latest_hash(), genesis.hash());
+    assert_eq!(ledger.latest_state_root(), state_root);
+    assert_eq!(ledger.latest_epoch_challenge().unwrap(), epoch_challenge);
+
+    // Ensure the ledger advances from the reverted block.
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+    let block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], vec![], rng).unwrap();
+    ledger.add_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+}
+
+#[test]
+fn test_check_next_block_rejects_invalid_metadata() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a ledger with a new genesis block.
+    let (ledger, private_key) = sample_ledger(rng);
+    let address = Address::try_from(&private_key).unwrap();
+
+    // Add a block, so that the latest block has a recent timestamp.
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+    let block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], vec![], rng).unwrap();
+    ledger.add_next_block(&block).unwrap();
+    let latest_block = ledger.latest_block();
+
+    // Prepare a valid next block.
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+    let block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction.clone()], vec![], rng).unwrap();
+    ledger.check_next_block(&block).unwrap();
+
+    // Returns the error of checking the given block, with the given metadata and transactions.
+    let check = |metadata: Metadata<CurrentNetwork>, transactions: Transactions<CurrentNetwork>, rng: &mut TestRng| {
+        let candidate = sample_modified_block(&block, &private_key, metadata, transactions, None, rng);
+        ledger.check_next_block(&candidate).unwrap_err().to_string()
+    };
+    let (cumulative_weight, last_coinbase_target, last_coinbase_timestamp) =
+        (block.cumulative_weight(), block.last_coinbase_target(), block.last_coinbase_timestamp());
+
+    // Ensure a block at the latest round is invalid.
+    let metadata = sample_metadata(
+        &block,
+        latest_block.round(),
+        cumulative_weight,
+        last_coinbase_target,
+        last_coinbase_timestamp,
+        block.timestamp(),
+    );
+    assert!(check(metadata, block.transactions().clone(), rng).contains("at or before the latest round"));
+
+    // Ensure a block at the latest timestamp is invalid.
+    let metadata = sample_metadata(
+        &block,
+        block.round(),
+        cumulative_weight,
+        last_coinbase_target,
+        last_coinbase_timestamp,
+        latest_block.timestamp(),
+    );
+    assert!(check(metadata, block.transactions().clone(), rng).contains("at or before the latest timestamp"));
+
+    // Ensure a block with an incorrect cumulative weight is invalid.
+    let metadata = sample_metadata(
+        &block,
+        block.round(),
+        cumulative_weight + 1,
+        last_coinbase_target,
+        last_coinbase_timestamp,
+        block.timestamp(),
+    );
+    assert!(check(metadata, block.transactions().clone(), rng).contains("incorrect cumulative weight"));
+
+    // Ensure a block with an incorrect last coinbase target is invalid.
+    let metadata = sample_metadata(
+        &block,
+        block.round(),
+        cumulative_weight,
+        last_coinbase_target + 1,
+        last_coinbase_timestamp,
+        block.timestamp(),
+    );
+    assert!(check(metadata, block.transactions().clone(), rng).contains("incorrect last coinbase target"));
+
+    // Ensure a block with an incorrect last coinbase timestamp is invalid.
+    let metadata = sample_metadata(
+        &block,
+        block.round(),
+        cumulative_weight,
+        last_coinbase_target,
+        last_coinbase_timestamp + 1,
+        block.timestamp(),
+    );
+    assert!(check(metadata, block.transactions().clone(), rng).contains("incorrect last coinbase timestamp"));
+
+    // Ensure a block with an incorrect transactions root is invalid.
+    let header = Header::from(
+        block.previous_state_root(),
+        Field::rand(rng),
+        block.finalize_root(),
+        Field::zero(),
+        *block.metadata(),
+    )
+    .unwrap();
+    let candidate =
+        Block::new(&private_key, block.previous_hash(), header, block.transactions().clone(), None, rng).unwrap();
+    let error = ledger.check_next_block(&candidate).unwrap_err().to_string();
+    assert!(error.contains("incorrect transactions root"));
+
+    // Ensure a block that labels an accepted execution as rejected is invalid.
+    let execution = match &transaction {
+        Transaction::Execute(_, execution, _) => execution.clone(),
+        _ => unreachable!("The transfer is an execution"),
+    };
+    let fee = Transaction::from_fee(transaction.fee_transition().unwrap()).unwrap();
+    let rejected = ConfirmedTransaction::rejected_execute(0, fee, execution, vec![]).unwrap();
+    let error = check(*block.metadata(), Transactions::from(&[rejected]), rng);
+    assert!(error.contains("mismatched outcomes"));
+}
+
+#[test]
+fn test_check_next_block_rejects_invalid_coinbase() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a ledger with a new genesis block.
+    let (ledger, private_key) = sample_ledger(rng);
+    let address = Address::try_from(&private_key).unwrap();
+
+    // Prepare a valid next block, with a coinbase solution.
+    let solutions = sample_prover_solutions(&ledger, &ledger.latest_epoch_challenge().unwrap(), rng);
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+    let block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], solutions, rng).unwrap();
+    let coinbase = block.coinbase().cloned().unwrap();
+    ledger.check_next_block(&block).unwrap();
+
+    // Returns the error of checking the given block, with the given metadata and coinbase solution.
+    let check = |metadata: Metadata<CurrentNetwork>, coinbase: CoinbaseSolution<CurrentNetwork>, rng: &mut TestRng| {
+        let transactions = block.transactions().clone();
+        let candidate = sample_modified_block(&block, &private_key, metadata, transactions, Some(coinbase), rng);
+        ledger.check_next_block(&candidate).unwrap_err().to_string()
+    };
+    let (cumulative_weight, last_coinbase_target, last_coinbase_timestamp) =
+        (block.cumulative_weight(), block.last_coinbase_target(), block.last_coinbase_timestamp());
+
+    // Ensure a block with an incorrect cumulative weight is invalid.
+    let metadata = sample_metadata(
+        &block,
+        block.round(),
+        cumulative_weight - 1,
+        last_coinbase_target,
+        last_coinbase_timestamp,
+        block.timestamp(),
+    );
+    assert!(check(metadata, coinbase.clone(), rng).contains("incorrect cumulative weight"));
+
+    // Ensure a block with an incorrect last coinbase target is invalid.
+    let metadata = sample_metadata(
+        &block,
+        block.round(),
+        cumulative_weight,
+        last_coinbase_target + 1,
+        last_coinbase_timestamp,
+        block.timestamp(),
+    );
+    assert!(check(metadata, coinbase.clone(), rng).contains("incorrect last coinbase target"));
+
+    // Ensure a block with an incorrect last coinbase timestamp is invalid.
+    let metadata = sample_metadata(
+        &block,
+        block.round(),
+        cumulative_weight,
+        last_coinbase_target,
+        last_coinbase_timestamp - 1,
+        block.timestamp(),
+    );
+    assert!(check(metadata, coinbase.clone(), rng).contains("incorrect last coinbase timestamp"));
+
+    // Ensure a block with a coinbase solution for another epoch challenge is invalid.
+    let epoch_challenge = EpochChallenge::new(0, ledger.latest_hash(), CurrentNetwork::COINBASE_PUZZLE_DEGREE).unwrap();
+    let solutions = sample_prover_solutions(&ledger, &epoch_challenge, rng);
+    let invalid_coinbase =
+        ledger.coinbase_puzzle().unwrap().accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    let candidate = sample_modified_block(
+        &block,
+        &private_key,
+        *block.metadata(),
+        block.transactions().clone(),
+        Some(invalid_coinbase),
+        rng,
+    );
+    assert!(ledger.check_next_block(&candidate).is_err());
+
+    // Add the block to the ledger.
+    ledger.add_next_block(&block).unwrap();
+
+    // Ensure a block that reuses the puzzle commitments of the coinbase solution is invalid.
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+    let next_block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], vec![], rng).unwrap();
+    let candidate = sample_modified_block(
+        &next_block,
+        &private_key,
+        *next_block.metadata(),
+        next_block.transactions().clone(),
+        Some(coinbase),
+        rng,
+    );
+    let error = ledger.check_next_block(&candidate).unwrap_err().to_string();
+    assert!(error.contains("existing puzzle commitment"));
+}
+
+#[test]
+fn test_prepare_advance_to_next_block_with_coinbase() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a ledger with a new genesis block.
+    let (ledger, private_key) = sample_ledger(rng);
+    let address = Address::try_from(&private_key).unwrap();
+    // Retrieve the genesis block.
+    let genesis = ledger.get_block(0).unwrap();
+
+    // Sample the prover solutions for the latest epoch challenge.
+    let solutions = sample_prover_solutions(&ledger, &ledger.latest_epoch_challenge().unwrap(), rng);
+    // Sample a prover solution for another epoch challenge.
+    let epoch_challenge = EpochChallenge::new(0, ledger.latest_hash(), CurrentNetwork::COINBASE_PUZZLE_DEGREE).unwrap();
+    let invalid_solution = sample_prover_solution(&ledger, &epoch_challenge, CurrentNetwork::GENESIS_PROOF_TARGET, rng);
+
+    // Create a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+
+    // Ensure an invalid prover solution is skipped.
+    let candidate_solutions = vec![invalid_solution];
+    let block = ledger
+        .prepare_advance_to_next_block(&private_key, vec![transaction.clone()], candidate_solutions, rng)
+        .unwrap();
+    assert!(block.coinbase().is_none());
+    ledger.check_next_block(&block).unwrap();
+
+    // Prepare the next block, with the valid prover solutions and the invalid prover solution.
+    let mut candidate_solutions = solutions.clone();
+    candidate_solutions.push(invalid_solution);
+    let block =
+        ledger.prepare_advance_to_next_block(&private_key, vec![transaction], candidate_solutions, rng).unwrap();
+
+    // Ensure the valid prover solutions are aggregated into the coinbase solution.
+    let coinbase = block.coinbase().unwrap();
+    assert_eq!(coinbase.len(), solutions.len());
+    assert!(coinbase.puzzle_commitments().all(|commitment| commitment != invalid_solution.commitment()));
+    assert_eq!(block.cumulative_weight(), genesis.cumulative_weight() + coinbase.to_cumulative_proof_target().unwrap());
+    assert_eq!(block.last_coinbase_target(), block.coinbase_target());
+    assert_eq!(block.last_coinbase_timestamp(), block.timestamp());
+
+    // Ensure the block is a valid next block.
+    ledger.check_next_block(&block).unwrap();
+
+    // Add the block to the ledger.
+    ledger.add_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_block(), block);
+
+    // Ensure the prover solutions are not aggregated again.
+    let transaction = ledger.create_transfer(&private_key, address, 1, 1, None).unwrap();
+    let block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], solutions, rng).unwrap();
+    assert!(block.coinbase().is_none());
+    ledger.check_next_block(&block).unwrap();
+}
@@ -73,6 +73,18 @@ impl_remote!(SplitVerifier, REMOTE_URL, "resources/", "split", "verifier");
 // Fee
 impl_remote!(FeeProver, REMOTE_URL, "resources/", "fee", "prover");
 impl_remote!(FeeVerifier, REMOTE_URL, "resources/", "fee", "verifier");
+// TransferPublic
+impl_remote!(TransferPublicProver, REMOTE_URL, "resources/", "transfer_public", "prover");
+impl_remote!(TransferPublicVerifier, REMOTE_URL, "resources/", "transfer_public", "verifier");
+// TransferPrivateToPublic
+impl_remote!(TransferPrivateToPublicProver, REMOTE_URL, "resources/", "transfer_private_to_public", "prover");
+impl_remote!(TransferPrivateToPublicVerifier, REMOTE_URL, "resources/", "transfer_private_to_public", "verifier");
+// TransferPublicToPrivate
+impl_remote!(TransferPublicToPrivateProver, REMOTE_URL, "resources/", "transfer_public_to_private", "prover");
+impl_remote!(TransferPublicToPrivateVerifier, REMOTE_URL, "resources/", "transfer_public_to_private", "verifier");
+// FeePublic
+impl_remote!(FeePublicProver, REMOTE_URL, "resources/", "fee_public", "prover");
+impl_remote!(FeePublicVerifier, REMOTE_URL, "resources/", "fee_public", "verifier");
 
 #[macro_export]
 macro_rules! insert_credit_keys {
@@ -84,6 +96,10 @@ macro_rules! insert_credit_keys {
             $crate::insert_key!($map, string, $type<$network>, ("join", $crate::testnet3::[<Join $variant>]::load_bytes()));
             $crate::insert_key!($map, string, $type<$network>, ("split", $crate::testnet3::[<Split $variant>]::load_bytes()));
             $crate::insert_key!($map, string, $type<$network>, ("fee", $crate::testnet3::[<Fee $variant>]::load_bytes()));
+            $crate::insert_key!($map, string, $type<$network>, ("transfer_public", $crate::testnet3::[<TransferPublic $variant>]::load_bytes()));
+            $crate::insert_key!($map, string, $type<$network>, ("transfer_private_to_public", $crate::testnet3::[<TransferPrivateToPublic $variant>]::load_bytes()));
+            $crate::insert_key!($map, string, $type<$network>, ("transfer_public_to_private", $crate::testnet3::[<TransferPublicToPrivate $variant>]::load_bytes()));
+            $crate::insert_key!($map, string, $type<$network>, ("fee_public", $crate::testnet3::[<FeePublic $variant>]::load_bytes()));
         }
     }};
 }
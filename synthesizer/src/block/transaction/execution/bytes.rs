@@ -20,7 +20,7 @@ impl<N: Network> FromBytes for Execution<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid execution version"));
         }
         // Read the number of transitions.
@@ -33,6 +33,21 @@ impl<N: Network> FromBytes for Execution<N> {
         // Read the transitions.
         let transitions =
             (0..num_transitions).map(|_| Transition::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the editions, which are only present from version 1.
+        let mut editions = IndexMap::new();
+        if version == 1 {
+            // Read the number of editions.
+            let num_editions = u8::read_le(&mut reader)?;
+            // Read the editions.
+            for _ in 0..num_editions {
+                // Read the program ID.
+                let program_id = ProgramID::read_le(&mut reader)?;
+                // Read the edition.
+                let edition = u16::read_le(&mut reader)?;
+                // Insert the edition.
+                editions.insert(program_id, edition);
+            }
+        }
         // Read the global state root.
         let global_state_root = N::StateRoot::read_le(&mut reader)?;
         // Read the inclusion proof variant.
@@ -44,21 +59,40 @@ impl<N: Network> FromBytes for Execution<N> {
             _ => return Err(error("Invalid inclusion proof variant '{inclusion_variant}'")),
         };
         // Return the new `Execution` instance.
-        Self::from(transitions.into_iter(), global_state_root, inclusion_proof).map_err(|e| error(e.to_string()))
+        Self::from(transitions.into_iter(), editions, global_state_root, inclusion_proof)
+            .map_err(|e| error(e.to_string()))
     }
 }
 
 impl<N: Network> ToBytes for Execution<N> {
     /// Writes the execution to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Determine the version.
+        // Note: Version 0 is used when all programs ran against their initial edition.
+        let version = match self.editions.is_empty() {
+            true => 0u8,
+            false => 1u8,
+        };
         // Write the version.
-        0u8.write_le(&mut writer)?;
+        version.write_le(&mut writer)?;
         // Write the number of transitions.
         (u8::try_from(self.transitions.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
         // Write the transitions.
         for transition in self.transitions.values() {
             transition.write_le(&mut writer)?;
         }
+        // Write the editions.
+        if version == 1 {
+            // Write the number of editions.
+            (u8::try_from(self.editions.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+            // Write the editions.
+            for (program_id, edition) in &self.editions {
+                // Write the program ID.
+                program_id.write_le(&mut writer)?;
+                // Write the edition.
+                edition.write_le(&mut writer)?;
+            }
+        }
         // Write the global state root.
         self.global_state_root.write_le(&mut writer)?;
         // Write the inclusion proof.
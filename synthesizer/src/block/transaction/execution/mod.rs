@@ -17,7 +17,7 @@ mod serialize;
 mod string;
 
 use crate::{snark::Proof, Transition};
-use console::{account::Field, network::prelude::*};
+use console::{account::Field, network::prelude::*, program::ProgramID};
 
 use indexmap::IndexMap;
 
@@ -25,6 +25,9 @@ use indexmap::IndexMap;
 pub struct Execution<N: Network> {
     /// The transitions.
     transitions: IndexMap<N::TransitionID, Transition<N>>,
+    /// The mapping of upgraded program IDs to the edition that the execution ran against.
+    /// Programs that are not present in this mapping ran against the initial edition.
+    editions: IndexMap<ProgramID<N>, u16>,
     /// The global state root.
     global_state_root: N::StateRoot,
     /// The inclusion proof.
@@ -34,20 +37,40 @@ pub struct Execution<N: Network> {
 impl<N: Network> Execution<N> {
     /// Initialize a new `Execution` instance.
     pub fn new() -> Self {
-        Self { transitions: Default::default(), global_state_root: Default::default(), inclusion_proof: None }
+        Self {
+            transitions: Default::default(),
+            editions: Default::default(),
+            global_state_root: Default::default(),
+            inclusion_proof: None,
+        }
     }
 
     /// Initializes a new `Execution` instance with the given transitions.
     pub fn from(
         transitions: impl Iterator<Item = Transition<N>>,
+        editions: IndexMap<ProgramID<N>, u16>,
         global_state_root: N::StateRoot,
         inclusion_proof: Option<Proof<N>>,
     ) -> Result<Self> {
         // Construct the execution.
-        let execution =
-            Self { transitions: transitions.map(|t| (*t.id(), t)).collect(), global_state_root, inclusion_proof };
+        let execution = Self {
+            transitions: transitions.map(|t| (*t.id(), t)).collect(),
+            editions,
+            global_state_root,
+            inclusion_proof,
+        };
         // Ensure the transitions are not empty.
         ensure!(!execution.transitions.is_empty(), "Execution cannot initialize from empty list of transitions");
+        // Ensure the editions are well-formed.
+        for (program_id, edition) in &execution.editions {
+            // Ensure the edition is not the initial edition.
+            ensure!(*edition != N::EDITION, "Execution cannot record the initial edition for '{program_id}'");
+            // Ensure the program is used in the execution.
+            ensure!(
+                execution.transitions.values().any(|transition| transition.program_id() == program_id),
+                "Execution records an edition for '{program_id}', but contains no transition for it"
+            );
+        }
         // Return the new `Execution` instance.
         Ok(execution)
     }
@@ -57,6 +80,16 @@ impl<N: Network> Execution<N> {
         Ok(u64::try_from(self.to_bytes_le()?.len())?)
     }
 
+    /// Returns the mapping of upgraded program IDs to the edition that the execution ran against.
+    pub const fn editions(&self) -> &IndexMap<ProgramID<N>, u16> {
+        &self.editions
+    }
+
+    /// Returns the edition of the given program ID that the execution ran against.
+    pub fn edition(&self, program_id: &ProgramID<N>) -> u16 {
+        self.editions.get(program_id).copied().unwrap_or(N::EDITION)
+    }
+
     /// Returns the global state root.
     pub const fn global_state_root(&self) -> N::StateRoot {
         self.global_state_root
@@ -97,6 +130,14 @@ impl<N: Network> Execution<N> {
         self.transitions.insert(*transition.id(), transition);
     }
 
+    /// Records the edition of the given program ID that the execution ran against.
+    /// Note: The initial edition is implied, and is therefore not recorded.
+    pub fn insert_edition(&mut self, program_id: ProgramID<N>, edition: u16) {
+        if edition != N::EDITION {
+            self.editions.insert(program_id, edition);
+        }
+    }
+
     /// Pops the last `Transition` from the execution.
     pub fn pop(&mut self) -> Result<Transition<N>> {
         match self.transitions.pop() {
@@ -21,9 +21,12 @@ impl<N: Network> Serialize for Execution<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut execution = serializer.serialize_struct("Execution", 3)?;
+                let mut execution = serializer.serialize_struct("Execution", 4)?;
                 execution
                     .serialize_field("transitions", &self.transitions.values().collect::<Vec<&Transition<N>>>())?;
+                if !self.editions.is_empty() {
+                    execution.serialize_field("editions", &self.editions)?;
+                }
                 execution.serialize_field("global_state_root", &self.global_state_root)?;
                 if let Some(inclusion_proof) = &self.inclusion_proof {
                     execution.serialize_field("inclusion", inclusion_proof)?;
@@ -44,6 +47,12 @@ impl<'de, N: Network> Deserialize<'de> for Execution<N> {
                 let mut execution = serde_json::Value::deserialize(deserializer)?;
                 // Retrieve the transitions.
                 let transitions: Vec<_> = DeserializeExt::take_from_value::<D>(&mut execution, "transitions")?;
+                // Retrieve the editions.
+                let editions = serde_json::from_value::<Option<IndexMap<ProgramID<N>, u16>>>(
+                    execution.get_mut("editions").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?
+                .unwrap_or_default();
                 // Retrieve the global state root.
                 let global_state_root = DeserializeExt::take_from_value::<D>(&mut execution, "global_state_root")?;
                 // Retrieve the inclusion proof.
@@ -52,7 +61,8 @@ impl<'de, N: Network> Deserialize<'de> for Execution<N> {
                 )
                 .map_err(de::Error::custom)?;
                 // Recover the execution.
-                Self::from(transitions.into_iter(), global_state_root, inclusion_proof).map_err(de::Error::custom)
+                Self::from(transitions.into_iter(), editions, global_state_root, inclusion_proof)
+                    .map_err(de::Error::custom)
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "execution"),
         }
@@ -19,8 +19,8 @@ mod string;
 use crate::{snark::Proof, Input, Transition};
 use console::{
     network::prelude::*,
-    program::{Literal, Plaintext},
-    types::U64,
+    program::{Literal, Plaintext, Value},
+    types::{Address, U64},
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -45,15 +45,39 @@ impl<N: Network> Fee<N> {
         self.amount().map(|amount| amount.is_zero())
     }
 
+    /// Returns `true` if the fee is paid from the public balance of the caller (i.e. `credits.aleo/fee_public`).
+    pub fn is_fee_public(&self) -> bool {
+        self.transition.program_id().to_string() == "credits.aleo"
+            && self.transition.function_name().to_string() == "fee_public"
+    }
+
     /// Returns the amount (in microcredits).
     pub fn amount(&self) -> Result<U64<N>> {
+        // Determine the input index of the amount, which is the first input of a public fee.
+        let index = match self.is_fee_public() {
+            true => 0,
+            false => 1,
+        };
         // Retrieve the amount (in microcredits) as a plaintext value.
-        match self.transition.inputs().get(1) {
+        match self.transition.inputs().get(index) {
             Some(Input::Public(_, Some(Plaintext::Literal(Literal::U64(microcredits), _)))) => Ok(*microcredits),
             _ => bail!("Failed to retrieve the fee (in microcredits) from the fee transition"),
         }
     }
 
+    /// Returns the address that pays the fee, if the fee is paid from the public balance of the caller.
+    pub fn payer(&self) -> Result<Option<Address<N>>> {
+        // If the fee is not public, the payer is not revealed.
+        if !self.is_fee_public() {
+            return Ok(None);
+        }
+        // Retrieve the caller, which is the first finalize input of the public fee.
+        match self.transition.finalize().and_then(|inputs| inputs.first()) {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::Address(payer), _))) => Ok(Some(*payer)),
+            _ => bail!("Failed to retrieve the payer from the public fee transition"),
+        }
+    }
+
     /// Returns the transition ID.
     pub fn transition_id(&self) -> &N::TransitionID {
         self.transition.id()
@@ -139,7 +139,7 @@ impl<N: Network> Transaction<N> {
             TransactionLeaf::new_execution(index as u16, **transition.id()).to_bits_le()
         });
         // If the fee is present, add it to the leaves.
-        let leaves = match fee {
+        let mut leaves = match fee {
             Some(fee) => {
                 // Construct the transaction leaf.
                 let leaf = TransactionLeaf::new_fee(
@@ -152,6 +152,14 @@ impl<N: Network> Transaction<N> {
             }
             None => leaves.collect::<Vec<_>>(),
         };
+        // Add the editions of the upgraded programs to the leaves, so they are committed to in the transaction ID.
+        for (program_id, edition) in execution.editions() {
+            // Compute the edition ID as `Hash(program_id || edition)`.
+            let preimage = program_id.to_bits_le().into_iter().chain(edition.to_bits_le()).collect::<Vec<_>>();
+            let id = N::hash_bhp1024(&preimage)?;
+            // Construct the transaction leaf.
+            leaves.push(TransactionLeaf::new_edition(leaves.len() as u16, id).to_bits_le());
+        }
 
         // Compute the execution tree.
         N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&leaves)
@@ -200,6 +208,13 @@ impl<N: Network> Transaction<N> {
             Self::MAX_TRANSITIONS,
             execution.len()
         );
+        // Ensure the number of leaves, including the fee and the editions, is within the Merkle tree size.
+        let num_leaves = execution.len() + 1 + execution.editions().len();
+        ensure!(
+            num_leaves <= Self::MAX_TRANSITIONS,
+            "Execution must contain at most {} leaves (including the fee and editions), found {num_leaves}",
+            Self::MAX_TRANSITIONS
+        );
         Ok(())
     }
 }
@@ -16,8 +16,8 @@ mod bytes;
 mod serialize;
 mod string;
 
-use crate::block::{Deployment, Execution, FinalizeOperation, Transaction};
-use console::network::prelude::*;
+use crate::block::{Deployment, Execution, FinalizeOperation, Transaction, Transition};
+use console::{network::prelude::*, types::U64};
 
 pub type NumFinalizeSize = u16;
 
@@ -38,13 +38,17 @@ impl<T: Clone + Debug + PartialEq + Eq + ToBytes> Deref for Rejected<T> {
 #[derive(Clone, PartialEq, Eq)]
 pub enum ConfirmedTransaction<N: Network> {
     /// The accepted deploy transaction is composed of `(index, deploy_transaction, finalize_operations)`.
+    /// Note: The finalize operations of the fee, if any, precede those of the deployment.
     AcceptedDeploy(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
     /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations)`.
+    /// Note: The finalize operations of the fee, if any, precede those of the execution.
     AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
-    /// The rejected deploy transaction is composed of `(index, fee_transaction, rejected_deployment)`.
-    RejectedDeploy(u32, Transaction<N>, Box<Rejected<Deployment<N>>>),
-    /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution)`.
-    RejectedExecute(u32, Transaction<N>, Rejected<Execution<N>>),
+    /// The rejected deploy transaction is composed of `(index, fee_transaction, rejected_deployment, finalize)`.
+    /// Note: The finalize operations are those of the fee.
+    RejectedDeploy(u32, Transaction<N>, Box<Rejected<Deployment<N>>>, Vec<FinalizeOperation<N>>),
+    /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution, finalize)`.
+    /// Note: The finalize operations are those of the fee.
+    RejectedExecute(u32, Transaction<N>, Rejected<Execution<N>>, Vec<FinalizeOperation<N>>),
 }
 
 impl<N: Network> ConfirmedTransaction<N> {
@@ -54,26 +58,44 @@ impl<N: Network> ConfirmedTransaction<N> {
         transaction: Transaction<N>,
         finalize_operations: Vec<FinalizeOperation<N>>,
     ) -> Result<Self> {
-        // Retrieve the program from the deployment, and ensure the transaction is a deploy transaction.
-        let program = match &transaction {
-            Transaction::Deploy(_, _, deployment, _) => deployment.program(),
+        // Retrieve the deployment, and ensure the transaction is a deploy transaction.
+        let deployment = match &transaction {
+            Transaction::Deploy(_, _, deployment, _) => deployment,
             Transaction::Execute(..) | Transaction::Fee(..) => {
                 bail!("Transaction '{}' is not a deploy transaction", transaction.id())
             }
         };
-        // Ensure the number of program mappings matches the number of finalize operations.
-        if program.mappings().len() != finalize_operations.len() {
-            bail!(
-                "The number of program mappings ({}) does not match the nubmer of finalize operations ({})",
-                program.mappings().len(),
-                finalize_operations.len()
-            )
-        }
-        // Ensure the finalize operations contain the correct types.
-        for operation in finalize_operations.iter() {
-            // Ensure the finalize operation is an initialize mapping.
-            if !matches!(operation, FinalizeOperation::InitializeMapping(..)) {
-                bail!("Transaction '{}' (deploy) contains an invalid finalize operation type", transaction.id())
+        // Retrieve the program.
+        let program = deployment.program();
+        // Skip the finalize operations of the fee, which precede those of the deployment.
+        let num_fee_operations = finalize_operations.iter().take_while(|operation| is_key_value(operation)).count();
+        let deployment_operations = &finalize_operations[num_fee_operations..];
+        // If this is the initial deployment, ensure the finalize operations initialize each program mapping.
+        if deployment.edition() == N::EDITION {
+            // Ensure the number of program mappings matches the number of finalize operations.
+            if program.mappings().len() != deployment_operations.len() {
+                bail!(
+                    "The number of program mappings ({}) does not match the nubmer of finalize operations ({})",
+                    program.mappings().len(),
+                    deployment_operations.len()
+                )
+            }
+            // Ensure the finalize operations contain the correct types.
+            for operation in deployment_operations.iter() {
+                // Ensure the finalize operation is an initialize mapping.
+                if !matches!(operation, FinalizeOperation::InitializeMapping(..)) {
+                    bail!("Transaction '{}' (deploy) contains an invalid finalize operation type", transaction.id())
+                }
+            }
+        } else {
+            // Otherwise, ensure the upgrade only initializes or removes mappings.
+            // Ensure the finalize operations contain the correct types.
+            for operation in deployment_operations.iter() {
+                // Ensure the finalize operation is an initialize or remove mapping.
+                if !matches!(operation, FinalizeOperation::InitializeMapping(..) | FinalizeOperation::RemoveMapping(..))
+                {
+                    bail!("Transaction '{}' (deploy) contains an invalid finalize operation type", transaction.id())
+                }
             }
         }
         // Return the accepted deploy transaction.
@@ -88,14 +110,9 @@ impl<N: Network> ConfirmedTransaction<N> {
     ) -> Result<Self> {
         // Ensure the finalize operations contain the correct types.
         for operation in finalize_operations.iter() {
-            // Ensure the finalize operation is an insert or update key-value operation.
-            match operation {
-                FinalizeOperation::InsertKeyValue(..) | FinalizeOperation::UpdateKeyValue(..) => (),
-                FinalizeOperation::InitializeMapping(..)
-                | FinalizeOperation::RemoveMapping(..)
-                | FinalizeOperation::RemoveKeyValue(..) => {
-                    bail!("Transaction '{}' (execute) contains an invalid finalize operation type", transaction.id())
-                }
+            // Ensure the finalize operation is an insert, update, or remove key-value operation.
+            if !is_key_value(operation) {
+                bail!("Transaction '{}' (execute) contains an invalid finalize operation type", transaction.id())
             }
         }
         // Ensure the transaction is an execute transaction.
@@ -110,23 +127,42 @@ impl<N: Network> ConfirmedTransaction<N> {
         index: u32,
         transaction: Transaction<N>,
         rejected_deployment: Deployment<N>,
+        finalize_operations: Vec<FinalizeOperation<N>>,
     ) -> Result<Self> {
         // Ensure the transaction is a fee transaction.
-        match transaction.is_fee() {
-            true => {
-                Ok(ConfirmedTransaction::RejectedDeploy(index, transaction, Box::new(Rejected(rejected_deployment))))
-            }
-            false => bail!("Transaction '{}' is not a fee transaction", transaction.id()),
-        }
+        ensure!(transaction.is_fee(), "Transaction '{}' is not a fee transaction", transaction.id());
+        // Ensure the finalize operations of the fee contain the correct types.
+        ensure!(
+            finalize_operations.iter().all(is_key_value),
+            "Transaction '{}' (fee) contains an invalid finalize operation type",
+            transaction.id()
+        );
+        // Return the rejected deploy transaction.
+        Ok(ConfirmedTransaction::RejectedDeploy(
+            index,
+            transaction,
+            Box::new(Rejected(rejected_deployment)),
+            finalize_operations,
+        ))
     }
 
     /// Returns a new instance of a rejected execute transaction.
-    pub fn rejected_execute(index: u32, transaction: Transaction<N>, rejected_execution: Execution<N>) -> Result<Self> {
+    pub fn rejected_execute(
+        index: u32,
+        transaction: Transaction<N>,
+        rejected_execution: Execution<N>,
+        finalize_operations: Vec<FinalizeOperation<N>>,
+    ) -> Result<Self> {
         // Ensure the transaction is a fee transaction.
-        match transaction.is_fee() {
-            true => Ok(ConfirmedTransaction::RejectedExecute(index, transaction, Rejected(rejected_execution))),
-            false => bail!("Transaction '{}' is not a fee transaction", transaction.id()),
-        }
+        ensure!(transaction.is_fee(), "Transaction '{}' is not a fee transaction", transaction.id());
+        // Ensure the finalize operations of the fee contain the correct types.
+        ensure!(
+            finalize_operations.iter().all(is_key_value),
+            "Transaction '{}' (fee) contains an invalid finalize operation type",
+            transaction.id()
+        );
+        // Return the rejected execute transaction.
+        Ok(ConfirmedTransaction::RejectedExecute(index, transaction, Rejected(rejected_execution), finalize_operations))
     }
 }
 
@@ -161,8 +197,8 @@ impl<N: Network> ConfirmedTransaction<N> {
         match self {
             ConfirmedTransaction::AcceptedDeploy(_, transaction, _) => transaction,
             ConfirmedTransaction::AcceptedExecute(_, transaction, _) => transaction,
-            ConfirmedTransaction::RejectedDeploy(_, transaction, _) => transaction,
-            ConfirmedTransaction::RejectedExecute(_, transaction, _) => transaction,
+            ConfirmedTransaction::RejectedDeploy(_, transaction, ..) => transaction,
+            ConfirmedTransaction::RejectedExecute(_, transaction, ..) => transaction,
         }
     }
 
@@ -171,21 +207,55 @@ impl<N: Network> ConfirmedTransaction<N> {
         match self {
             ConfirmedTransaction::AcceptedDeploy(_, transaction, _) => transaction,
             ConfirmedTransaction::AcceptedExecute(_, transaction, _) => transaction,
-            ConfirmedTransaction::RejectedDeploy(_, transaction, _) => transaction,
-            ConfirmedTransaction::RejectedExecute(_, transaction, _) => transaction,
+            ConfirmedTransaction::RejectedDeploy(_, transaction, ..) => transaction,
+            ConfirmedTransaction::RejectedExecute(_, transaction, ..) => transaction,
         }
     }
 
+    /// Returns an iterator over the transition IDs of the transaction, as it was submitted.
+    /// Note: For a rejected execution, this includes the transitions of the rejected execution,
+    /// followed by the fee transition, in the same order as in the unconfirmed transaction.
+    pub fn unconfirmed_transition_ids(&self) -> impl '_ + Iterator<Item = &N::TransitionID> {
+        // Retrieve the transitions of the rejected execution, if any.
+        let rejected = match self {
+            ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => Some(rejected.transitions()),
+            _ => None,
+        };
+        rejected.into_iter().flatten().map(Transition::id).chain(self.transaction().transition_ids())
+    }
+
+    /// Returns the fee amount (in microcredits) charged for the confirmed transaction.
+    /// Note: For a rejected transaction, this is the amount of the fee that was charged in its place.
+    pub fn fee_amount(&self) -> Result<U64<N>> {
+        self.transaction().fee()
+    }
+
     /// Returns the number of finalize operations.
     pub fn num_finalize(&self) -> usize {
+        self.finalize_operations().len()
+    }
+
+    /// Returns the finalize operations.
+    pub fn finalize_operations(&self) -> &Vec<FinalizeOperation<N>> {
         match self {
-            ConfirmedTransaction::AcceptedDeploy(_, _, finalize)
-            | ConfirmedTransaction::AcceptedExecute(_, _, finalize) => finalize.len(),
-            ConfirmedTransaction::RejectedDeploy(..) | ConfirmedTransaction::RejectedExecute(..) => 0,
+            ConfirmedTransaction::AcceptedDeploy(_, _, finalize) => finalize,
+            ConfirmedTransaction::AcceptedExecute(_, _, finalize) => finalize,
+            ConfirmedTransaction::RejectedDeploy(_, _, _, finalize) => finalize,
+            ConfirmedTransaction::RejectedExecute(_, _, _, finalize) => finalize,
         }
     }
 }
 
+/// Returns `true` if the finalize operation inserts, updates, or removes a key-value.
+fn is_key_value<N: Network>(operation: &FinalizeOperation<N>) -> bool {
+    match operation {
+        FinalizeOperation::InsertKeyValue(..)
+        | FinalizeOperation::UpdateKeyValue(..)
+        | FinalizeOperation::RemoveKeyValue(..) => true,
+        FinalizeOperation::InitializeMapping(..) | FinalizeOperation::RemoveMapping(..) => false,
+    }
+}
+
 impl<N: Network> Deref for ConfirmedTransaction<N> {
     type Target = Transaction<N>;
 
@@ -231,7 +301,7 @@ pub(crate) mod test_helpers {
         };
 
         // Return the confirmed transaction.
-        ConfirmedTransaction::rejected_deploy(index, fee_transaction, deploy).unwrap()
+        ConfirmedTransaction::rejected_deploy(index, fee_transaction, deploy, vec![]).unwrap()
     }
 
     /// Samples a rejected execute transaction at the given index.
@@ -246,7 +316,7 @@ pub(crate) mod test_helpers {
         };
 
         // Return the confirmed transaction.
-        ConfirmedTransaction::rejected_execute(index, fee_transaction, execute).unwrap()
+        ConfirmedTransaction::rejected_execute(index, fee_transaction, execute, vec![]).unwrap()
     }
 
     /// Sample a list of randomly confirmed transactions.
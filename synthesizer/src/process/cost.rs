@@ -0,0 +1,364 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::program::{Call, CallOperator};
+
+/// The base cost in microcredits of a transition, covering the verification of its proof.
+pub const TRANSITION_BASE_COST: u64 = 10_000;
+/// The cost in microcredits of an arithmetic, logical, or comparison instruction.
+pub const ARITHMETIC_INSTRUCTION_COST: u64 = 10;
+/// The cost in microcredits of a division, exponentiation, inversion, or shift instruction.
+pub const COMPLEX_INSTRUCTION_COST: u64 = 50;
+/// The cost in microcredits of an algebraic hash or commitment instruction (i.e. BHP, Pedersen, Poseidon).
+pub const ALGEBRAIC_HASH_INSTRUCTION_COST: u64 = 100;
+/// The cost in microcredits of a bitwise hash instruction (i.e. Keccak, SHA-3, SHA-256).
+pub const BITWISE_HASH_INSTRUCTION_COST: u64 = 500;
+/// The cost in microcredits of a signature verification instruction.
+pub const SIGNATURE_INSTRUCTION_COST: u64 = 500;
+/// The cost in microcredits of a finalize command that reads from a mapping.
+pub const MAPPING_READ_COMMAND_COST: u64 = 1_000;
+/// The cost in microcredits of a finalize command that writes to a mapping.
+pub const MAPPING_WRITE_COMMAND_COST: u64 = 10_000;
+/// The cost in microcredits of a finalize command that controls the flow of execution.
+pub const BRANCH_COMMAND_COST: u64 = 10;
+/// The cost in microcredits of a finalize command that samples randomness.
+pub const RANDOM_COMMAND_COST: u64 = 100;
+
+/// Returns the cost in microcredits of the given instruction.
+pub const fn instruction_cost<N: Network>(instruction: &Instruction<N>) -> u64 {
+    match instruction {
+        Instruction::Abs(..)
+        | Instruction::AbsWrapped(..)
+        | Instruction::Add(..)
+        | Instruction::AddWrapped(..)
+        | Instruction::And(..)
+        | Instruction::AssertEq(..)
+        | Instruction::AssertNeq(..)
+        | Instruction::Cast(..)
+        | Instruction::Double(..)
+        | Instruction::GreaterThan(..)
+        | Instruction::GreaterThanOrEqual(..)
+        | Instruction::IsEq(..)
+        | Instruction::IsNeq(..)
+        | Instruction::LessThan(..)
+        | Instruction::LessThanOrEqual(..)
+        | Instruction::Mul(..)
+        | Instruction::MulWrapped(..)
+        | Instruction::Nand(..)
+        | Instruction::Neg(..)
+        | Instruction::Nor(..)
+        | Instruction::Not(..)
+        | Instruction::Or(..)
+        | Instruction::Square(..)
+        | Instruction::Sub(..)
+        | Instruction::SubWrapped(..)
+        | Instruction::Ternary(..)
+        | Instruction::Xor(..) => ARITHMETIC_INSTRUCTION_COST,
+        Instruction::Div(..)
+        | Instruction::DivWrapped(..)
+        | Instruction::Inv(..)
+        | Instruction::Modulo(..)
+        | Instruction::Pow(..)
+        | Instruction::PowWrapped(..)
+        | Instruction::Rem(..)
+        | Instruction::RemWrapped(..)
+        | Instruction::Shl(..)
+        | Instruction::ShlWrapped(..)
+        | Instruction::Shr(..)
+        | Instruction::ShrWrapped(..)
+        | Instruction::SquareRoot(..) => COMPLEX_INSTRUCTION_COST,
+        Instruction::CommitBHP256(..)
+        | Instruction::CommitBHP512(..)
+        | Instruction::CommitBHP768(..)
+        | Instruction::CommitBHP1024(..)
+        | Instruction::CommitPED64(..)
+        | Instruction::CommitPED128(..)
+        | Instruction::HashBHP256(..)
+        | Instruction::HashBHP512(..)
+        | Instruction::HashBHP768(..)
+        | Instruction::HashBHP1024(..)
+        | Instruction::HashPED64(..)
+        | Instruction::HashPED128(..)
+        | Instruction::HashPSD2(..)
+        | Instruction::HashPSD4(..)
+        | Instruction::HashPSD8(..) => ALGEBRAIC_HASH_INSTRUCTION_COST,
+        Instruction::HashKeccak256(..) | Instruction::HashSha3_256(..) | Instruction::HashSha256(..) => {
+            BITWISE_HASH_INSTRUCTION_COST
+        }
+        Instruction::SignVerify(..) => SIGNATURE_INSTRUCTION_COST,
+        // Note: A call to a closure also costs the instructions of the closure (see `call_cost`),
+        // and a call to a function is priced by its own transition.
+        Instruction::Call(..) => ARITHMETIC_INSTRUCTION_COST,
+    }
+}
+
+/// Returns the cost in microcredits of the given instructions in the given stack,
+/// including the instructions of any closures that are called.
+fn instructions_cost<N: Network>(stack: &Stack<N>, instructions: &[Instruction<N>]) -> Result<u64> {
+    instructions.iter().try_fold(0u64, |cost, instruction| {
+        let cost = cost.saturating_add(instruction_cost(instruction));
+        match instruction {
+            Instruction::Call(call) => Ok(cost.saturating_add(call_cost(stack, call)?)),
+            _ => Ok(cost),
+        }
+    })
+}
+
+/// Returns the cost in microcredits of the instructions of the closure called by the given call.
+/// If the call is to a function, its cost is zero, as the function is priced by its own transition.
+fn call_cost<N: Network>(stack: &Stack<N>, call: &Call<N>) -> Result<u64> {
+    // Retrieve the substack and resource.
+    let (substack, resource) = match call.operator() {
+        CallOperator::Locator(locator) => (stack.get_external_stack(locator.program_id())?, locator.resource()),
+        CallOperator::Resource(resource) => (stack, resource),
+    };
+    // Compute the cost of the instructions of the closure, if the resource is a closure.
+    match substack.program().get_closure(resource) {
+        Ok(closure) => instructions_cost(substack, closure.instructions()),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Returns the cost in microcredits of the given finalize command.
+pub const fn command_cost<N: Network>(command: &Command<N>) -> u64 {
+    match command {
+        Command::Instruction(instruction) => instruction_cost(instruction),
+        Command::Get(..) | Command::Contains(..) => MAPPING_READ_COMMAND_COST,
+        Command::GetOrInit(..) | Command::Set(..) | Command::Remove(..) => MAPPING_WRITE_COMMAND_COST,
+        Command::BranchEq(..) | Command::BranchNeq(..) | Command::Position(..) => BRANCH_COMMAND_COST,
+        Command::RandChaCha(..) => RANDOM_COMMAND_COST,
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the minimum fee in microcredits for the given execution,
+    /// as `(storage cost, transition cost, finalize cost)`.
+    ///
+    /// The storage cost is charged per byte of the execution, the transition cost covers each transition
+    /// and the instructions of its function, and the finalize cost covers each command of its finalize scope.
+    /// As finalize branches only jump forward, each command runs at most once, so this is an upper bound.
+    pub fn execution_cost_breakdown(&self, execution: &Execution<N>) -> Result<(u64, u64, u64)> {
+        // Compute the storage cost.
+        let storage_cost = execution
+            .size_in_bytes()?
+            .checked_mul(N::EXECUTION_STORAGE_FEE_MULTIPLIER)
+            .ok_or_else(|| anyhow!("The storage cost computation overflowed for an execution"))?;
+
+        // Initialize the transition and finalize costs.
+        let mut transition_cost = 0u64;
+        let mut finalize_cost = 0u64;
+
+        for transition in execution.transitions() {
+            // Retrieve the stack.
+            let stack = self.get_stack(transition.program_id())?;
+            // Retrieve the function.
+            let function = stack.get_function(transition.function_name())?;
+
+            // Compute the cost of the transition.
+            let cost = instructions_cost(stack, function.instructions())?.saturating_add(TRANSITION_BASE_COST);
+            transition_cost = transition_cost.saturating_add(cost);
+
+            // Compute the cost of the finalize scope, if it exists.
+            if let Some(finalize) = function.finalize_logic() {
+                let cost = finalize.commands().iter().map(command_cost).fold(0u64, u64::saturating_add);
+                finalize_cost = finalize_cost.saturating_add(cost);
+            }
+        }
+
+        Ok((storage_cost, transition_cost, finalize_cost))
+    }
+
+    /// Returns the minimum fee in microcredits for the given execution.
+    pub fn execution_cost(&self, execution: &Execution<N>) -> Result<u64> {
+        // Compute the cost breakdown.
+        let (storage_cost, transition_cost, finalize_cost) = self.execution_cost_breakdown(execution)?;
+        // Return the total cost.
+        storage_cost
+            .checked_add(transition_cost)
+            .and_then(|cost| cost.checked_add(finalize_cost))
+            .ok_or_else(|| anyhow!("The fee computation overflowed for an execution"))
+    }
+
+    /// Returns the minimum fee in microcredits for the given deployment.
+    pub fn deployment_cost(&self, deployment: &Deployment<N>) -> Result<u64> {
+        deployment
+            .size_in_bytes()?
+            .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
+            .ok_or_else(|| anyhow!("The fee computation overflowed for a deployment"))
+    }
+
+    /// Returns the estimated circuit cost of each function in the given program, without synthesizing them.
+    /// See `Stack::estimate_function` for the assumptions made by the estimate.
+    pub fn estimate_program<A: circuit::Aleo<Network = N>>(
+        &self,
+        program_id: &ProgramID<N>,
+    ) -> Result<Vec<ConstraintEstimate<N>>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Estimate each function.
+        stack.program().functions().keys().map(|function_name| stack.estimate_function::<A>(function_name)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::program::Program;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_instruction_and_command_costs() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    add r1 r1 into r2;
+    hash.bhp256 r1 into r3;
+    finalize r0 r2;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+    contains account[r0] into r4;
+",
+        )
+        .unwrap();
+
+        let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+
+        // Check the instruction costs.
+        let costs = function.instructions().iter().map(instruction_cost).collect::<Vec<_>>();
+        assert_eq!(costs, vec![ARITHMETIC_INSTRUCTION_COST, ALGEBRAIC_HASH_INSTRUCTION_COST]);
+
+        // Check the command costs.
+        let costs = function.finalize_logic().unwrap().commands().iter().map(command_cost).collect::<Vec<_>>();
+        assert_eq!(costs, vec![
+            MAPPING_WRITE_COMMAND_COST,
+            ARITHMETIC_INSTRUCTION_COST,
+            MAPPING_WRITE_COMMAND_COST,
+            MAPPING_READ_COMMAND_COST
+        ]);
+    }
+
+    #[test]
+    fn test_closure_call_cost() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+closure digest:
+    input r0 as u64;
+    hash.bhp256 r0 into r1;
+    add r1 r1 into r2;
+    output r2 as field;
+
+function compute:
+    input r0 as u64.private;
+    call digest r0 into r1;
+    output r1 as field.private;
+",
+        )
+        .unwrap();
+
+        // Initialize a process with the program.
+        let mut process = Process::<CurrentNetwork> {
+            universal_srs: Arc::new(UniversalSRS::load().unwrap()),
+            stacks: IndexMap::new(),
+        };
+        let stack = Stack::new(&process, &program).unwrap();
+        process.add_stack(stack);
+
+        // Retrieve the stack and function.
+        let stack = process.get_stack(program.id()).unwrap();
+        let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+
+        // Ensure the call is priced by the instructions of the closure.
+        let cost = instructions_cost(stack, function.instructions()).unwrap();
+        assert_eq!(cost, 2 * ARITHMETIC_INSTRUCTION_COST + ALGEBRAIC_HASH_INSTRUCTION_COST);
+    }
+
+    #[test]
+    fn test_estimate_program() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program estimate.aleo;
+
+closure product:
+    input r0 as field;
+    mul r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    mul r0 r1 into r2;
+    mul r0 2field into r3;
+    add r2 r3 into r4;
+    call product r4 into r5;
+    output r5 as field.private;
+
+function digest:
+    input r0 as u64.private;
+    hash.bhp256 r0 into r1;
+    output r1 as field.private;
+",
+        )
+        .unwrap();
+
+        // Initialize a process with the program.
+        let mut process = Process::<CurrentNetwork> {
+            universal_srs: Arc::new(UniversalSRS::load().unwrap()),
+            stacks: IndexMap::new(),
+        };
+        let stack = Stack::new(&process, &program).unwrap();
+        process.add_stack(stack);
+
+        // Estimate the program.
+        let estimates = process.estimate_program::<CurrentAleo>(program.id()).unwrap();
+        assert_eq!(estimates.len(), 2);
+
+        // Ensure every instruction in 'compute' is estimated.
+        let compute = &estimates[0];
+        assert_eq!(compute.function_name().to_string(), "compute");
+        assert!(compute.is_complete());
+        let counts = compute.instructions().iter().map(|instruction| instruction.count().unwrap()).collect::<Vec<_>>();
+        // Multiplying two variables costs a constraint, while scaling by a constant or adding is free.
+        assert!(counts[0].matches(0, 0, 1, 1));
+        assert!(counts[1].matches(0, 0, 0, 0));
+        assert!(counts[2].matches(0, 0, 0, 0));
+        // The call costs the instructions of the closure.
+        assert!(counts[3].matches(0, 0, 1, 1));
+        assert_eq!(compute.max_constraints(), Some(2));
+
+        // Ensure the hash in 'digest' is reported as unestimated.
+        let digest = &estimates[1];
+        assert!(!digest.is_complete());
+        assert!(digest.instructions()[0].count().is_none());
+        assert_eq!(digest.max_constraints(), None);
+    }
+}
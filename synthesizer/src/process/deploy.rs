@@ -15,7 +15,7 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Deploys the given program ID, if it does not exist.
+    /// Deploys the given program ID. If the program already exists, its next edition is deployed.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -24,8 +24,8 @@ impl<N: Network> Process<N> {
     ) -> Result<Deployment<N>> {
         let timer = timer!("Process::deploy");
 
-        // Compute the stack.
-        let stack = Stack::new(self, program)?;
+        // Compute the stack, for the next edition of the program.
+        let stack = self.compute_stack(program, self.next_edition(program.id())?)?;
         lap!(timer, "Compute the stack");
 
         // Return the deployment.
@@ -45,12 +45,9 @@ impl<N: Network> Process<N> {
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("Process::verify_deployment");
-        // Retrieve the program ID.
-        let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        // Note: This ensures a new program does not already exist, and an upgrade follows the latest edition.
+        let stack = self.compute_stack(deployment.program(), deployment.edition())?;
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
@@ -69,7 +66,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_stack(deployment.program(), deployment.edition())?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
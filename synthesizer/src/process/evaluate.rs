@@ -37,4 +37,29 @@ impl<N: Network> Process<N> {
         finish!(timer);
         response
     }
+
+    /// Evaluates a program function on the given request, recording each evaluated instruction into the given trace.
+    /// The trace is populated up to and including the failing instruction, if evaluation fails.
+    #[inline]
+    pub fn evaluate_with_trace<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        trace: &Trace<N>,
+    ) -> Result<Response<N>> {
+        let timer = timer!("Process::evaluate_with_trace");
+        let logname = "Process::evaluate_with_trace";
+        web_sys::console::log_1(&logname.into());
+        web_sys::console::time_with_label(logname);
+        // Retrieve the main request (without popping it).
+        let request = authorization.peek_next()?;
+
+        // Evaluate the function.
+        let call_stack = CallStack::evaluate_with_trace(authorization, trace.clone())?;
+        let response = self.get_stack(request.program_id())?.evaluate_function::<A>(call_stack);
+        lap!(timer, "Evaluate the function");
+        web_sys::console::time_end_with_label(logname);
+
+        finish!(timer);
+        response
+    }
 }
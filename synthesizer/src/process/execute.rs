@@ -21,6 +21,27 @@ impl<N: Network> Process<N> {
         &self,
         authorization: Authorization<N>,
         rng: &mut R,
+    ) -> Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        self.execute_inner::<A, R>(authorization, false, rng)
+    }
+
+    /// Executes the given authorization, recording the circuit cost of each instruction into the metrics.
+    #[inline]
+    pub fn execute_with_profile<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        self.execute_inner::<A, R>(authorization, true, rng)
+    }
+
+    /// Executes the given authorization, recording the circuit cost of each instruction if `profile` is `true`.
+    #[inline]
+    fn execute_inner<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        profile: bool,
+        rng: &mut R,
     ) -> Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
         let timer = timer!("Process::execute");
 
@@ -37,7 +58,12 @@ impl<N: Network> Process<N> {
         // Initialize the metrics.
         let metrics = Arc::new(RwLock::new(Vec::new()));
         // Initialize the call stack.
-        let call_stack = CallStack::execute(authorization, execution.clone(), inclusion.clone(), metrics.clone())?;
+        let call_stack = match profile {
+            true => {
+                CallStack::execute_with_profile(authorization, execution.clone(), inclusion.clone(), metrics.clone())?
+            }
+            false => CallStack::execute(authorization, execution.clone(), inclusion.clone(), metrics.clone())?,
+        };
         lap!(timer, "Initialize call stack");
         // Execute the circuit.
         let response = self.get_stack(request.program_id())?.execute_function::<A, R>(call_stack, rng)?;
@@ -206,8 +232,9 @@ impl<N: Network> Process<N> {
             #[cfg(debug_assertions)]
             println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
-            // Retrieve the verifying key.
-            let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
+            // Retrieve the verifying key, for the edition the transition was executed against.
+            let verifying_key =
+                stack.get_verifying_key_for_edition(function.name(), execution.edition(stack.program_id()))?;
             // Ensure the transition proof is valid.
             ensure!(
                 verifying_key.verify(&function.name().to_string(), &inputs, transition.proof()),
@@ -23,19 +23,45 @@ impl<N: Network> Process<N> {
         credits: Record<N, Plaintext<N>>,
         fee_in_microcredits: u64,
         rng: &mut R,
+    ) -> Result<(Response<N>, Transition<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        // Construct the inputs.
+        let inputs = [Value::Record(credits), Value::from_str(&U64::<N>::new(fee_in_microcredits).to_string())?];
+        // Execute the fee.
+        self.execute_fee_function::<A, R>(private_key, "fee", &inputs, rng)
+    }
+
+    /// Executes the fee given the fee amount (in microcredits), which is paid from the public balance of the caller.
+    #[inline]
+    pub fn execute_fee_public<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Transition<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        // Construct the inputs.
+        let inputs = [Value::from_str(&U64::<N>::new(fee_in_microcredits).to_string())?];
+        // Execute the fee.
+        self.execute_fee_function::<A, R>(private_key, "fee_public", &inputs, rng)
+    }
+
+    /// Executes the given fee function in 'credits.aleo' on the given inputs.
+    #[inline]
+    fn execute_fee_function<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: &str,
+        inputs: &[Value<N>],
+        rng: &mut R,
     ) -> Result<(Response<N>, Transition<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
         let timer = timer!("Process::execute_fee");
 
         // Ensure the fee has the correct program ID.
         let program_id = ProgramID::from_str("credits.aleo")?;
         // Ensure the fee has the correct function.
-        let function_name = Identifier::from_str("fee")?;
+        let function_name = Identifier::from_str(function_name)?;
 
         // Retrieve the input types.
         let input_types = self.get_program(program_id)?.get_function(&function_name)?.input_types();
-        // Construct the inputs.
-        let inputs = [Value::Record(credits), Value::from_str(&U64::<N>::new(fee_in_microcredits).to_string())?];
-        lap!(timer, "Construct the inputs");
         // Compute the request.
         let request = Request::sign(private_key, program_id, function_name, inputs.iter(), &input_types, rng)?;
         lap!(timer, "Compute the request");
@@ -96,7 +122,10 @@ impl<N: Network> Process<N> {
         ensure!(*fee.program_id() == fee_program_id, "Incorrect program ID for fee");
 
         // Ensure the fee has the correct function.
-        let fee_function = Identifier::from_str("fee")?;
+        let fee_function = match fee.is_fee_public() {
+            true => Identifier::from_str("fee_public")?,
+            false => Identifier::from_str("fee")?,
+        };
         ensure!(*fee.function_name() == fee_function, "Incorrect function name for fee");
 
         // Ensure the transition ID of the fee is correct.
@@ -144,6 +173,13 @@ impl<N: Network> Process<N> {
         inputs.extend(fee.inputs().iter().flat_map(|input| input.verifier_inputs()));
         // Extend the inputs with the output IDs.
         inputs.extend(fee.outputs().iter().flat_map(|output| output.verifier_inputs()));
+        // Extend the inputs with the checksum of the finalize inputs, if the fee is paid from the public balance.
+        if let Some(finalize) = fee.finalize() {
+            // Convert the finalize inputs into concatenated bits.
+            let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
+            // Compute the checksum of the finalize inputs.
+            inputs.push(*N::hash_bhp1024(&finalize_bits)?);
+        }
         lap!(timer, "Construct the verifier inputs");
 
         // Retrieve the stack.
@@ -158,11 +194,24 @@ impl<N: Network> Process<N> {
         #[cfg(debug_assertions)]
         println!("Fee public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
-        // Ensure the fee contains input records.
-        ensure!(
-            fee.inputs().iter().any(|input| matches!(input, Input::Record(..))),
-            "The fee proof is the wrong type (found *no* input records)"
-        );
+        match fee.is_fee_public() {
+            true => {
+                // Ensure the public fee does not contain input records.
+                ensure!(
+                    !fee.inputs().iter().any(|input| matches!(input, Input::Record(..))),
+                    "The fee proof is the wrong type (found input records in a public fee)"
+                );
+                // Ensure the public fee contains the finalize inputs.
+                ensure!(fee.finalize().is_some(), "The public fee is missing inputs for 'finalize'");
+            }
+            false => {
+                // Ensure the fee contains input records.
+                ensure!(
+                    fee.inputs().iter().any(|input| matches!(input, Input::Record(..))),
+                    "The fee proof is the wrong type (found *no* input records)"
+                );
+            }
+        }
 
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
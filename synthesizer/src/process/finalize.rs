@@ -27,7 +27,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::finalize_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_stack(deployment.program(), deployment.edition())?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -38,16 +38,50 @@ impl<N: Network> Process<N> {
 
         // Retrieve the program ID.
         let program_id = deployment.program_id();
+        // Retrieve the previous edition of the program, if this deployment is an upgrade.
+        let previous_program = match deployment.edition() == N::EDITION {
+            true => None,
+            false => Some(self.get_program(program_id)?),
+        };
 
         // Initialize the mappings, and store their finalize operations.
         atomic_batch_scope!(store, {
             // Initialize a list for the finalize operations.
             let mut finalize_operations = Vec::with_capacity(deployment.program().mappings().len());
 
-            // Iterate over the mappings.
-            for mapping in deployment.program().mappings().values() {
-                // Initialize the mapping.
-                finalize_operations.push(store.initialize_mapping(program_id, mapping.name())?);
+            match previous_program {
+                // If this is a new program, initialize all of its mappings.
+                None => {
+                    // Iterate over the mappings.
+                    for mapping in deployment.program().mappings().values() {
+                        // Initialize the mapping.
+                        finalize_operations.push(store.initialize_mapping(program_id, mapping.name())?);
+                    }
+                }
+                // If this is an upgrade, preserve the mappings whose key and value types are unchanged.
+                Some(previous_program) => {
+                    // Remove the mappings that were dropped from the program.
+                    for name in previous_program.mappings().keys() {
+                        if !deployment.program().mappings().contains_key(name) {
+                            finalize_operations.push(store.remove_mapping(program_id, name)?);
+                        }
+                    }
+                    // Iterate over the mappings.
+                    for mapping in deployment.program().mappings().values() {
+                        match previous_program.mappings().get(mapping.name()) {
+                            // If the key and value types are unchanged, preserve the mapping.
+                            Some(previous)
+                                if previous.key() == mapping.key() && previous.value() == mapping.value() => {}
+                            // If the key or value types changed, reset the mapping.
+                            Some(_) => {
+                                finalize_operations.push(store.remove_mapping(program_id, mapping.name())?);
+                                finalize_operations.push(store.initialize_mapping(program_id, mapping.name())?);
+                            }
+                            // If the mapping is new, initialize the mapping.
+                            None => finalize_operations.push(store.initialize_mapping(program_id, mapping.name())?),
+                        }
+                    }
+                }
             }
             lap!(timer, "Initialize the program mappings");
 
@@ -64,6 +98,7 @@ impl<N: Network> Process<N> {
     #[inline]
     pub(crate) fn finalize_execution<P: FinalizeStorage<N>>(
         &self,
+        state: FinalizeGlobalState,
         store: &FinalizeStore<N, P>,
         execution: &Execution<N>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
@@ -99,57 +134,131 @@ impl<N: Network> Process<N> {
                 #[cfg(debug_assertions)]
                 println!("Finalizing transition for {}/{}...", transition.program_id(), transition.function_name());
 
-                // Retrieve the stack.
-                let stack = self.get_stack(transition.program_id())?;
-                // Retrieve the function name.
-                let function_name = transition.function_name();
-
-                // If there is a finalize scope, finalize the function.
-                if let Some((_, finalize)) = stack.get_function(function_name)?.finalize() {
-                    // Retrieve the finalize inputs.
-                    let inputs = match transition.finalize() {
-                        Some(inputs) => inputs,
-                        // Ensure the transition contains finalize inputs.
-                        None => bail!("The transition is missing inputs for 'finalize'"),
-                    };
+                // Finalize the transition, and store its finalize operations.
+                finalize_operations.extend(self.finalize_transition(state, store, transition)?);
+                lap!(timer, "Finalize transition for {}", transition.function_name());
+            }
+            finish!(timer);
 
-                    // Initialize the registers.
-                    let mut registers = FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone());
+            // Return the finalize operations.
+            Ok(finalize_operations)
+        })
+    }
 
-                    // Store the inputs.
-                    finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(
-                        |(register, input)| {
-                            // Assign the input value to the register.
-                            registers.store(stack, register, input.clone())
-                        },
-                    )?;
-
-                    // Evaluate the commands.
-                    for command in finalize.commands() {
-                        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                            command.finalize(stack, store, &mut registers)
-                        }));
-                        match result {
-                            // If the evaluation succeeds with an operation, add it to the list.
-                            Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
-                            // If the evaluation succeeds with no operation, continue.
-                            Ok(Ok(None)) => (),
-                            // If the evaluation fails, bail and return the error.
-                            Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
-                            // If the evaluation fails, bail and return the error.
-                            Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
-                        }
-                    }
+    /// Finalizes the fee.
+    /// This method assumes the given fee **is valid**.
+    /// This method should **only** be called by `VM::finalize()`.
+    #[inline]
+    pub(crate) fn finalize_fee<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        fee: &Fee<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        let timer = timer!("Process::finalize_fee");
+
+        atomic_batch_scope!(store, {
+            // Finalize the fee transition.
+            // Note: Only a fee that is paid from the public balance contains a finalize scope.
+            let finalize_operations = self.finalize_transition(state, store, fee)?;
 
-                    lap!(timer, "Finalize transition for {function_name}");
-                }
-            }
             finish!(timer);
 
             // Return the finalize operations.
             Ok(finalize_operations)
         })
     }
+
+    /// Finalizes the given transition, returning its finalize operations.
+    /// If the function of the transition does not have a finalize scope, no finalize operations are returned.
+    #[inline]
+    fn finalize_transition<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        transition: &Transition<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(transition.program_id())?;
+        // Retrieve the function name.
+        let function_name = transition.function_name();
+
+        // Initialize a list for finalize operations.
+        let mut finalize_operations = Vec::new();
+
+        // If there is a finalize scope, finalize the function.
+        if let Some((_, finalize)) = stack.get_function(function_name)?.finalize() {
+            // Retrieve the finalize inputs.
+            let inputs = match transition.finalize() {
+                Some(inputs) => inputs,
+                // Ensure the transition contains finalize inputs.
+                None => bail!("The transition is missing inputs for 'finalize'"),
+            };
+
+            // Initialize the registers.
+            let mut registers = FinalizeRegisters::<N>::new(
+                state,
+                *transition.id(),
+                stack.get_finalize_types(finalize.name())?.clone(),
+            );
+
+            // Store the inputs.
+            finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
+                // Assign the input value to the register.
+                registers.store(stack, register, input.clone())
+            })?;
+
+            // Initialize a counter for the index of the next command.
+            let mut counter = 0;
+
+            // Evaluate the commands.
+            while counter < finalize.commands().len() {
+                // Retrieve the command.
+                let command = &finalize.commands()[counter];
+                // If the command is a branch, determine the index of the next command.
+                let position = match command {
+                    Command::BranchEq(branch_eq) => Some((branch_eq.evaluate(stack, &registers), branch_eq.position())),
+                    Command::BranchNeq(branch_neq) => {
+                        Some((branch_neq.evaluate(stack, &registers), branch_neq.position()))
+                    }
+                    _ => None,
+                };
+                if let Some((is_taken, position)) = position {
+                    counter = match is_taken {
+                        // If the branch is taken, jump to the position.
+                        Ok(true) => match finalize.positions().get(position) {
+                            // Ensure the branch jumps forward.
+                            Some(index) if *index > counter => *index,
+                            _ => bail!("'finalize' failed to branch to position '{position}' ({command})"),
+                        },
+                        // If the branch is not taken, continue to the next command.
+                        Ok(false) => counter + 1,
+                        // If the evaluation fails, bail and return the error.
+                        Err(error) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                    };
+                    continue;
+                }
+
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    command.finalize(stack, store, &mut registers)
+                }));
+                match result {
+                    // If the evaluation succeeds with an operation, add it to the list.
+                    Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
+                    // If the evaluation succeeds with no operation, continue.
+                    Ok(Ok(None)) => (),
+                    // If the evaluation fails, bail and return the error.
+                    Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                    // If the evaluation fails, bail and return the error.
+                    Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                }
+                counter += 1;
+            }
+        }
+
+        // Return the finalize operations.
+        Ok(finalize_operations)
+    }
 }
 
 #[cfg(test)]
@@ -15,6 +15,9 @@
 mod stack;
 pub use stack::*;
 
+mod cost;
+pub use cost::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -28,7 +31,7 @@ mod tests;
 use crate::{
     atomic_batch_scope,
     block::{Deployment, Execution, Fee, FinalizeOperation, Input, Transition},
-    program::{Instruction, Program},
+    program::{finalize::Command, Instruction, Program},
     store::{FinalizeStorage, FinalizeStore},
 };
 use console::{
@@ -100,12 +103,94 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
+    /// Type-checks the given program against the process, without adding it to the process.
+    /// Returns every error that is found, each located at the statement that caused it where possible.
+    #[inline]
+    pub fn check_program(&self, program: &Program<N>) -> Vec<Error> {
+        Stack::check(self, program)
+    }
+
+    /// Adds a new edition of an existing program to the process.
+    /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
+    #[inline]
+    pub fn upgrade_program(&mut self, program: &Program<N>) -> Result<()> {
+        // Determine the next edition of the program.
+        let edition = self.next_edition(program.id())?;
+        // Compute the program stack, and add it to the process.
+        self.add_stack(Stack::new_edition(self, program, edition)?);
+        Ok(())
+    }
+
+    /// Returns the edition that the next deployment of the given program ID must use.
+    #[inline]
+    pub fn next_edition(&self, program_id: &ProgramID<N>) -> Result<u16> {
+        match self.stacks.get(program_id) {
+            Some(stack) => {
+                stack.edition().checked_add(1).ok_or_else(|| anyhow!("Program '{program_id}' cannot be upgraded further"))
+            }
+            None => Ok(N::EDITION),
+        }
+    }
+
     /// Adds a new stack to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
     pub fn add_stack(&mut self, stack: Stack<N>) {
         // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), stack);
+        if self.stacks.insert(*stack.program_id(), stack).is_some() {
+            // If the program was upgraded, refresh the external stacks of its dependents.
+            // Note: Stacks are stored in import order, so each dependency is refreshed before its dependents.
+            for index in 0..self.stacks.len() {
+                let external_ids = self.stacks[index].program().imports().keys().copied().collect::<Vec<_>>();
+                for program_id in external_ids {
+                    if let Some(external_stack) = self.stacks.get(&program_id).cloned() {
+                        self.stacks[index].replace_external_stack(external_stack);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Removes the given programs from the process, along with every program that (transitively) imports them.
+    /// Returns the IDs of the removed programs.
+    #[inline]
+    pub fn remove_programs(&mut self, program_ids: &[ProgramID<N>]) -> Result<Vec<ProgramID<N>>> {
+        // Ensure 'credits.aleo' is not removed.
+        let credits_id = ProgramID::from_str("credits.aleo")?;
+        ensure!(!program_ids.contains(&credits_id), "Cannot remove 'credits.aleo' from the process");
+
+        // Determine the programs to remove.
+        // Note: An upgraded program may import programs that were added after it, so this iterates to a fixpoint.
+        let mut removed = program_ids.iter().filter(|id| self.stacks.contains_key(*id)).copied().collect::<Vec<_>>();
+        loop {
+            let importers = self
+                .stacks
+                .iter()
+                .filter(|(id, stack)| {
+                    !removed.contains(*id) && stack.program().imports().keys().any(|import| removed.contains(import))
+                })
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>();
+            if importers.is_empty() {
+                break;
+            }
+            removed.extend(importers);
+        }
+
+        // Remove the stacks.
+        for program_id in &removed {
+            self.stacks.shift_remove(program_id);
+        }
+        Ok(removed)
+    }
+
+    /// Returns the stack for the given program, deployed at the given edition.
+    #[inline]
+    fn compute_stack(&self, program: &Program<N>, edition: u16) -> Result<Stack<N>> {
+        match edition == N::EDITION {
+            true => Stack::new(self, program),
+            false => Stack::new_edition(self, program, edition),
+        }
     }
 }
 
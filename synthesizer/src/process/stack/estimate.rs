@@ -0,0 +1,444 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Call;
+use console::program::LiteralType;
+
+use circuit::{Count, Measurement, Mode};
+
+/// The estimated circuit cost of a single instruction.
+#[derive(Copy, Clone, Debug)]
+pub struct InstructionEstimate {
+    /// The index of the instruction in its function or closure.
+    index: usize,
+    /// The number of constants, public variables, private variables, and constraints, if known.
+    count: Option<Count>,
+}
+
+impl InstructionEstimate {
+    /// Returns the index of the instruction in its function or closure.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of constants, public variables, private variables, and constraints.
+    /// Returns `None` if the instruction is not described by the `Metrics` of its circuit types.
+    pub const fn count(&self) -> Option<Count> {
+        self.count
+    }
+}
+
+/// The estimated circuit cost of a function, derived from its register types without synthesizing it.
+#[derive(Clone, Debug)]
+pub struct ConstraintEstimate<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The estimate for each instruction, in program order.
+    instructions: Vec<InstructionEstimate>,
+}
+
+impl<N: Network> ConstraintEstimate<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the estimate for each instruction, in program order.
+    pub fn instructions(&self) -> &[InstructionEstimate] {
+        &self.instructions
+    }
+
+    /// Returns `true` if every instruction in the function has an estimate.
+    pub fn is_complete(&self) -> bool {
+        self.instructions.iter().all(|instruction| instruction.count.is_some())
+    }
+
+    /// Returns the sum of the estimated instructions.
+    /// If the estimate is not complete, this is a lower bound on the cost of the function body.
+    pub fn total(&self) -> Count {
+        self.instructions
+            .iter()
+            .filter_map(|instruction| instruction.count)
+            .fold(Count::zero(), |sum, count| sum + count)
+    }
+
+    /// Returns the largest number of constraints the function body may use.
+    /// Returns `None` if the estimate is not complete, as the bound would not cover every instruction.
+    pub fn max_constraints(&self) -> Option<u64> {
+        match self.is_complete() {
+            true => match self.total().3 {
+                Measurement::Exact(num_constraints)
+                | Measurement::Range(_, num_constraints)
+                | Measurement::UpperBound(num_constraints) => Some(num_constraints),
+            },
+            false => None,
+        }
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Estimates the circuit cost of the given function from its register types, without synthesizing it.
+    ///
+    /// Every value derived from a non-constant operand is assumed to be private, so the estimate
+    /// is an upper bound for instructions whose cost depends on the mode of their operands.
+    pub fn estimate_function<A: circuit::Aleo<Network = N>>(
+        &self,
+        function_name: &Identifier<N>,
+    ) -> Result<ConstraintEstimate<N>> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+
+        // Determine the mode of each input.
+        let modes = function
+            .inputs()
+            .iter()
+            .map(|input| {
+                let mode = match input.value_type() {
+                    ValueType::Constant(..) => Mode::Constant,
+                    ValueType::Public(..) => Mode::Public,
+                    ValueType::Private(..) | ValueType::Record(..) | ValueType::ExternalRecord(..) => Mode::Private,
+                };
+                (input.register().locator(), mode)
+            })
+            .collect();
+
+        // Estimate the instructions.
+        let instructions = self.estimate_instructions::<A>(function.name(), function.instructions(), modes)?;
+        Ok(ConstraintEstimate { program_id: *self.program_id(), function_name: *function.name(), instructions })
+    }
+
+    /// Estimates the circuit cost of each instruction, given the modes of the input registers.
+    fn estimate_instructions<A: circuit::Aleo<Network = N>>(
+        &self,
+        name: &Identifier<N>,
+        instructions: &[Instruction<N>],
+        mut modes: IndexMap<u64, Mode>,
+    ) -> Result<Vec<InstructionEstimate>> {
+        // Retrieve the register types.
+        let register_types = self.get_register_types(name)?;
+
+        let mut estimates = Vec::with_capacity(instructions.len());
+        for (index, instruction) in instructions.iter().enumerate() {
+            // Determine the mode of each operand.
+            let operand_modes = instruction
+                .operands()
+                .iter()
+                .map(|operand| match operand {
+                    Operand::Literal(..) | Operand::ProgramID(..) => Mode::Constant,
+                    Operand::Register(register) => modes.get(&register.locator()).copied().unwrap_or(Mode::Private),
+                    Operand::Caller | Operand::BlockHeight | Operand::BlockTimestamp => Mode::Private,
+                })
+                .collect::<Vec<_>>();
+
+            // Estimate the instruction.
+            let count = match instruction {
+                Instruction::Call(call) => self.estimate_call::<A>(call, &operand_modes)?,
+                _ => {
+                    // Retrieve the literal type of each operand, if every operand is a literal.
+                    let literal_types = instruction
+                        .operands()
+                        .iter()
+                        .map(|operand| match register_types.get_type_from_operand(self, operand)? {
+                            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => Ok(Some(literal_type)),
+                            _ => Ok(None),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    match literal_types.into_iter().collect::<Option<Vec<_>>>() {
+                        Some(types) => count_literal_operation::<A>(&instruction.opcode(), &types, &operand_modes),
+                        None => None,
+                    }
+                }
+            };
+
+            // The destinations are constant if every operand is constant, and are otherwise assumed to be private.
+            let mode = match operand_modes.iter().all(Mode::is_constant) {
+                true => Mode::Constant,
+                false => Mode::Private,
+            };
+            for register in instruction.destinations() {
+                modes.insert(register.locator(), mode);
+            }
+
+            estimates.push(InstructionEstimate { index, count });
+        }
+        Ok(estimates)
+    }
+
+    /// Estimates the circuit cost of a `call` instruction.
+    /// A call to a closure costs the sum of its instructions, while a call to a function is not estimated.
+    fn estimate_call<A: circuit::Aleo<Network = N>>(&self, call: &Call<N>, modes: &[Mode]) -> Result<Option<Count>> {
+        // Retrieve the substack and resource.
+        let (substack, resource) = match call.operator() {
+            CallOperator::Locator(locator) => (self.get_external_stack(locator.program_id())?, locator.resource()),
+            CallOperator::Resource(resource) => (self, resource),
+        };
+        // If the resource is a function, its cost in the caller is not estimated.
+        let closure = match substack.program().get_closure(resource) {
+            Ok(closure) => closure,
+            Err(_) => return Ok(None),
+        };
+
+        // Estimate the closure, with its inputs in the modes of the call operands.
+        let modes =
+            closure.inputs().iter().zip_eq(modes).map(|(input, mode)| (input.register().locator(), *mode)).collect();
+        let instructions = substack.estimate_instructions::<A>(closure.name(), closure.instructions(), modes)?;
+
+        // Sum the instructions, if they were all estimated.
+        Ok(instructions
+            .iter()
+            .map(|instruction| instruction.count)
+            .collect::<Option<Vec<_>>>()
+            .map(|counts| counts.into_iter().fold(Count::zero(), |sum, count| sum + count)))
+    }
+}
+
+/// Dispatches an integer literal type to its integer type.
+macro_rules! integer {
+    ($literal_type:expr, |$I:ident| $body:expr) => {
+        match $literal_type {
+            LiteralType::I8 => {
+                type $I = i8;
+                Some($body)
+            }
+            LiteralType::I16 => {
+                type $I = i16;
+                Some($body)
+            }
+            LiteralType::I32 => {
+                type $I = i32;
+                Some($body)
+            }
+            LiteralType::I64 => {
+                type $I = i64;
+                Some($body)
+            }
+            LiteralType::I128 => {
+                type $I = i128;
+                Some($body)
+            }
+            LiteralType::U8 => {
+                type $I = u8;
+                Some($body)
+            }
+            LiteralType::U16 => {
+                type $I = u16;
+                Some($body)
+            }
+            LiteralType::U32 => {
+                type $I = u32;
+                Some($body)
+            }
+            LiteralType::U64 => {
+                type $I = u64;
+                Some($body)
+            }
+            LiteralType::U128 => {
+                type $I = u128;
+                Some($body)
+            }
+            _ => None,
+        }
+    };
+}
+
+/// Dispatches a magnitude literal type (i.e. an exponent or shift amount) to its integer type.
+macro_rules! magnitude {
+    ($literal_type:expr, |$M:ident| $body:expr) => {
+        match $literal_type {
+            LiteralType::U8 => {
+                type $M = u8;
+                $body
+            }
+            LiteralType::U16 => {
+                type $M = u16;
+                $body
+            }
+            LiteralType::U32 => {
+                type $M = u32;
+                $body
+            }
+            _ => None,
+        }
+    };
+}
+
+/// Returns the circuit cost of a literal operation, if it is described by the `Metrics` of its circuit types.
+fn count_literal_operation<A: circuit::Aleo>(opcode: &str, types: &[LiteralType], modes: &[Mode]) -> Option<Count> {
+    use circuit::{
+        prelude::{
+            count,
+            AbsChecked,
+            AbsWrapped,
+            AddWrapped,
+            Compare,
+            DivWrapped,
+            Double,
+            Equal,
+            Inverse,
+            Metrics,
+            MulWrapped,
+            Nor,
+            PowChecked,
+            PowWrapped,
+            RemWrapped,
+            ShlChecked,
+            ShlWrapped,
+            ShrChecked,
+            ShrWrapped,
+            Square,
+            SquareRoot,
+            SubWrapped,
+            Ternary,
+        },
+        types::integers::Integer,
+        Address,
+        Boolean,
+        Field,
+        Group,
+        Scalar,
+    };
+    use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Sub};
+
+    match (opcode, types, modes) {
+        // Field operations.
+        ("add", [LiteralType::Field, LiteralType::Field], [a, b]) => {
+            Some(count!(Field<A>, Add<Field<A>, Output = Field<A>>, &(*a, *b)))
+        }
+        ("sub", [LiteralType::Field, LiteralType::Field], [a, b]) => {
+            Some(count!(Field<A>, Sub<Field<A>, Output = Field<A>>, &(*a, *b)))
+        }
+        ("mul", [LiteralType::Field, LiteralType::Field], [a, b]) => {
+            Some(count!(Field<A>, Mul<Field<A>, Output = Field<A>>, &(*a, *b)))
+        }
+        ("div", [LiteralType::Field, LiteralType::Field], [a, b]) => {
+            Some(count!(Field<A>, Div<Field<A>, Output = Field<A>>, &(*a, *b)))
+        }
+        ("double", [LiteralType::Field], [a]) => Some(count!(Field<A>, Double<Output = Field<A>>, a)),
+        ("inv", [LiteralType::Field], [a]) => Some(count!(Field<A>, Inverse<Output = Field<A>>, a)),
+        ("neg", [LiteralType::Field], [a]) => Some(count!(Field<A>, Neg<Output = Field<A>>, a)),
+        ("square", [LiteralType::Field], [a]) => Some(count!(Field<A>, Square<Output = Field<A>>, a)),
+        ("sqrt", [LiteralType::Field], [a]) => Some(count!(Field<A>, SquareRoot<Output = Field<A>>, a)),
+        ("is.eq" | "is.neq", [LiteralType::Field, LiteralType::Field], [a, b]) => {
+            Some(count!(Field<A>, Equal<Field<A>, Output = Boolean<A>>, &(*a, *b)))
+        }
+        ("ternary", [LiteralType::Boolean, LiteralType::Field, LiteralType::Field], [c, a, b]) => {
+            Some(count!(Field<A>, Ternary<Boolean = Boolean<A>, Output = Field<A>>, &(*c, *a, *b)))
+        }
+        // Group operations.
+        ("add", [LiteralType::Group, LiteralType::Group], [a, b]) => {
+            Some(count!(Group<A>, Add<Group<A>, Output = Group<A>>, &(*a, *b)))
+        }
+        ("sub", [LiteralType::Group, LiteralType::Group], [a, b]) => {
+            Some(count!(Group<A>, Sub<Group<A>, Output = Group<A>>, &(*a, *b)))
+        }
+        ("neg", [LiteralType::Group], [a]) => Some(count!(Group<A>, Neg<Output = Group<A>>, a)),
+        ("ternary", [LiteralType::Boolean, LiteralType::Group, LiteralType::Group], [c, a, b]) => {
+            Some(count!(Group<A>, Ternary<Boolean = Boolean<A>, Output = Group<A>>, &(*c, *a, *b)))
+        }
+        // Scalar operations.
+        ("add", [LiteralType::Scalar, LiteralType::Scalar], [a, b]) => {
+            Some(count!(Scalar<A>, Add<Scalar<A>, Output = Scalar<A>>, &(*a, *b)))
+        }
+        // Address operations.
+        ("ternary", [LiteralType::Boolean, LiteralType::Address, LiteralType::Address], [c, a, b]) => {
+            Some(count!(Address<A>, Ternary<Boolean = Boolean<A>, Output = Address<A>>, &(*c, *a, *b)))
+        }
+        // Boolean operations.
+        ("nor", [LiteralType::Boolean, LiteralType::Boolean], [a, b]) => {
+            Some(count!(Boolean<A>, Nor<Boolean<A>, Output = Boolean<A>>, &(*a, *b)))
+        }
+        ("not", [LiteralType::Boolean], [a]) => Some(count!(Boolean<A>, Not<Output = Boolean<A>>, a)),
+        // Integer operations.
+        ("abs", [x], [a]) => integer!(x, |I| count!(Integer<A, I>, AbsChecked<Output = Integer<A, I>>, a)),
+        ("abs.w", [x], [a]) => integer!(x, |I| count!(Integer<A, I>, AbsWrapped<Output = Integer<A, I>>, a)),
+        ("neg", [x], [a]) => integer!(x, |I| count!(Integer<A, I>, Neg<Output = Integer<A, I>>, a)),
+        ("not", [x], [a]) => integer!(x, |I| count!(Integer<A, I>, Not<Output = Integer<A, I>>, a)),
+        ("add", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, Add<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("add.w", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, AddWrapped<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("sub", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, Sub<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("sub.w", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, SubWrapped<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("mul", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, Mul<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("mul.w", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, MulWrapped<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("div", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, Div<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("div.w", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, DivWrapped<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("rem", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, Rem<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("rem.w", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, RemWrapped<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("and", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, BitAnd<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("or", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, BitOr<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("xor", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, BitXor<Integer<A, I>, Output = Integer<A, I>>, &(*a, *b)))
+        }
+        ("gt" | "gte" | "lt" | "lte", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, Compare<Integer<A, I>, Output = Boolean<A>>, &(*a, *b)))
+        }
+        ("is.eq" | "is.neq", [x, y], [a, b]) if x == y => {
+            integer!(x, |I| count!(Integer<A, I>, Equal<Integer<A, I>, Output = Boolean<A>>, &(*a, *b)))
+        }
+        ("ternary", [LiteralType::Boolean, x, y], [c, a, b]) if x == y => integer!(x, |I| {
+            count!(Integer<A, I>, Ternary<Boolean = Boolean<A>, Output = Integer<A, I>>, &(*c, *a, *b))
+        }),
+        ("pow", [x, y], [a, b]) => magnitude!(y, |M| integer!(x, |I| {
+            count!(Integer<A, I>, PowChecked<Integer<A, M>, Output = Integer<A, I>>, &(*a, *b))
+        })),
+        ("pow.w", [x, y], [a, b]) => magnitude!(y, |M| integer!(x, |I| {
+            count!(Integer<A, I>, PowWrapped<Integer<A, M>, Output = Integer<A, I>>, &(*a, *b))
+        })),
+        ("shl", [x, y], [a, b]) => magnitude!(y, |M| integer!(x, |I| {
+            count!(Integer<A, I>, ShlChecked<Integer<A, M>, Output = Integer<A, I>>, &(*a, *b))
+        })),
+        ("shl.w", [x, y], [a, b]) => magnitude!(y, |M| integer!(x, |I| {
+            count!(Integer<A, I>, ShlWrapped<Integer<A, M>, Output = Integer<A, I>>, &(*a, *b))
+        })),
+        ("shr", [x, y], [a, b]) => magnitude!(y, |M| integer!(x, |I| {
+            count!(Integer<A, I>, ShrChecked<Integer<A, M>, Output = Integer<A, I>>, &(*a, *b))
+        })),
+        ("shr.w", [x, y], [a, b]) => magnitude!(y, |M| integer!(x, |I| {
+            count!(Integer<A, I>, ShrWrapped<Integer<A, M>, Output = Integer<A, I>>, &(*a, *b))
+        })),
+        // The remaining operations are not described by `Metrics`.
+        _ => None,
+    }
+}
@@ -35,6 +35,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
         }
 
+        // Retrieve the trace, if one is being recorded.
+        let trace = call_stack.trace().cloned();
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(closure.name())?.clone());
         // Set the transition caller.
@@ -51,12 +54,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
-            }
-        }
+        self.evaluate_instructions(closure.name(), closure.instructions(), &mut registers, trace.as_ref())?;
         lap!(timer, "Evaluate the instructions");
 
         // Load the outputs.
@@ -75,6 +73,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     }
                     // If the operand is the caller, retrieve the caller from the registers.
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
+                    // If the operand is the block height or block timestamp, throw an error.
+                    Operand::BlockHeight | Operand::BlockTimestamp => {
+                        bail!("Forbidden operation: Cannot use '{}' outside of 'finalize'", output.operand())
+                    }
                 }
             })
             .collect();
@@ -95,7 +97,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         web_sys::console::time_with_label("[evaluate] evaluate_function");
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
+            CallStack::Evaluate(authorization, ..) => (authorization.next()?, call_stack),
             CallStack::Execute(authorization, ..) => (authorization.peek_next()?, call_stack.replicate()),
             _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
         };
@@ -133,6 +135,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         web_sys::console::log_1(&"[evaluate] Perform input checks".into());
         web_sys::console::time_with_label("[evaluate] Perform input checks");
 
+        // Retrieve the trace, if one is being recorded.
+        let trace = call_stack.trace().cloned();
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
         // Set the transition caller.
@@ -162,12 +167,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         web_sys::console::time_with_label("[evaluate] Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in function.instructions() {
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
-            }
-        }
+        self.evaluate_instructions(function.name(), function.instructions(), &mut registers, trace.as_ref())?;
         lap!(timer, "Evaluate the instructions");
         web_sys::console::time_end_with_label("[evaluate] Store the inputs");
         web_sys::console::log_1(&"[evaluate] Evaluate the instructions".into());
@@ -195,6 +195,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     }
                     // If the operand is the caller, retrieve the caller from the registers.
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
+                    // If the operand is the block height or block timestamp, throw an error.
+                    Operand::BlockHeight | Operand::BlockTimestamp => {
+                        bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -226,3 +230,59 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         )
     }
 }
+
+impl<N: Network> Stack<N> {
+    /// Evaluates the given instructions, recording each one into the trace if it is given.
+    fn evaluate_instructions<A: circuit::Aleo<Network = N>>(
+        &self,
+        resource: &Identifier<N>,
+        instructions: &[Instruction<N>],
+        registers: &mut Registers<N, A>,
+        trace: Option<&Trace<N>>,
+    ) -> Result<()> {
+        // If no trace is being recorded, evaluate the instructions directly.
+        let trace = match trace {
+            Some(trace) => trace,
+            None => {
+                for instruction in instructions {
+                    // If the evaluation fails, bail and return the error.
+                    if let Err(error) = instruction.evaluate(self, registers) {
+                        bail!("Failed to evaluate instruction ({instruction}): {error}");
+                    }
+                }
+                return Ok(());
+            }
+        };
+
+        trace.enter();
+        let result = instructions.iter().try_for_each(|instruction| {
+            // Resolve the operands before the instruction runs.
+            let inputs = instruction
+                .operands()
+                .iter()
+                .map(|operand| (operand.clone(), registers.load(self, operand).ok()))
+                .collect();
+            let index = trace.start(*self.program.id(), *resource, instruction, inputs);
+
+            // If the evaluation fails, record and return the error.
+            if let Err(error) = instruction.evaluate(self, registers) {
+                trace.finish(index, Vec::new(), Some(error.to_string()));
+                bail!("Failed to evaluate instruction ({instruction}): {error}");
+            }
+
+            // Record the values stored into the destination registers.
+            let outputs = instruction
+                .destinations()
+                .into_iter()
+                .filter_map(|register| {
+                    let value = registers.load(self, &Operand::Register(register.clone())).ok()?;
+                    Some((register, value))
+                })
+                .collect();
+            trace.finish(index, outputs, None);
+            Ok(())
+        });
+        trace.exit();
+        result
+    }
+}
@@ -104,6 +104,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::Caller => Ok(circuit::Value::Plaintext(circuit::Plaintext::from(
                         circuit::Literal::Address(registers.caller_circuit()?),
                     ))),
+                    // If the operand is the block height or block timestamp, throw an error.
+                    Operand::BlockHeight | Operand::BlockTimestamp => {
+                        bail!("Forbidden operation: Cannot use '{}' outside of 'finalize'", output.operand())
+                    }
                 }
             })
             .collect();
@@ -247,9 +251,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
+        // Determine if the circuit cost of each instruction should be recorded.
+        let is_profiling = registers.call_stack().is_profiling();
+        // Initialize a vector for the circuit cost of each instruction.
+        let mut instruction_metrics = Vec::new();
         
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -258,8 +266,35 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 }
             }
 
-            // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            // If the call stack is not profiling, then execute the instruction.
+            if !is_profiling {
+                instruction.execute(self, &mut registers)?;
+            }
+            // Otherwise, record the circuit cost of the instruction.
+            else {
+                // Sample the circuit counters before the instruction.
+                let (num_public_before, num_private_before, num_constraints_before, num_nonzeros_before) =
+                    (A::num_public(), A::num_private(), A::num_constraints(), A::num_nonzeros());
+
+                // Execute the instruction.
+                instruction.execute(self, &mut registers)?;
+
+                // Record the circuit cost of the instruction.
+                let num_nonzeros = A::num_nonzeros();
+                instruction_metrics.push(InstructionMetrics {
+                    index,
+                    instruction: instruction.to_string(),
+                    closure: None,
+                    num_constraints: A::num_constraints().saturating_sub(num_constraints_before),
+                    num_public: A::num_public().saturating_sub(num_public_before),
+                    num_private: A::num_private().saturating_sub(num_private_before),
+                    num_nonzeros: (
+                        num_nonzeros.0.saturating_sub(num_nonzeros_before.0),
+                        num_nonzeros.1.saturating_sub(num_nonzeros_before.1),
+                        num_nonzeros.2.saturating_sub(num_nonzeros_before.2),
+                    ),
+                });
+            }
 
             // If the instruction was a function call, then set the tracker to `true`.
             if let Instruction::Call(call) = instruction {
@@ -267,6 +302,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 if call.is_function_call(self)? {
                     contains_function_call = true;
                 }
+                // Otherwise, attribute the circuit cost to the called closure.
+                else if let Some(metrics) = instruction_metrics.last_mut() {
+                    metrics.closure = Some(match call.operator() {
+                        CallOperator::Locator(locator) => *locator.resource(),
+                        CallOperator::Resource(resource) => *resource,
+                    });
+                }
             }
         }
         web_sys::console::time_end_with_label(logname);
@@ -297,6 +339,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::Caller => Ok(circuit::Value::Plaintext(circuit::Plaintext::from(
                         circuit::Literal::Address(registers.caller_circuit()?),
                     ))),
+                    // If the operand is the block height or block timestamp, throw an error.
+                    Operand::BlockHeight | Operand::BlockTimestamp => {
+                        bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -382,6 +428,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     match value {
                         circuit::Value::Plaintext(circuit::Plaintext::Literal(..)) => (),
                         circuit::Value::Plaintext(circuit::Plaintext::Struct(..)) => (),
+                        circuit::Value::Plaintext(circuit::Plaintext::Array(..)) => (),
                         circuit::Value::Record(..) => {
                             bail!(
                                 "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -470,7 +517,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             lap!(timer, "Save the circuit assignment");
         }
         // If the circuit is in `Execute` mode, then execute the circuit into a transition.
-        else if let CallStack::Execute(_, ref execution, ref inclusion, ref metrics) = registers.call_stack() {
+        else if let CallStack::Execute(_, ref execution, ref inclusion, ref metrics, _) = registers.call_stack() {
             registers.ensure_console_and_circuit_registers_match()?;
             let logname = "[execute] proving_key.prove";
             web_sys::console::log_1(&logname.into());
@@ -495,6 +542,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             inclusion.write().insert_transition(console_request.input_ids(), &transition)?;
             // Add the transition to the execution.
             execution.write().push(transition);
+            // Record the edition of the program that the transition ran against.
+            execution.write().insert_edition(*self.program_id(), self.edition);
             
             // Add the metrics.
             metrics.write().push(CallMetrics {
@@ -504,6 +553,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                instructions: instruction_metrics,
             });
             web_sys::console::time_end_with_label(logname);
         }
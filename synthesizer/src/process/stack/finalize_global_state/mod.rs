@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+
+/// The global state that is accessible in `finalize`, corresponding to the block being finalized.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FinalizeGlobalState {
+    /// The block height.
+    block_height: u32,
+    /// The block timestamp.
+    block_timestamp: i64,
+    /// The random seed for the block, derived from the block-level data.
+    random_seed: [u8; 32],
+}
+
+impl FinalizeGlobalState {
+    /// Initializes a new global state, given the block height, block timestamp, previous block hash,
+    /// and the IDs of every transition in the block, in block order.
+    ///
+    /// The random seed is derived by hashing the block height, the previous block hash, and the transition IDs.
+    /// As the seed depends on every transition in the block, it is only fixed once the transaction is included,
+    /// so a transaction author cannot predict it, or grind their transition ID for a favorable outcome.
+    /// The block timestamp is chosen by the block producer, so it is excluded to prevent grinding the seed.
+    /// Note: The block producer chooses which transactions to include, and so may still influence the seed.
+    #[inline]
+    pub fn new<'a, N: Network>(
+        block_height: u32,
+        block_timestamp: i64,
+        previous_block_hash: N::BlockHash,
+        transition_ids: impl IntoIterator<Item = &'a N::TransitionID>,
+    ) -> Result<Self> {
+        // Hash the transition IDs.
+        let transition_ids = transition_ids.into_iter().map(|transition_id| **transition_id).collect::<Vec<_>>();
+        let transitions_digest: Field<N> = N::hash_psd2(&transition_ids)?;
+        // Construct the preimage for the random seed.
+        let preimage = block_height
+            .to_bits_le()
+            .into_iter()
+            .chain((*previous_block_hash).to_bits_le())
+            .chain(transitions_digest.to_bits_le())
+            .collect::<Vec<_>>();
+        // Hash the preimage to derive the random seed.
+        let random_seed = N::hash_bhp1024(&preimage)?.to_bytes_le()?;
+        // Ensure the random seed is 32 bytes.
+        let random_seed = match random_seed.try_into() {
+            Ok(random_seed) => random_seed,
+            Err(_) => bail!("Failed to derive the random seed for the finalize global state"),
+        };
+        Ok(Self { block_height, block_timestamp, random_seed })
+    }
+
+    /// Initializes a new global state from the given block height, block timestamp, and random seed.
+    #[inline]
+    pub const fn from(block_height: u32, block_timestamp: i64, random_seed: [u8; 32]) -> Self {
+        Self { block_height, block_timestamp, random_seed }
+    }
+
+    /// Returns the block height.
+    #[inline]
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the block timestamp.
+    #[inline]
+    pub const fn block_timestamp(&self) -> i64 {
+        self.block_timestamp
+    }
+
+    /// Returns the random seed.
+    #[inline]
+    pub const fn random_seed(&self) -> &[u8; 32] {
+        &self.random_seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, types::Field};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_random_seed() {
+        let rng = &mut TestRng::default();
+
+        // Sample a previous block hash and the transition IDs.
+        let previous_block_hash = Field::<CurrentNetwork>::rand(rng).into();
+        let transition_ids: Vec<<CurrentNetwork as Network>::TransitionID> =
+            (0..3).map(|_| Field::<CurrentNetwork>::rand(rng).into()).collect();
+        let state = FinalizeGlobalState::new::<CurrentNetwork>(1, 0, previous_block_hash, &transition_ids).unwrap();
+
+        // Ensure the random seed does not depend on the block timestamp.
+        let candidate = FinalizeGlobalState::new::<CurrentNetwork>(1, 1, previous_block_hash, &transition_ids).unwrap();
+        assert_eq!(candidate.random_seed(), state.random_seed());
+        assert_eq!(candidate.block_timestamp(), 1);
+
+        // Ensure the random seed changes with the block height.
+        let candidate = FinalizeGlobalState::new::<CurrentNetwork>(2, 0, previous_block_hash, &transition_ids).unwrap();
+        assert_ne!(candidate.random_seed(), state.random_seed());
+
+        // Ensure the random seed changes with the transitions in the block.
+        let candidate =
+            FinalizeGlobalState::new::<CurrentNetwork>(1, 0, previous_block_hash, &transition_ids[..2]).unwrap();
+        assert_ne!(candidate.random_seed(), state.random_seed());
+        let reordered = [transition_ids[1], transition_ids[0], transition_ids[2]];
+        let candidate = FinalizeGlobalState::new::<CurrentNetwork>(1, 0, previous_block_hash, &reordered).unwrap();
+        assert_ne!(candidate.random_seed(), state.random_seed());
+        let candidate = FinalizeGlobalState::new::<CurrentNetwork>(1, 0, previous_block_hash, &[]).unwrap();
+        assert_ne!(candidate.random_seed(), state.random_seed());
+
+        // Ensure the random seed changes with the previous block hash.
+        let previous_block_hash = Field::<CurrentNetwork>::rand(rng).into();
+        let candidate = FinalizeGlobalState::new::<CurrentNetwork>(1, 0, previous_block_hash, &transition_ids).unwrap();
+        assert_ne!(candidate.random_seed(), state.random_seed());
+    }
+}
@@ -34,6 +34,14 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             }
             // If the operand is the caller, load the value of the caller.
             Operand::Caller => bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'"),
+            // If the operand is the block height, load the block height.
+            Operand::BlockHeight => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
+            }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
         };
 
         // Retrieve the plaintext value.
@@ -44,8 +52,8 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
         let plaintext_value = match register {
             // If the register is a locator, then return the plaintext value.
             Register::Locator(..) => plaintext_value.clone(),
-            // If the register is a register member, then load the specific plaintext value.
-            Register::Member(_, ref path) => plaintext_value.find(path)?,
+            // If the register is a register access, then load the specific plaintext value.
+            Register::Access(_, ref path) => plaintext_value.find(path)?,
         };
 
         // Retrieve the type of the register.
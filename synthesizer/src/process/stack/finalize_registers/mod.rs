@@ -16,18 +16,23 @@ mod load;
 mod store;
 
 use crate::{
-    process::{FinalizeTypes, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    process::{FinalizeGlobalState, FinalizeTypes, RegistersLoad, RegistersStore, StackMatches, StackProgram},
     program::Operand,
 };
 use console::{
     network::prelude::*,
     program::{Literal, Plaintext, Register, Value},
+    types::{I64, U32},
 };
 
 use indexmap::IndexMap;
 
 #[derive(Clone)]
 pub struct FinalizeRegisters<N: Network> {
+    /// The global state for the finalize scope.
+    state: FinalizeGlobalState,
+    /// The transition ID.
+    transition_id: N::TransitionID,
     /// The mapping of all registers to their defined types.
     finalize_types: FinalizeTypes<N>,
     /// The mapping of assigned registers to their values.
@@ -35,9 +40,21 @@ pub struct FinalizeRegisters<N: Network> {
 }
 
 impl<N: Network> FinalizeRegisters<N> {
-    /// Initializes a new set of registers, given the finalize types.
+    /// Initializes a new set of registers, given the global state, transition ID, and finalize types.
     #[inline]
-    pub fn new(finalize_types: FinalizeTypes<N>) -> Self {
-        Self { finalize_types, registers: IndexMap::new() }
+    pub fn new(state: FinalizeGlobalState, transition_id: N::TransitionID, finalize_types: FinalizeTypes<N>) -> Self {
+        Self { state, transition_id, finalize_types, registers: IndexMap::new() }
+    }
+
+    /// Returns the global state for the finalize scope.
+    #[inline]
+    pub const fn state(&self) -> &FinalizeGlobalState {
+        &self.state
+    }
+
+    /// Returns the transition ID for the finalize scope.
+    #[inline]
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
     }
 }
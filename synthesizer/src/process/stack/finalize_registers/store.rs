@@ -59,7 +59,7 @@ impl<N: Network> RegistersStore<N> for FinalizeRegisters<N> {
                 }
             }
             // Ensure the register is not a register member.
-            Register::Member(..) => bail!("Cannot store to a register member: '{register}'"),
+            Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
         }
     }
 }
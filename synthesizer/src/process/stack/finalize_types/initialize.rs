@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use crate::finalize::{Get, GetOrInit, Set};
+use crate::finalize::{Branch, Contains, Get, GetOrInit, RandChaCha, Remove, Set, MAX_ADDITIONAL_SEEDS};
 
 impl<N: Network> FinalizeTypes<N> {
     /// Initializes a new instance of `FinalizeTypes` for the given finalize.
@@ -25,17 +25,60 @@ impl<N: Network> FinalizeTypes<N> {
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
         let mut finalize_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        // Attaches the location of the failing statement to an error.
+        let locate = |location: Location| move |error: Error| LocatedError::wrap(*finalize.name(), location, error);
 
         // Step 1. Check the inputs are well-formed.
-        for input in finalize.inputs() {
+        for (index, input) in finalize.inputs().iter().enumerate() {
             // Check the input register type.
-            finalize_types.check_input(stack, input.register(), input.plaintext_type())?;
+            finalize_types
+                .check_input(stack, input.register(), input.plaintext_type())
+                .map_err(locate(Location::FinalizeInput(index)))?;
         }
 
         // Step 2. Check the commands are well-formed.
-        for command in finalize.commands() {
+        // Initialize the set of registers that are assigned on every path to the current command.
+        let mut assigned: IndexSet<u64> = finalize_types.inputs.keys().copied().collect();
+        // Initialize a map of positions to the registers that are assigned on every branch to the position.
+        let mut branches: IndexMap<Identifier<N>, IndexSet<u64>> = IndexMap::new();
+        for (index, command) in finalize.commands().iter().enumerate() {
+            // If the command is a position, merge the registers assigned on each path to the position.
+            if let Command::Position(position) = command {
+                if let Some(branched) = branches.remove(position.name()) {
+                    assigned = assigned.intersection(&branched).copied().collect();
+                }
+            }
+            // Ensure the operand registers are assigned on every path to the command.
+            for operand in command.operands() {
+                if let Operand::Register(register) = operand {
+                    if !assigned.contains(&register.locator()) {
+                        let error = anyhow!(
+                            "Register '{register}' in '{}/{}' is not assigned on every path to '{command}'",
+                            stack.program_id(),
+                            finalize.name()
+                        );
+                        return Err(locate(Location::Command(index))(error));
+                    }
+                }
+            }
             // Check the command opcode, operands, and destinations.
-            finalize_types.check_command(stack, finalize.name(), command)?;
+            finalize_types.check_command(stack, finalize, command).map_err(locate(Location::Command(index)))?;
+            // Mark the destination registers as assigned.
+            assigned.extend(command.destinations().iter().map(|register| register.locator()));
+            // If the command is a branch, record the registers assigned on the path to the position.
+            let position = match command {
+                Command::BranchEq(branch_eq) => Some(branch_eq.position()),
+                Command::BranchNeq(branch_neq) => Some(branch_neq.position()),
+                _ => None,
+            };
+            if let Some(position) = position {
+                match branches.get_mut(position) {
+                    Some(branched) => branched.retain(|locator| assigned.contains(locator)),
+                    None => {
+                        branches.insert(*position, assigned.clone());
+                    }
+                }
+            }
         }
 
         Ok(finalize_types)
@@ -64,7 +107,7 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 
@@ -87,7 +130,7 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 }
@@ -110,10 +153,11 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            PlaintextType::Array(array_type) => Self::check_array_type(stack, array_type)?,
         };
 
         // Insert the input register.
-        self.add_input(register.clone(), *plaintext_type)?;
+        self.add_input(register.clone(), plaintext_type.clone())?;
 
         // Ensure the register type and the input type match.
         if *plaintext_type != self.get_type(stack, register)? {
@@ -122,19 +166,48 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given array type is well-formed.
+    #[inline]
+    fn check_array_type(stack: &(impl StackMatches<N> + StackProgram<N>), array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the number of elements does not exceed the maximum.
+        if **array_type.length() as usize > N::MAX_DATA_ENTRIES {
+            bail!("Array '{array_type}' in '{}' cannot exceed {} elements.", stack.program_id(), N::MAX_DATA_ENTRIES)
+        }
+        // Ensure the array does not exceed the maximum depth.
+        if array_type.depth() > N::MAX_DATA_DEPTH {
+            bail!("Array '{array_type}' in '{}' cannot exceed a depth of {}.", stack.program_id(), N::MAX_DATA_DEPTH)
+        }
+        // Ensure the base element type is defined in the program.
+        if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+            if !stack.program().contains_struct(struct_name) {
+                bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+            }
+        }
+        Ok(())
+    }
+
     /// Ensures the given command is well-formed.
     #[inline]
     fn check_command(
         &mut self,
         stack: &(impl StackMatches<N> + StackProgram<N>),
-        finalize_name: &Identifier<N>,
+        finalize: &Finalize<N>,
         command: &Command<N>,
     ) -> Result<()> {
+        // Retrieve the finalize name.
+        let finalize_name = finalize.name();
         match command {
             Command::Instruction(instruction) => self.check_instruction(stack, finalize_name, instruction)?,
             Command::Get(get) => self.check_get(stack, finalize_name, get)?,
             Command::GetOrInit(get_or_init) => self.check_get_or_init(stack, finalize_name, get_or_init)?,
             Command::Set(set) => self.check_set(stack, finalize_name, set)?,
+            Command::Contains(contains) => self.check_contains(stack, finalize_name, contains)?,
+            Command::Remove(remove) => self.check_remove(stack, finalize_name, remove)?,
+            Command::BranchEq(branch_eq) => self.check_branch(stack, finalize, branch_eq)?,
+            Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, branch_neq)?,
+            // Note that positions are checked when added to finalize.
+            Command::Position(_) => {}
+            Command::RandChaCha(rand_chacha) => self.check_rand_chacha(stack, finalize_name, rand_chacha)?,
         }
         Ok(())
     }
@@ -169,7 +242,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Ensure the destination register is a locator (and does not reference a member).
         ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
         // Insert the destination register.
-        self.add_destination(destination, *mapping_value_type)?;
+        self.add_destination(destination, mapping_value_type.clone())?;
         Ok(())
     }
 
@@ -217,7 +290,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Ensure the destination register is a locator (and does not reference a member).
         ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
         // Insert the destination register.
-        self.add_destination(destination, *mapping_value_type)?;
+        self.add_destination(destination, mapping_value_type.clone())?;
         Ok(())
     }
 
@@ -257,6 +330,121 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `contains` command is well-formed.
+    #[inline]
+    fn check_contains(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize_name: &Identifier<N>,
+        contains: &Contains<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in `contains` is defined in the program.
+        if !stack.program().contains_mapping(contains.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", contains.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(contains.mapping_name()).unwrap();
+        // Get the mapping key type.
+        let mapping_key_type = mapping.key().plaintext_type();
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, contains.key())?;
+        // Check that the key type in the mapping matches the key type in the instruction.
+        if *mapping_key_type != key_type {
+            bail!(
+                "Key type in `contains` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
+            )
+        }
+        // Get the destination register.
+        let destination = contains.destination().clone();
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, PlaintextType::Literal(LiteralType::Boolean))?;
+        Ok(())
+    }
+
+    /// Ensures the given `rand.chacha` command is well-formed.
+    #[inline]
+    fn check_rand_chacha(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize_name: &Identifier<N>,
+        rand_chacha: &RandChaCha<N>,
+    ) -> Result<()> {
+        // Ensure the number of additional seeds is within bounds.
+        if rand_chacha.operands().len() > MAX_ADDITIONAL_SEEDS {
+            bail!(
+                "Command '{rand_chacha}' in '{}/{finalize_name}' has more than {MAX_ADDITIONAL_SEEDS} operands.",
+                stack.program_id()
+            )
+        }
+        // Ensure the type of each additional seed is well-formed.
+        for operand in rand_chacha.operands() {
+            self.get_type_from_operand(stack, &operand)?;
+        }
+        // Get the destination register.
+        let destination = rand_chacha.destination().clone();
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, PlaintextType::Literal(rand_chacha.destination_type()))?;
+        Ok(())
+    }
+
+    /// Ensures the given `remove` command is well-formed.
+    #[inline]
+    fn check_remove(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize_name: &Identifier<N>,
+        remove: &Remove<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in `remove` is defined in the program.
+        if !stack.program().contains_mapping(remove.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", remove.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(remove.mapping_name()).unwrap();
+        // Get the mapping key type.
+        let mapping_key_type = mapping.key().plaintext_type();
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, remove.key())?;
+        // Check that the key type in the mapping matches the key type.
+        if *mapping_key_type != key_type {
+            bail!("Key type in `remove` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
+        }
+        Ok(())
+    }
+
+    /// Ensures the given `branch` command is well-formed.
+    #[inline]
+    fn check_branch<const VARIANT: u8>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize: &Finalize<N>,
+        branch: &Branch<N, VARIANT>,
+    ) -> Result<()> {
+        // Ensure the position in the `branch` is declared in finalize.
+        if !finalize.positions().contains_key(branch.position()) {
+            bail!("Position '{}' in '{}/{}' is not declared.", branch.position(), stack.program_id(), finalize.name())
+        }
+        // Retrieve the type of the first operand.
+        let first_type = self.get_type_from_operand(stack, branch.first())?;
+        // Retrieve the type of the second operand.
+        let second_type = self.get_type_from_operand(stack, branch.second())?;
+        // Check that the operand types match.
+        if first_type != second_type {
+            bail!(
+                "Command '{branch}' in '{}/{}' compares '{first_type}' with '{second_type}'.",
+                stack.program_id(),
+                finalize.name()
+            )
+        }
+        Ok(())
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -366,6 +554,12 @@ impl<N: Network> FinalizeTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the array type is well-formed.
+                        Self::check_array_type(stack, array_type)?;
+                        // Ensure the operand types match the array.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(..) => {
                         bail!("Illegal operation: Cannot cast to a record.")
                     }
@@ -435,6 +629,9 @@ impl<N: Network> FinalizeTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.keccak256",
+                    "hash.sha3_256",
+                    "hash.sha256",
                 ]
                 .contains(&opcode)
                 {
@@ -478,6 +675,18 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.keccak256" => ensure!(
+                        matches!(instruction, Instruction::HashKeccak256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha3_256" => ensure!(
+                        matches!(instruction, Instruction::HashSha3_256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha256" => ensure!(
+                        matches!(instruction, Instruction::HashSha256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -499,6 +708,20 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign(opcode) => {
+                // Ensure the instruction belongs to the defined set.
+                if !["sign.verify"].contains(&opcode) {
+                    bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
+                }
+                // Ensure the instruction is the correct one.
+                match opcode {
+                    "sign.verify" => ensure!(
+                        matches!(instruction, Instruction::SignVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+            }
         }
         Ok(())
     }
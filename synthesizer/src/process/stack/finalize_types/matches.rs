@@ -69,7 +69,7 @@ impl<N: Network> FinalizeTypes<N> {
                     let program_ref_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the program ID type matches the member type.
                     ensure!(
-                        program_ref_type == RegisterType::Plaintext(*member_type),
+                        program_ref_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{program_ref_type}' in the operand '{operand}'.",
                     )
                 }
@@ -79,12 +79,59 @@ impl<N: Network> FinalizeTypes<N> {
                     let caller_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the caller type matches the member type.
                     ensure!(
-                        caller_type == RegisterType::Plaintext(*member_type),
+                        caller_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block height type (u32) or block timestamp type (i64) matches the member type.
+                Operand::BlockHeight | Operand::BlockTimestamp => {
+                    // Retrieve the block operand type.
+                    let block_type = self.get_type_from_operand(stack, operand)?;
+                    // Ensure the block operand type matches the member type.
+                    ensure!(
+                        block_type == *member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
     }
+
+    /// Checks that the given operands matches the layout of the array. The ordering of the operands matters.
+    pub fn matches_array(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        operands: &[Operand<N>],
+        array_type: &ArrayType<N>,
+    ) -> Result<()> {
+        // Ensure the number of elements does not exceed the maximum.
+        if operands.len() > N::MAX_DATA_ENTRIES {
+            bail!("'{array_type}' cannot exceed {} elements", N::MAX_DATA_ENTRIES)
+        }
+        // Ensure the array does not exceed the maximum depth.
+        if array_type.depth() > N::MAX_DATA_DEPTH {
+            bail!("'{array_type}' cannot exceed a depth of {}", N::MAX_DATA_DEPTH)
+        }
+
+        // Ensure the number of elements match.
+        let num_elements = operands.len();
+        let expected_num_elements = **array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Ensure the operand types match the element type.
+        let element_type = array_type.element_type();
+        for (index, operand) in operands.iter().enumerate() {
+            // Retrieve the operand type.
+            let operand_type = self.get_type_from_operand(stack, operand)?;
+            // Ensure the operand type matches the element type.
+            ensure!(
+                operand_type == *element_type,
+                "Array element at index {index} expects a '{element_type}', but found '{operand_type}' in the operand '{operand}'.",
+            )
+        }
+        Ok(())
+    }
 }
@@ -16,7 +16,7 @@ mod initialize;
 mod matches;
 
 use crate::{
-    process::{StackMatches, StackProgram},
+    process::{LocatedError, Location, StackMatches, StackProgram},
     program::{
         finalize::{Command, Finalize},
         Instruction,
@@ -27,10 +27,10 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, LiteralType, PlaintextType, Register, RegisterType, Struct},
+    program::{Access, ArrayType, Identifier, LiteralType, PlaintextType, Register, RegisterType, Struct},
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct FinalizeTypes<N: Network> {
@@ -75,6 +75,8 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => PlaintextType::Literal(LiteralType::Address),
             Operand::Caller => PlaintextType::Literal(LiteralType::Address),
+            Operand::BlockHeight => PlaintextType::Literal(LiteralType::U32),
+            Operand::BlockTimestamp => PlaintextType::Literal(LiteralType::I64),
         })
     }
 
@@ -87,43 +89,59 @@ impl<N: Network> FinalizeTypes<N> {
         // Initialize a tracker for the type of the register.
         let mut plaintext_type = if self.is_input(register) {
             // Retrieve the input value type as a register type.
-            *self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+            self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?.clone()
         } else {
             // Retrieve the destination register type.
-            *self
-                .destinations
+            self.destinations
                 .get(&register.locator())
                 .ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+                .clone()
         };
 
-        // Retrieve the member path if the register is a member. Otherwise, return the type.
+        // Retrieve the access path if the register is an access. Otherwise, return the type.
         let path = match &register {
             // If the register is a locator, then output the register type.
             Register::Locator(..) => return Ok(plaintext_type),
-            // If the register is a member, then traverse the member path to output the register type.
-            Register::Member(_, path) => {
-                // Ensure the member path is valid.
-                ensure!(!path.is_empty(), "Register '{register}' references no members.");
-                // Output the member path.
+            // If the register is an access, then traverse the access path to output the register type.
+            Register::Access(_, path) => {
+                // Ensure the access path is valid.
+                ensure!(!path.is_empty(), "Register '{register}' references no accesses.");
+                // Output the access path.
                 path
             }
         };
 
-        // Traverse the member path to find the register type.
-        for path_name in path.iter() {
+        // Traverse the access path to find the register type.
+        for access in path.iter() {
             // Update the register type at each step.
-            plaintext_type = match &plaintext_type {
-                // Ensure the plaintext type is not a literal, as the register references a member.
-                PlaintextType::Literal(..) => bail!("'{register}' references a literal."),
+            plaintext_type = match (&plaintext_type, access) {
+                // Ensure the plaintext type is not a literal, as the register references an access.
+                (PlaintextType::Literal(..), _) => bail!("'{register}' references a literal."),
                 // Traverse the member path to output the register type.
-                PlaintextType::Struct(struct_name) => {
+                (PlaintextType::Struct(struct_name), Access::Member(path_name)) => {
                     // Retrieve the member type from the struct.
                     match stack.program().get_struct(struct_name)?.members().get(path_name) {
                         // Update the member type.
-                        Some(plaintext_type) => *plaintext_type,
+                        Some(plaintext_type) => plaintext_type.clone(),
                         None => bail!("'{path_name}' does not exist in struct '{struct_name}'"),
                     }
                 }
+                // Traverse the array to output the register type.
+                (PlaintextType::Array(array_type), Access::Index(index)) => {
+                    // Ensure the index is within bounds.
+                    match **index < **array_type.length() {
+                        true => array_type.element_type().clone(),
+                        false => {
+                            bail!("Index '{index}' is out of bounds for array of length '{}'", array_type.length())
+                        }
+                    }
+                }
+                (PlaintextType::Struct(..), Access::Index(..)) => {
+                    bail!("'{register}' references a struct with an index.")
+                }
+                (PlaintextType::Array(..), Access::Member(..)) => {
+                    bail!("'{register}' references an array with a member.")
+                }
             }
         }
         // Output the member type.
@@ -15,12 +15,65 @@
 use super::*;
 
 impl<N: Network> Stack<N> {
-    /// Initializes a new stack, given the process and program.
+    /// Initializes a new stack, given the process, program, and edition.
     #[inline]
-    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
+        // Construct the stack for the program and its imports.
+        let mut stack = Self::initialize_imports(process, program, edition)?;
+        // Add the program closures to the stack.
+        for closure in program.closures().values() {
+            // Add the closure to the stack.
+            stack.insert_closure(closure)?;
+        }
+        // Add the program functions to the stack.
+        for function in program.functions().values() {
+            // Add the function to the stack.
+            stack.insert_function(function)?;
+        }
+        // Return the stack.
+        Ok(stack)
+    }
+
+    /// Type-checks the given program against the process, and returns every error that is found.
+    /// Unlike `Stack::initialize`, checking continues past a closure or function that is not well-formed,
+    /// and each error is located at the statement (or else the closure or function) that caused it.
+    #[inline]
+    pub(crate) fn check(process: &Process<N>, program: &Program<N>) -> Vec<Error> {
+        let mut errors = Vec::new();
+        // Ensure the program is well-formed.
+        if let Err(error) = Self::check_program_is_well_formed(program) {
+            errors.push(error);
+        }
+        // Construct the stack for the program and its imports.
+        let mut stack = match Self::initialize_imports(process, program, N::EDITION) {
+            Ok(stack) => stack,
+            Err(error) => {
+                errors.push(error);
+                return errors;
+            }
+        };
+        // Check the program closures.
+        for closure in program.closures().values() {
+            if let Err(error) = stack.insert_closure(closure) {
+                errors.push(LocatedError::wrap(*closure.name(), Location::Resource, error));
+            }
+        }
+        // Check the program functions.
+        for function in program.functions().values() {
+            if let Err(error) = stack.insert_function(function) {
+                errors.push(LocatedError::wrap(*function.name(), Location::Resource, error));
+            }
+        }
+        errors
+    }
+
+    /// Initializes a new stack with the imports of the given program, given the process, program, and edition.
+    #[inline]
+    fn initialize_imports(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
+            edition,
             external_stacks: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
@@ -40,16 +93,6 @@ impl<N: Network> Stack<N> {
             // Add the external stack to the stack.
             stack.insert_external_stack(external_stack.clone())?;
         }
-        // Add the program closures to the stack.
-        for closure in program.closures().values() {
-            // Add the closure to the stack.
-            stack.insert_closure(closure)?;
-        }
-        // Add the program functions to the stack.
-        for function in program.functions().values() {
-            // Add the function to the stack.
-            stack.insert_function(function)?;
-        }
         // Return the stack.
         Ok(stack)
     }
@@ -73,6 +116,14 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Replaces the external stack for the given program ID, if it exists.
+    #[inline]
+    pub(crate) fn replace_external_stack(&mut self, external_stack: Stack<N>) {
+        if let Some(stack) = self.external_stacks.get_mut(external_stack.program_id()) {
+            *stack = external_stack;
+        }
+    }
+
     /// Inserts the given closure to the stack.
     #[inline]
     fn insert_closure(&mut self, closure: &Closure<N>) -> Result<()> {
@@ -149,8 +149,10 @@ impl<N: Network> Inclusion<N> {
                 if execution.inclusion_proof().is_some() {
                     bail!("Inclusion expected the inclusion proof in the execution to be 'None'")
                 }
+                // Retrieve the editions.
+                let editions = execution.editions().clone();
                 // Return the execution.
-                Execution::from(execution.into_transitions(), global_state_root, None)
+                Execution::from(execution.into_transitions(), editions, global_state_root, None)
             }
             false => {
                 // Fetch the inclusion proving key.
@@ -158,8 +160,10 @@ impl<N: Network> Inclusion<N> {
 
                 // Compute the inclusion batch proof.
                 let (global_state_root, inclusion_proof) = Self::prove_batch::<A, R>(&proving_key, assignments, rng)?;
+                // Retrieve the editions.
+                let editions = execution.editions().clone();
                 // Return the execution.
-                Execution::from(execution.into_transitions(), global_state_root, Some(inclusion_proof))
+                Execution::from(execution.into_transitions(), editions, global_state_root, Some(inclusion_proof))
             }
         }
     }
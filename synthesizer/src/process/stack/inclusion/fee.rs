@@ -141,6 +141,12 @@ impl<N: Network> Inclusion<N> {
             bail!("Inclusion expected the global state root in the fee to *not* be zero")
         }
 
+        // If the fee is paid from the public balance, ensure there is no inclusion proof.
+        if fee.is_fee_public() {
+            ensure!(fee.inclusion_proof().is_none(), "Inclusion expected the public fee to *not* contain a proof");
+            return Ok(());
+        }
+
         // Retrieve the inclusion proof.
         let inclusion_proof = match fee.inclusion_proof() {
             Some(inclusion_proof) => inclusion_proof,
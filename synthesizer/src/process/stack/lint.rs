@@ -0,0 +1,409 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    finalize::{Command, Finalize},
+    Opcode,
+};
+use console::program::Access;
+
+use indexmap::IndexSet;
+
+/// The kind of suspicious code reported by a lint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LintKind {
+    /// A register is assigned, but never read.
+    UnusedRegister,
+    /// A public or constant output is derived from a private value.
+    PublicOutputLeak,
+    /// A finalize operand is derived from a private value.
+    PrivateFinalizeInput,
+    /// A record is consumed, but no record of the same type is output.
+    RecordNotRecreated,
+    /// A closure is never called.
+    DeadClosure,
+    /// A record is cast with a constant owner.
+    ConstantRecordOwner,
+}
+
+impl LintKind {
+    /// Returns the name of the lint, e.g. `unused-register`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            LintKind::UnusedRegister => "unused-register",
+            LintKind::PublicOutputLeak => "public-output-leak",
+            LintKind::PrivateFinalizeInput => "private-finalize-input",
+            LintKind::RecordNotRecreated => "record-not-recreated",
+            LintKind::DeadClosure => "dead-closure",
+            LintKind::ConstantRecordOwner => "constant-record-owner",
+        }
+    }
+}
+
+/// A warning about suspicious, but well-typed, code in a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lint<N: Network> {
+    /// The kind of lint.
+    kind: LintKind,
+    /// The name of the function or closure.
+    resource: Identifier<N>,
+    /// The location within the function or closure.
+    location: Location,
+    /// The message.
+    message: String,
+}
+
+impl<N: Network> Lint<N> {
+    /// Returns the kind of lint.
+    pub const fn kind(&self) -> LintKind {
+        self.kind
+    }
+
+    /// Returns the name of the function or closure.
+    pub const fn resource(&self) -> &Identifier<N> {
+        &self.resource
+    }
+
+    /// Returns the location within the function or closure.
+    pub const fn location(&self) -> Location {
+        self.location
+    }
+
+    /// Returns the message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl<N: Network> Display for Lint<N> {
+    /// Prints the lint, e.g. `warning[unused-register]: 'compute' instruction 2: Register 'r5' is never used`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "warning[{}]: '{}' {}: {}", self.kind.name(), self.resource, self.location, self.message)
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns the lints for the program, for each closure and then for each function.
+    /// The lints are derived from the register types of each closure and function, and do not affect execution.
+    pub fn lint(&self) -> Result<Vec<Lint<N>>> {
+        let mut lints = Vec::new();
+
+        // Determine the closures that are reachable from a function.
+        let reachable = self.reachable_closures();
+
+        for closure in self.program.closures().values() {
+            // Ensure the closure is called.
+            if !reachable.contains(closure.name()) {
+                lints.push(Lint {
+                    kind: LintKind::DeadClosure,
+                    resource: *closure.name(),
+                    location: Location::Resource,
+                    message: format!("Closure '{}' is never called", closure.name()),
+                });
+            }
+            // Ensure every register is used.
+            let inputs = closure.inputs().iter().map(|input| input.register().clone()).collect::<Vec<_>>();
+            let outputs = closure.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+            lints.extend(lint_unused_registers(closure.name(), &inputs, closure.instructions(), &outputs));
+        }
+
+        for function in self.program.functions().values() {
+            lints.extend(self.lint_function(function)?);
+        }
+        Ok(lints)
+    }
+
+    /// Returns the lints for the given function.
+    fn lint_function(&self, function: &Function<N>) -> Result<Vec<Lint<N>>> {
+        let name = *function.name();
+        // Retrieve the register types.
+        let register_types = self.get_register_types(&name)?;
+
+        // Ensure every register is used.
+        let inputs = function.inputs().iter().map(|input| input.register().clone()).collect::<Vec<_>>();
+        let mut outputs = function.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+        if let Some(command) = function.finalize_command() {
+            outputs.extend(command.operands().iter().cloned());
+        }
+        let mut lints = lint_unused_registers(&name, &inputs, function.instructions(), &outputs);
+
+        // Determine the registers holding private and constant values.
+        let mut private = IndexSet::new();
+        let mut constant = IndexSet::new();
+        for input in function.inputs() {
+            match input.value_type() {
+                ValueType::Constant(..) => constant.insert(input.register().locator()),
+                ValueType::Public(..) => false,
+                // Records are tracked by their entries.
+                ValueType::Private(..) | ValueType::Record(..) | ValueType::ExternalRecord(..) => {
+                    private.insert(input.register().locator())
+                }
+            };
+        }
+
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // Ensure a record is not cast with a constant owner.
+            if let Instruction::Cast(cast) = instruction {
+                if let (RegisterType::Record(record_name), Some(owner)) =
+                    (cast.register_type(), cast.operands().first())
+                {
+                    let is_constant = match owner {
+                        Operand::Literal(..) | Operand::ProgramID(..) => true,
+                        Operand::Register(register) => constant.contains(&register.locator()),
+                        _ => false,
+                    };
+                    if is_constant {
+                        lints.push(Lint {
+                            kind: LintKind::ConstantRecordOwner,
+                            resource: name,
+                            location: Location::Instruction(index),
+                            message: format!("Record '{record_name}' is owned by the constant address '{owner}'"),
+                        });
+                    }
+                }
+            }
+
+            // The destinations are private if any operand is private, unless the instruction is a commitment.
+            let is_private = !matches!(instruction.opcode(), Opcode::Commit(..))
+                && instruction
+                    .operands()
+                    .iter()
+                    .map(|operand| self.is_private_operand(register_types, &private, operand))
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter()
+                    .any(|is_private| is_private);
+            // The destinations are constant if every operand is constant.
+            let is_constant = instruction.operands().iter().all(|operand| match operand {
+                Operand::Literal(..) | Operand::ProgramID(..) => true,
+                Operand::Register(register) => constant.contains(&register.locator()),
+                _ => false,
+            });
+            for register in instruction.destinations() {
+                if is_private {
+                    private.insert(register.locator());
+                }
+                if is_constant {
+                    constant.insert(register.locator());
+                }
+            }
+        }
+
+        // Ensure no public or constant output is derived from a private value.
+        for (index, output) in function.outputs().iter().enumerate() {
+            if let ValueType::Public(..) | ValueType::Constant(..) = output.value_type() {
+                if self.is_private_operand(register_types, &private, output.operand())? {
+                    lints.push(Lint {
+                        kind: LintKind::PublicOutputLeak,
+                        resource: name,
+                        location: Location::Output(index),
+                        message: format!(
+                            "Output '{}' is declared as '{}', but is derived from a private value",
+                            output.operand(),
+                            output.value_type()
+                        ),
+                    });
+                }
+            }
+        }
+
+        if let Some((command, finalize)) = function.finalize() {
+            // Ensure no finalize operand is derived from a private value.
+            for operand in command.operands() {
+                if self.is_private_operand(register_types, &private, operand)? {
+                    lints.push(Lint {
+                        kind: LintKind::PrivateFinalizeInput,
+                        resource: name,
+                        location: Location::FinalizeCommand,
+                        message: format!("Finalize operand '{operand}' is derived from a private value"),
+                    });
+                }
+            }
+            // Ensure every finalize register is used.
+            lints.extend(lint_unused_finalize_registers(&name, finalize));
+        }
+
+        // Ensure every consumed record is re-created.
+        for (index, input) in function.inputs().iter().enumerate() {
+            if let RegisterType::Record(record_name) = register_types.get_type(self, input.register())? {
+                let is_recreated = function
+                    .outputs()
+                    .iter()
+                    .any(|output| matches!(output.value_type(), ValueType::Record(output_name) if *output_name == record_name));
+                if !is_recreated {
+                    lints.push(Lint {
+                        kind: LintKind::RecordNotRecreated,
+                        resource: name,
+                        location: Location::Input(index),
+                        message: format!("Record '{record_name}' is consumed, but no '{record_name}' record is output"),
+                    });
+                }
+            }
+        }
+
+        Ok(lints)
+    }
+
+    /// Returns `true` if the given operand is derived from a private value.
+    /// Record entries are private if they are declared as private in the record type.
+    fn is_private_operand(
+        &self,
+        register_types: &RegisterTypes<N>,
+        private: &IndexSet<u64>,
+        operand: &Operand<N>,
+    ) -> Result<bool> {
+        let register = match operand {
+            Operand::Register(register) => register,
+            _ => return Ok(false),
+        };
+        if !private.contains(&register.locator()) {
+            return Ok(false);
+        }
+        // Retrieve the record type, if the register is an input record.
+        let record_type = match register_types.get_type(self, &Register::Locator(register.locator()))? {
+            RegisterType::Record(record_name) if register_types.is_input(register) => {
+                self.program.get_record(&record_name)?
+            }
+            RegisterType::ExternalRecord(locator) if register_types.is_input(register) => {
+                self.get_external_record(&locator)?
+            }
+            _ => return Ok(true),
+        };
+        // Determine the visibility of the accessed entry.
+        match register {
+            Register::Access(_, path) => match path.first() {
+                Some(Access::Member(member)) if member == &Identifier::from_str("owner")? => {
+                    Ok(record_type.owner().is_private())
+                }
+                Some(Access::Member(member)) => {
+                    Ok(matches!(record_type.entries().get(member), Some(EntryType::Private(..)) | None))
+                }
+                _ => Ok(true),
+            },
+            Register::Locator(..) => Ok(true),
+        }
+    }
+
+    /// Returns the names of the closures that are called, directly or transitively, by a function.
+    fn reachable_closures(&self) -> IndexSet<Identifier<N>> {
+        // Returns the local closures called by the given instructions.
+        let calls = |instructions: &[Instruction<N>]| {
+            instructions
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    Instruction::Call(call) => match call.operator() {
+                        CallOperator::Resource(resource) if self.program.contains_closure(resource) => Some(*resource),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut reachable = IndexSet::new();
+        let mut queue =
+            self.program.functions().values().flat_map(|function| calls(function.instructions())).collect::<Vec<_>>();
+        while let Some(name) = queue.pop() {
+            if reachable.insert(name) {
+                if let Ok(closure) = self.program.get_closure(&name) {
+                    queue.extend(calls(closure.instructions()));
+                }
+            }
+        }
+        reachable
+    }
+}
+
+/// Returns a lint for each input or destination register that is never read.
+fn lint_unused_registers<N: Network>(
+    name: &Identifier<N>,
+    inputs: &[Register<N>],
+    instructions: &[Instruction<N>],
+    outputs: &[Operand<N>],
+) -> Vec<Lint<N>> {
+    // Determine the registers that are read.
+    let used = instructions
+        .iter()
+        .flat_map(|instruction| instruction.operands().iter())
+        .chain(outputs.iter())
+        .filter_map(|operand| match operand {
+            Operand::Register(register) => Some(register.locator()),
+            _ => None,
+        })
+        .collect::<IndexSet<_>>();
+
+    let mut lints = Vec::new();
+    for (index, register) in inputs.iter().enumerate() {
+        if !used.contains(&register.locator()) {
+            lints.push(Lint {
+                kind: LintKind::UnusedRegister,
+                resource: *name,
+                location: Location::Input(index),
+                message: format!("Input register '{register}' is never used"),
+            });
+        }
+    }
+    for (index, instruction) in instructions.iter().enumerate() {
+        for register in instruction.destinations() {
+            if !used.contains(&register.locator()) {
+                lints.push(Lint {
+                    kind: LintKind::UnusedRegister,
+                    resource: *name,
+                    location: Location::Instruction(index),
+                    message: format!("Register '{register}' is never used"),
+                });
+            }
+        }
+    }
+    lints
+}
+
+/// Returns a lint for each finalize input or destination register that is never read.
+fn lint_unused_finalize_registers<N: Network>(name: &Identifier<N>, finalize: &Finalize<N>) -> Vec<Lint<N>> {
+    // Determine the registers that are read.
+    let used = finalize
+        .commands()
+        .iter()
+        .flat_map(Command::operands)
+        .filter_map(|operand| match operand {
+            Operand::Register(register) => Some(register.locator()),
+            _ => None,
+        })
+        .collect::<IndexSet<_>>();
+
+    let mut lints = Vec::new();
+    for (index, input) in finalize.inputs().iter().enumerate() {
+        if !used.contains(&input.register().locator()) {
+            lints.push(Lint {
+                kind: LintKind::UnusedRegister,
+                resource: *name,
+                location: Location::FinalizeInput(index),
+                message: format!("Finalize input register '{}' is never used", input.register()),
+            });
+        }
+    }
+    for (index, command) in finalize.commands().iter().enumerate() {
+        for register in command.destinations() {
+            if !used.contains(&register.locator()) {
+                lints.push(Lint {
+                    kind: LintKind::UnusedRegister,
+                    resource: *name,
+                    location: Location::Command(index),
+                    message: format!("Register '{register}' is never used"),
+                });
+            }
+        }
+    }
+    lints
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::Identifier};
+
+/// The location of a statement within a function or closure.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Location {
+    /// The function or closure itself.
+    Resource,
+    /// The input statement at the given index.
+    Input(usize),
+    /// The instruction at the given index.
+    Instruction(usize),
+    /// The output statement at the given index.
+    Output(usize),
+    /// The `finalize` command of the function.
+    FinalizeCommand,
+    /// The finalize input statement at the given index.
+    FinalizeInput(usize),
+    /// The finalize command at the given index.
+    Command(usize),
+}
+
+impl Display for Location {
+    /// Prints the location, e.g. `instruction 2`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Location::Resource => write!(f, "definition"),
+            Location::Input(index) => write!(f, "input {index}"),
+            Location::Instruction(index) => write!(f, "instruction {index}"),
+            Location::Output(index) => write!(f, "output {index}"),
+            Location::FinalizeCommand => write!(f, "finalize statement"),
+            Location::FinalizeInput(index) => write!(f, "finalize input {index}"),
+            Location::Command(index) => write!(f, "finalize command {index}"),
+        }
+    }
+}
+
+/// An error in the statement at the given location of a function or closure.
+/// The error prints as the underlying error, so that wrapping an error does not change its message.
+#[derive(Debug)]
+pub struct LocatedError<N: Network> {
+    /// The name of the function or closure.
+    resource: Identifier<N>,
+    /// The location within the function or closure.
+    location: Location,
+    /// The underlying error.
+    error: Error,
+}
+
+impl<N: Network> LocatedError<N> {
+    /// Wraps the given error with the location of its statement, unless the error is already located.
+    pub fn wrap(resource: Identifier<N>, location: Location, error: Error) -> Error {
+        match error.is::<Self>() {
+            true => error,
+            false => Error::new(Self { resource, location, error }),
+        }
+    }
+
+    /// Returns the name of the function or closure.
+    pub const fn resource(&self) -> &Identifier<N> {
+        &self.resource
+    }
+
+    /// Returns the location within the function or closure.
+    pub const fn location(&self) -> Location {
+        self.location
+    }
+
+    /// Returns the underlying error.
+    pub const fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl<N: Network> Display for LocatedError<N> {
+    /// Prints the underlying error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<N: Network> std::error::Error for LocatedError<N> {
+    /// Returns the source of the underlying error.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
@@ -15,6 +15,12 @@
 mod authorization;
 pub use authorization::*;
 
+mod estimate;
+pub use estimate::*;
+
+mod finalize_global_state;
+pub use finalize_global_state::*;
+
 mod finalize_registers;
 pub use finalize_registers::*;
 
@@ -24,6 +30,12 @@ pub use finalize_types::*;
 mod inclusion;
 pub use inclusion::*;
 
+mod lint;
+pub use lint::*;
+
+mod location;
+pub use location::*;
+
 mod query;
 pub use query::*;
 
@@ -33,6 +45,9 @@ pub use register_types::*;
 mod registers;
 pub use registers::*;
 
+mod trace;
+pub use trace::*;
+
 mod traits;
 pub use traits::*;
 
@@ -85,7 +100,9 @@ use std::sync::Arc;
 
 pub type Assignments<N> = Arc<RwLock<Vec<circuit::Assignment<<N as Environment>::Field>>>>;
 
-#[derive(Copy, Clone, Debug)]
+/// Note: `CallMetrics` is not `Copy`, as it owns the per-instruction profile. This is a breaking change
+/// for callers that relied on copying it; clone it instead.
+#[derive(Clone, Debug)]
 pub struct CallMetrics<N: Network> {
     pub program_id: ProgramID<N>,
     pub function_name: Identifier<N>,
@@ -93,6 +110,26 @@ pub struct CallMetrics<N: Network> {
     pub num_request_constraints: u64,
     pub num_function_constraints: u64,
     pub num_response_constraints: u64,
+    /// The circuit cost of each instruction in the function, in program order.
+    /// This is only populated if the call stack is profiling, and is empty otherwise.
+    pub instructions: Vec<InstructionMetrics<N>>,
+}
+
+/// The circuit cost of a single instruction, sampled from the circuit environment around its execution.
+/// For a `call` to a closure, this includes every instruction in the closure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionMetrics<N: Network> {
+    /// The index of the instruction in the function.
+    pub index: usize,
+    /// The instruction, as a string.
+    pub instruction: String,
+    /// The closure called by the instruction, if any.
+    pub closure: Option<Identifier<N>>,
+    pub num_constraints: u64,
+    pub num_public: u64,
+    pub num_private: u64,
+    /// The number of non-zero entries in the `A`, `B`, and `C` matrices.
+    pub num_nonzeros: (u64, u64, u64),
 }
 
 #[derive(Clone)]
@@ -100,14 +137,19 @@ pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
-    Evaluate(Authorization<N>),
-    Execute(Authorization<N>, Arc<RwLock<Execution<N>>>, Arc<RwLock<Inclusion<N>>>, Arc<RwLock<Vec<CallMetrics<N>>>>),
+    Evaluate(Authorization<N>, Option<Trace<N>>),
+    Execute(Authorization<N>, Arc<RwLock<Execution<N>>>, Arc<RwLock<Inclusion<N>>>, Arc<RwLock<Vec<CallMetrics<N>>>>, bool),
 }
 
 impl<N: Network> CallStack<N> {
     /// Initializes a call stack as `Self::Evaluate`.
     pub fn evaluate(authorization: Authorization<N>) -> Result<Self> {
-        Ok(CallStack::Evaluate(authorization))
+        Ok(CallStack::Evaluate(authorization, None))
+    }
+
+    /// Initializes a call stack as `Self::Evaluate`, recording each evaluated instruction into the given trace.
+    pub fn evaluate_with_trace(authorization: Authorization<N>, trace: Trace<N>) -> Result<Self> {
+        Ok(CallStack::Evaluate(authorization, Some(trace)))
     }
 
     /// Initializes a call stack as `Self::Execute`.
@@ -117,7 +159,17 @@ impl<N: Network> CallStack<N> {
         inclusion: Arc<RwLock<Inclusion<N>>>,
         metrics: Arc<RwLock<Vec<CallMetrics<N>>>>,
     ) -> Result<Self> {
-        Ok(CallStack::Execute(authorization, execution, inclusion, metrics))
+        Ok(CallStack::Execute(authorization, execution, inclusion, metrics, false))
+    }
+
+    /// Initializes a call stack as `Self::Execute`, recording the circuit cost of each instruction into the metrics.
+    pub fn execute_with_profile(
+        authorization: Authorization<N>,
+        execution: Arc<RwLock<Execution<N>>>,
+        inclusion: Arc<RwLock<Inclusion<N>>>,
+        metrics: Arc<RwLock<Vec<CallMetrics<N>>>>,
+    ) -> Result<Self> {
+        Ok(CallStack::Execute(authorization, execution, inclusion, metrics, true))
     }
 }
 
@@ -136,23 +188,37 @@ impl<N: Network> CallStack<N> {
                 *private_key,
                 Arc::new(RwLock::new(assignments.read().clone())),
             ),
-            CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
-            CallStack::Execute(authorization, execution, inclusion, metrics) => CallStack::Execute(
+            CallStack::Evaluate(authorization, trace) => CallStack::Evaluate(authorization.replicate(), trace.clone()),
+            CallStack::Execute(authorization, execution, inclusion, metrics, profile) => CallStack::Execute(
                 authorization.replicate(),
                 Arc::new(RwLock::new(execution.read().clone())),
                 Arc::new(RwLock::new(inclusion.read().clone())),
                 Arc::new(RwLock::new(metrics.read().clone())),
+                *profile,
             ),
         }
     }
 
+    /// Returns the trace, if the call stack is recording one.
+    pub fn trace(&self) -> Option<&Trace<N>> {
+        match self {
+            CallStack::Evaluate(_, trace) => trace.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the call stack is recording the circuit cost of each instruction.
+    pub const fn is_profiling(&self) -> bool {
+        matches!(self, CallStack::Execute(.., true))
+    }
+
     /// Pushes the request to the stack.
     pub fn push(&mut self, request: Request<N>) -> Result<()> {
         match self {
             CallStack::Authorize(requests, ..) => requests.push(request),
             CallStack::Synthesize(requests, ..) => requests.push(request),
             CallStack::CheckDeployment(requests, ..) => requests.push(request),
-            CallStack::Evaluate(authorization) => authorization.push(request),
+            CallStack::Evaluate(authorization, ..) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
         }
         Ok(())
@@ -166,7 +232,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::CheckDeployment(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.next(),
+            CallStack::Evaluate(authorization, ..) => authorization.next(),
             CallStack::Execute(authorization, ..) => authorization.next(),
         }
     }
@@ -179,7 +245,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::CheckDeployment(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.peek_next(),
+            CallStack::Evaluate(authorization, ..) => authorization.peek_next(),
             CallStack::Execute(authorization, ..) => authorization.peek_next(),
         }
     }
@@ -189,6 +255,8 @@ impl<N: Network> CallStack<N> {
 pub struct Stack<N: Network> {
     /// The program (record types, structs, functions).
     program: Program<N>,
+    /// The edition of the program.
+    edition: u16,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Stack<N>>,
     /// The mapping of closure and function names to their register types.
@@ -199,8 +267,8 @@ pub struct Stack<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of function name to proving key.
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
-    /// The mapping of function name to verifying key.
-    verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The mapping of `(function name, edition)` to verifying key.
+    verifying_keys: Arc<RwLock<IndexMap<(Identifier<N>, u16), VerifyingKey<N>>>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -211,6 +279,44 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the program is well-formed.
+        Self::check_program_is_well_formed(program)?;
+        // Return the stack.
+        Stack::initialize(process, program, N::EDITION)
+    }
+
+    /// Initializes a stack for a new edition of an existing program, given the process and the program.
+    /// The verifying keys of all prior editions are carried over, so older executions remain verifiable.
+    #[inline]
+    pub fn new_edition(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::from_str("credits.aleo")?, "Program 'credits.aleo' cannot be upgraded");
+        // Retrieve the stack of the existing program.
+        let previous = process.get_stack(program_id)?;
+        // Ensure the edition immediately follows the existing edition.
+        ensure!(
+            previous.edition().checked_add(1) == Some(edition),
+            "Program '{program_id}' must be upgraded to edition {}, found {edition}",
+            previous.edition().saturating_add(1)
+        );
+        // Ensure the program is well-formed.
+        Self::check_program_is_well_formed(program)?;
+
+        // Initialize the stack.
+        let mut stack = Stack::initialize(process, program, edition)?;
+        // Carry over the verifying keys of the prior editions.
+        stack.verifying_keys = Arc::new(RwLock::new(previous.verifying_keys.read().clone()));
+        // Return the stack.
+        Ok(stack)
+    }
+
+    /// Ensures the given program is well-formed.
+    #[inline]
+    fn check_program_is_well_formed(program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program network-level domain (NLD) is correct.
         ensure!(program_id.is_aleo(), "Program '{program_id}' has an incorrect network-level domain (NLD)");
         // Ensure the program contains functions.
@@ -226,8 +332,7 @@ impl<N: Network> Stack<N> {
         // Ensure the program deserializes from a string correctly.
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
 
-        // Return the stack.
-        Stack::initialize(process, program)
+        Ok(())
     }
 }
 
@@ -330,6 +435,12 @@ impl<N: Network> StackProgram<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns the edition of the program.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
@@ -339,7 +450,7 @@ impl<N: Network> Stack<N> {
     /// Returns `true` if the verifying key for the given function name exists.
     #[inline]
     pub fn contains_verifying_key(&self, function_name: &Identifier<N>) -> bool {
-        self.verifying_keys.read().contains_key(function_name)
+        self.verifying_keys.read().contains_key(&(*function_name, self.edition))
     }
 
     /// Returns the proving key for the given function name.
@@ -355,10 +466,20 @@ impl<N: Network> Stack<N> {
     /// Returns the verifying key for the given function name.
     #[inline]
     pub fn get_verifying_key(&self, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        self.get_verifying_key_for_edition(function_name, self.edition)
+    }
+
+    /// Returns the verifying key for the given function name and edition.
+    #[inline]
+    pub fn get_verifying_key_for_edition(
+        &self,
+        function_name: &Identifier<N>,
+        edition: u16,
+    ) -> Result<VerifyingKey<N>> {
         // Return the verifying key, if it exists.
-        match self.verifying_keys.read().get(function_name) {
+        match self.verifying_keys.read().get(&(*function_name, edition)) {
             Some(verifying_key) => Ok(verifying_key.clone()),
-            None => bail!("Verifying key not found for: {}/{function_name}", self.program.id()),
+            None => bail!("Verifying key not found for: {}/{function_name} (edition {edition})", self.program.id()),
         }
     }
 
@@ -386,7 +507,7 @@ impl<N: Network> Stack<N> {
             self.program.id()
         );
         // Insert the verifying key.
-        self.verifying_keys.write().insert(*function_name, verifying_key);
+        self.verifying_keys.write().insert((*function_name, self.edition), verifying_key);
         Ok(())
     }
 
@@ -399,13 +520,14 @@ impl<N: Network> Stack<N> {
     /// Removes the verifying key for the given function name.
     #[inline]
     pub fn remove_verifying_key(&self, function_name: &Identifier<N>) {
-        self.verifying_keys.write().remove(function_name);
+        self.verifying_keys.write().remove(&(*function_name, self.edition));
     }
 }
 
 impl<N: Network> PartialEq for Stack<N> {
     fn eq(&self, other: &Self) -> bool {
         self.program == other.program
+            && self.edition == other.edition
             && self.external_stacks == other.external_stacks
             && self.register_types == other.register_types
             && self.finalize_types == other.finalize_types
@@ -24,31 +24,41 @@ impl<N: Network> RegisterTypes<N> {
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
         let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        // Attaches the location of the failing statement to an error.
+        let locate = |location: Location| move |error: Error| LocatedError::wrap(*closure.name(), location, error);
 
         // Step 1. Check the inputs are well-formed.
-        for input in closure.inputs() {
+        for (index, input) in closure.inputs().iter().enumerate() {
             // Check the input register type.
-            register_types.check_input(stack, input.register(), input.register_type())?;
+            register_types
+                .check_input(stack, input.register(), input.register_type())
+                .map_err(locate(Location::Input(index)))?;
         }
 
         // Step 2. Check the instructions are well-formed.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
             // Ensure the closure contains no call instructions.
-            ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
+            if instruction.opcode() == Opcode::Call {
+                let error = anyhow!("A 'call' instruction is not allowed in closures");
+                return Err(locate(Location::Instruction(index))(error));
+            }
             // Check the instruction opcode, operands, and destinations.
-            register_types.check_instruction(stack, closure.name(), instruction)?;
+            register_types
+                .check_instruction(stack, closure.name(), instruction)
+                .map_err(locate(Location::Instruction(index)))?;
         }
 
         // Step 3. Check the outputs are well-formed.
-        for output in closure.outputs() {
+        for (index, output) in closure.outputs().iter().enumerate() {
             // Ensure the closure output register is not a record.
-            ensure!(
-                !matches!(output.register_type(), RegisterType::Record(..)),
-                "Closure outputs do not support records"
-            );
+            if matches!(output.register_type(), RegisterType::Record(..)) {
+                return Err(locate(Location::Output(index))(anyhow!("Closure outputs do not support records")));
+            }
 
             // Check the output operand type.
-            register_types.check_output(stack, output.operand(), output.register_type())?;
+            register_types
+                .check_output(stack, output.operand(), output.register_type())
+                .map_err(locate(Location::Output(index)))?;
         }
 
         Ok(register_types)
@@ -63,67 +73,89 @@ impl<N: Network> RegisterTypes<N> {
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
         let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        // Attaches the location of the failing statement to an error.
+        let locate = |location: Location| move |error: Error| LocatedError::wrap(*function.name(), location, error);
 
         // Step 1. Check the inputs are well-formed.
-        for input in function.inputs() {
+        for (index, input) in function.inputs().iter().enumerate() {
             // TODO (howardwu): In order to support constant inputs, update `Self::deploy()` to allow
             //  the caller to provide optional constant inputs (instead of sampling random constants).
             //  Then, this check can be removed to enable support for constant inputs in functions.
-            ensure!(!matches!(input.value_type(), ValueType::Constant(..)), "Constant inputs are not supported");
+            if matches!(input.value_type(), ValueType::Constant(..)) {
+                return Err(locate(Location::Input(index))(anyhow!("Constant inputs are not supported")));
+            }
 
             // Check the input register type.
-            register_types.check_input(stack, input.register(), &RegisterType::from(*input.value_type()))?;
+            register_types
+                .check_input(stack, input.register(), &RegisterType::from(input.value_type().clone()))
+                .map_err(locate(Location::Input(index)))?;
         }
 
         // Step 2. Check the instructions are well-formed.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // Check the instruction opcode, operands, and destinations.
-            register_types.check_instruction(stack, function.name(), instruction)?;
+            register_types
+                .check_instruction(stack, function.name(), instruction)
+                .map_err(locate(Location::Instruction(index)))?;
         }
 
         // Step 3. Check the outputs are well-formed.
-        for output in function.outputs() {
+        for (index, output) in function.outputs().iter().enumerate() {
             // Check the output operand type.
-            register_types.check_output(stack, output.operand(), &RegisterType::from(*output.value_type()))?;
+            register_types
+                .check_output(stack, output.operand(), &RegisterType::from(output.value_type().clone()))
+                .map_err(locate(Location::Output(index)))?;
         }
 
         // Step 4. If the function has a finalize command, check that its operands are all defined.
         if let Some((command, _)) = function.finalize() {
-            // Ensure the number of finalize operands is within bounds.
-            ensure!(
-                command.operands().len() <= N::MAX_INPUTS,
-                "Function '{}' has too many finalize operands",
-                function.name()
-            );
-
-            // Check the type of each finalize operand.
-            for operand in command.operands() {
-                // Retrieve the register type from the operand.
-                let register_type = register_types.get_type_from_operand(stack, operand)?;
-                // Ensure the register type is a literal or a struct.
-                // See `Stack::execute_function()` for the same set of checks.
-                match register_type {
-                    RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
-                    RegisterType::Plaintext(PlaintextType::Struct(..)) => (),
-                    RegisterType::Record(..) => {
-                        bail!(
-                            "'{}/{}' attempts to pass a 'record' into 'finalize'",
-                            stack.program_id(),
-                            function.name()
-                        );
-                    }
-                    RegisterType::ExternalRecord(..) => {
-                        bail!(
-                            "'{}/{}' attempts to pass an 'external record' into 'finalize'",
-                            stack.program_id(),
-                            function.name()
-                        );
-                    }
+            register_types
+                .check_finalize_command(stack, function, command)
+                .map_err(locate(Location::FinalizeCommand))?;
+        }
+
+        Ok(register_types)
+    }
+
+    /// Checks that the operands of the given `finalize` command are defined, and may be passed into finalize.
+    #[inline]
+    fn check_finalize_command(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        function: &Function<N>,
+        command: &FinalizeCommand<N>,
+    ) -> Result<()> {
+        // Ensure the number of finalize operands is within bounds.
+        ensure!(
+            command.operands().len() <= N::MAX_INPUTS,
+            "Function '{}' has too many finalize operands",
+            function.name()
+        );
+
+        // Check the type of each finalize operand.
+        for operand in command.operands() {
+            // Retrieve the register type from the operand.
+            let register_type = self.get_type_from_operand(stack, operand)?;
+            // Ensure the register type is a literal or a struct.
+            // See `Stack::execute_function()` for the same set of checks.
+            match register_type {
+                RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+                RegisterType::Plaintext(PlaintextType::Struct(..)) => (),
+                RegisterType::Plaintext(PlaintextType::Array(..)) => (),
+                RegisterType::Record(..) => {
+                    bail!("'{}/{}' attempts to pass a 'record' into 'finalize'", stack.program_id(), function.name());
+                }
+                RegisterType::ExternalRecord(..) => {
+                    bail!(
+                        "'{}/{}' attempts to pass an 'external record' into 'finalize'",
+                        stack.program_id(),
+                        function.name()
+                    );
                 }
             }
         }
 
-        Ok(register_types)
+        Ok(())
     }
 }
 
@@ -149,7 +181,7 @@ impl<N: Network> RegisterTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 
@@ -172,7 +204,7 @@ impl<N: Network> RegisterTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 }
@@ -195,6 +227,7 @@ impl<N: Network> RegisterTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array_type(stack, array_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -210,7 +243,7 @@ impl<N: Network> RegisterTypes<N> {
         };
 
         // Insert the input register.
-        self.add_input(register.clone(), *register_type)?;
+        self.add_input(register.clone(), register_type.clone())?;
 
         // Ensure the register type and the input type match.
         if *register_type != self.get_type(stack, register)? {
@@ -249,6 +282,7 @@ impl<N: Network> RegisterTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array_type(stack, array_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -274,6 +308,26 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given array type is well-formed.
+    #[inline]
+    fn check_array_type(stack: &(impl StackMatches<N> + StackProgram<N>), array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the number of elements does not exceed the maximum.
+        if **array_type.length() as usize > N::MAX_DATA_ENTRIES {
+            bail!("Array '{array_type}' in '{}' cannot exceed {} elements.", stack.program_id(), N::MAX_DATA_ENTRIES)
+        }
+        // Ensure the array does not exceed the maximum depth.
+        if array_type.depth() > N::MAX_DATA_DEPTH {
+            bail!("Array '{array_type}' in '{}' cannot exceed a depth of {}.", stack.program_id(), N::MAX_DATA_DEPTH)
+        }
+        // Ensure the base element type is defined in the program.
+        if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+            if !stack.program().contains_struct(struct_name) {
+                bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+            }
+        }
+        Ok(())
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -428,6 +482,12 @@ impl<N: Network> RegisterTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the array type is well-formed.
+                        Self::check_array_type(stack, array_type)?;
+                        // Ensure the operand types match the array.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(record_name) => {
                         // Ensure the record type is defined in the program.
                         if !stack.program().contains_record(record_name) {
@@ -508,6 +568,9 @@ impl<N: Network> RegisterTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.keccak256",
+                    "hash.sha3_256",
+                    "hash.sha256",
                 ]
                 .contains(&opcode)
                 {
@@ -551,6 +614,18 @@ impl<N: Network> RegisterTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.keccak256" => ensure!(
+                        matches!(instruction, Instruction::HashKeccak256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha3_256" => ensure!(
+                        matches!(instruction, Instruction::HashSha3_256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha256" => ensure!(
+                        matches!(instruction, Instruction::HashSha256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -572,6 +647,20 @@ impl<N: Network> RegisterTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign(opcode) => {
+                // Ensure the instruction belongs to the defined set.
+                if !["sign.verify"].contains(&opcode) {
+                    bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
+                }
+                // Ensure the instruction is the correct one.
+                match opcode {
+                    "sign.verify" => ensure!(
+                        matches!(instruction, Instruction::SignVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+            }
         }
         Ok(())
     }
@@ -64,7 +64,7 @@ impl<N: Network> RegisterTypes<N> {
                     );
                     // Ensure the register type matches the member type.
                     ensure!(
-                        register_type == RegisterType::Plaintext(*member_type),
+                        register_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{register_type}' in the operand '{operand}'.",
                     )
                 }
@@ -74,7 +74,7 @@ impl<N: Network> RegisterTypes<N> {
                     let program_ref_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the program ID type matches the member type.
                     ensure!(
-                        program_ref_type == RegisterType::Plaintext(*member_type),
+                        program_ref_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{program_ref_type}' in the operand '{operand}'.",
                     )
                 }
@@ -84,10 +84,14 @@ impl<N: Network> RegisterTypes<N> {
                     let caller_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the caller type matches the member type.
                     ensure!(
-                        caller_type == RegisterType::Plaintext(*member_type),
+                        caller_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block height and block timestamp are not used outside of 'finalize'.
+                Operand::BlockHeight | Operand::BlockTimestamp => {
+                    bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+                }
             }
         }
         Ok(())
@@ -147,6 +151,9 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Cannot cast a program ID ('{program_id}') as a record owner")
             }
             Operand::Caller => {}
+            Operand::BlockHeight | Operand::BlockTimestamp => {
+                bail!("Forbidden operation: Cannot use '{}' outside of 'finalize'", operands[0])
+            }
         }
 
         // Ensure the operand types match the record entry types.
@@ -176,7 +183,7 @@ impl<N: Network> RegisterTypes<N> {
                             );
                             // Ensure the register type matches the entry type.
                             ensure!(
-                                register_type == RegisterType::Plaintext(*plaintext_type),
+                                register_type == RegisterType::Plaintext(plaintext_type.clone()),
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{register_type}' in the operand '{operand}'.",
                             )
                         }
@@ -187,7 +194,7 @@ impl<N: Network> RegisterTypes<N> {
                                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                             // Ensure the program ID type matches the member type.
                             ensure!(
-                                program_ref_type == RegisterType::Plaintext(*plaintext_type),
+                                program_ref_type == RegisterType::Plaintext(plaintext_type.clone()),
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{program_ref_type}' in the operand '{operand}'.",
                             )
                         }
@@ -197,14 +204,55 @@ impl<N: Network> RegisterTypes<N> {
                             let caller_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                             // Ensure the caller type matches the member type.
                             ensure!(
-                                caller_type == RegisterType::Plaintext(*plaintext_type),
+                                caller_type == RegisterType::Plaintext(plaintext_type.clone()),
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{caller_type}' in the operand '{operand}'.",
                             )
                         }
+                        // Ensure the block height and block timestamp are not used outside of 'finalize'.
+                        Operand::BlockHeight | Operand::BlockTimestamp => {
+                            bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+                        }
                     }
                 }
             }
         }
         Ok(())
     }
+
+    /// Checks that the given operands matches the layout of the array. The ordering of the operands matters.
+    pub fn matches_array(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        operands: &[Operand<N>],
+        array_type: &ArrayType<N>,
+    ) -> Result<()> {
+        // Ensure the number of elements does not exceed the maximum.
+        if operands.len() > N::MAX_DATA_ENTRIES {
+            bail!("'{array_type}' cannot exceed {} elements", N::MAX_DATA_ENTRIES)
+        }
+        // Ensure the array does not exceed the maximum depth.
+        if array_type.depth() > N::MAX_DATA_DEPTH {
+            bail!("'{array_type}' cannot exceed a depth of {}", N::MAX_DATA_DEPTH)
+        }
+
+        // Ensure the number of elements match.
+        let num_elements = operands.len();
+        let expected_num_elements = **array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Ensure the operand types match the element type.
+        let element_type = array_type.element_type();
+        for (index, operand) in operands.iter().enumerate() {
+            // Retrieve the operand type.
+            let operand_type = self.get_type_from_operand(stack, operand)?;
+            // Ensure the operand type matches the element type.
+            ensure!(
+                operand_type == RegisterType::Plaintext(element_type.clone()),
+                "Array element at index {index} expects a '{element_type}', but found '{operand_type}' in the operand '{operand}'.",
+            )
+        }
+        Ok(())
+    }
 }
@@ -15,10 +15,25 @@
 mod initialize;
 mod matches;
 
-use crate::{CallOperator, Closure, Function, Instruction, Opcode, Operand, Program, StackMatches, StackProgram};
+use crate::{
+    finalize::FinalizeCommand,
+    CallOperator,
+    Closure,
+    Function,
+    Instruction,
+    LocatedError,
+    Location,
+    Opcode,
+    Operand,
+    Program,
+    StackMatches,
+    StackProgram,
+};
 use console::{
     network::prelude::*,
     program::{
+        Access,
+        ArrayType,
         EntryType,
         Identifier,
         LiteralType,
@@ -81,6 +96,9 @@ impl<N: Network> RegisterTypes<N> {
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::Caller => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::BlockHeight | Operand::BlockTimestamp => {
+                bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+            }
         })
     }
 
@@ -93,44 +111,60 @@ impl<N: Network> RegisterTypes<N> {
         // Initialize a tracker for the register type.
         let mut register_type = if self.is_input(register) {
             // Retrieve the input value type as a register type.
-            *self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+            self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?.clone()
         } else {
             // Retrieve the destination register type.
-            *self
-                .destinations
+            self.destinations
                 .get(&register.locator())
                 .ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+                .clone()
         };
 
-        // Retrieve the member path if the register is a member. Otherwise, return the register type.
+        // Retrieve the access path if the register is an access. Otherwise, return the register type.
         let path = match &register {
             // If the register is a locator, then output the register type.
             Register::Locator(..) => return Ok(register_type),
-            // If the register is a member, then traverse the member path to output the register type.
-            Register::Member(_, path) => {
-                // Ensure the member path is valid.
-                ensure!(!path.is_empty(), "Register '{register}' references no members.");
-                // Output the member path.
+            // If the register is an access, then traverse the access path to output the register type.
+            Register::Access(_, path) => {
+                // Ensure the access path is valid.
+                ensure!(!path.is_empty(), "Register '{register}' references no accesses.");
+                // Output the access path.
                 path
             }
         };
 
-        // Traverse the member path to find the register type.
-        for path_name in path.iter() {
+        // Traverse the access path to find the register type.
+        for access in path.iter() {
             // Update the register type at each step.
-            register_type = match &register_type {
-                // Ensure the plaintext type is not a literal, as the register references a member.
-                RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("'{register}' references a literal."),
+            register_type = match (&register_type, access) {
+                // Ensure the plaintext type is not a literal, as the register references an access.
+                (RegisterType::Plaintext(PlaintextType::Literal(..)), _) => bail!("'{register}' references a literal."),
                 // Traverse the member path to output the register type.
-                RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
+                (RegisterType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(path_name)) => {
                     // Retrieve the member type from the struct.
                     match stack.program().get_struct(struct_name)?.members().get(path_name) {
                         // Update the member type.
-                        Some(plaintext_type) => RegisterType::Plaintext(*plaintext_type),
+                        Some(plaintext_type) => RegisterType::Plaintext(plaintext_type.clone()),
                         None => bail!("'{path_name}' does not exist in struct '{struct_name}'"),
                     }
                 }
-                RegisterType::Record(record_name) => {
+                // Traverse the array to output the register type.
+                (RegisterType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
+                    // Ensure the index is within bounds.
+                    match **index < **array_type.length() {
+                        true => RegisterType::Plaintext(array_type.element_type().clone()),
+                        false => {
+                            bail!("Index '{index}' is out of bounds for array of length '{}'", array_type.length())
+                        }
+                    }
+                }
+                (RegisterType::Plaintext(PlaintextType::Struct(..)), Access::Index(..)) => {
+                    bail!("'{register}' references a struct with an index.")
+                }
+                (RegisterType::Plaintext(PlaintextType::Array(..)), Access::Member(..)) => {
+                    bail!("'{register}' references an array with a member.")
+                }
+                (RegisterType::Record(record_name), Access::Member(path_name)) => {
                     // Ensure the record type exists.
                     ensure!(stack.program().contains_record(record_name), "Record '{record_name}' does not exist");
                     // Retrieve the member type from the record.
@@ -144,13 +178,13 @@ impl<N: Network> RegisterTypes<N> {
                             Some(entry_type) => match entry_type {
                                 EntryType::Constant(plaintext_type)
                                 | EntryType::Public(plaintext_type)
-                                | EntryType::Private(plaintext_type) => RegisterType::Plaintext(*plaintext_type),
+                                | EntryType::Private(plaintext_type) => RegisterType::Plaintext(plaintext_type.clone()),
                             },
                             None => bail!("'{path_name}' does not exist in record '{record_name}'"),
                         }
                     }
                 }
-                RegisterType::ExternalRecord(locator) => {
+                (RegisterType::ExternalRecord(locator), Access::Member(path_name)) => {
                     // Ensure the external record type exists.
                     ensure!(stack.contains_external_record(locator), "External record '{locator}' does not exist");
                     // Retrieve the member type from the external record.
@@ -164,12 +198,15 @@ impl<N: Network> RegisterTypes<N> {
                             Some(entry_type) => match entry_type {
                                 EntryType::Constant(plaintext_type)
                                 | EntryType::Public(plaintext_type)
-                                | EntryType::Private(plaintext_type) => RegisterType::Plaintext(*plaintext_type),
+                                | EntryType::Private(plaintext_type) => RegisterType::Plaintext(plaintext_type.clone()),
                             },
                             None => bail!("'{path_name}' does not exist in external record '{locator}'"),
                         }
                     }
                 }
+                (RegisterType::Record(..) | RegisterType::ExternalRecord(..), Access::Index(..)) => {
+                    bail!("'{register}' references a record with an index.")
+                }
             }
         }
         // Output the member type.
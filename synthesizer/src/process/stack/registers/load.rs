@@ -34,6 +34,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             }
             // If the operand is the caller, load the value of the caller.
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
+            // If the operand is the block height or block timestamp, throw an error.
+            Operand::BlockHeight | Operand::BlockTimestamp => {
+                bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+            }
         };
 
         // Retrieve the stack value.
@@ -44,8 +48,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
         let stack_value = match register {
             // If the register is a locator, then return the stack value.
             Register::Locator(..) => stack_value.clone(),
-            // If the register is a register member, then load the specific stack value.
-            Register::Member(_, ref path) => {
+            // If the register is a register access, then load the specific stack value.
+            Register::Access(_, ref path) => {
                 match stack_value {
                     // Retrieve the plaintext member from the path.
                     Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(path)?),
@@ -107,6 +111,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
                     self.caller_circuit()?,
                 ))));
             }
+            // If the operand is the block height or block timestamp, throw an error.
+            Operand::BlockHeight | Operand::BlockTimestamp => {
+                bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+            }
         };
 
         // Retrieve the circuit value.
@@ -117,10 +125,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
         let circuit_value = match register {
             // If the register is a locator, then return the stack value.
             Register::Locator(..) => circuit_value.clone(),
-            // If the register is a register member, then load the specific stack value.
-            Register::Member(_, ref path) => {
+            // If the register is a register access, then load the specific stack value.
+            Register::Access(_, ref path) => {
                 // Inject the path.
-                let path = path.iter().map(|member| circuit::Identifier::constant(*member)).collect::<Vec<_>>();
+                let path = path.iter().map(|access| circuit::Access::constant(*access)).collect::<Vec<_>>();
 
                 match circuit_value {
                     // Retrieve the plaintext member from the path.
@@ -56,7 +56,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
                 }
             }
             // Ensure the register is not a register member.
-            Register::Member(..) => bail!("Cannot store to a register member: '{register}'"),
+            Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
         }
     }
 }
@@ -105,7 +105,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStoreCircuit<N, A> for
                 }
             }
             // Ensure the register is not a register member.
-            Register::Member(..) => bail!("Cannot store to a register member: '{register}'"),
+            Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
         }
     }
 }
@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Instruction, Operand};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID, Register, Value},
+};
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// An instruction that was evaluated, along with its resolved operands and the registers it stored into.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceEntry<N: Network> {
+    /// The call depth of the instruction, where `0` is the main function.
+    depth: usize,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The name of the function or closure.
+    resource: Identifier<N>,
+    /// The instruction.
+    instruction: Instruction<N>,
+    /// The resolved operands, if they could be loaded.
+    inputs: Vec<(Operand<N>, Option<Value<N>>)>,
+    /// The destination registers and their stored values.
+    outputs: Vec<(Register<N>, Value<N>)>,
+    /// The error, if the instruction failed.
+    error: Option<String>,
+}
+
+impl<N: Network> TraceEntry<N> {
+    /// Returns the call depth of the instruction, where `0` is the main function.
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the function or closure.
+    pub const fn resource(&self) -> &Identifier<N> {
+        &self.resource
+    }
+
+    /// Returns the instruction.
+    pub const fn instruction(&self) -> &Instruction<N> {
+        &self.instruction
+    }
+
+    /// Returns the resolved operands.
+    pub fn inputs(&self) -> &[(Operand<N>, Option<Value<N>>)] {
+        &self.inputs
+    }
+
+    /// Returns the destination registers and their stored values.
+    pub fn outputs(&self) -> &[(Register<N>, Value<N>)] {
+        &self.outputs
+    }
+
+    /// Returns the error, if the instruction failed.
+    pub fn error(&self) -> Option<&String> {
+        self.error.as_ref()
+    }
+}
+
+impl<N: Network> Serialize for TraceEntry<N> {
+    /// Serializes the trace entry into a structured log.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entry = serializer.serialize_struct("TraceEntry", 7)?;
+        entry.serialize_field("depth", &self.depth)?;
+        entry.serialize_field("program_id", &self.program_id)?;
+        entry.serialize_field("resource", &self.resource)?;
+        entry.serialize_field("instruction", &self.instruction.to_string())?;
+        entry.serialize_field(
+            "inputs",
+            &self
+                .inputs
+                .iter()
+                .map(|(operand, value)| (operand.to_string(), value.as_ref().map(ToString::to_string)))
+                .collect::<Vec<_>>(),
+        )?;
+        entry.serialize_field(
+            "outputs",
+            &self.outputs.iter().map(|(register, value)| (register.to_string(), value.to_string())).collect::<Vec<_>>(),
+        )?;
+        entry.serialize_field("error", &self.error)?;
+        entry.end()
+    }
+}
+
+impl<N: Network> Display for TraceEntry<N> {
+    /// Prints the trace entry as an indented line, e.g. `token.aleo/mint: add r0 r1 into r2; (r0 = 1u64, r1 = 2u64) -> r2 = 3u64`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the location and the instruction.
+        let indent = "  ".repeat(self.depth);
+        write!(f, "{indent}{}/{}: {}", self.program_id, self.resource, self.instruction)?;
+        // Print the resolved operands.
+        let inputs = self
+            .inputs
+            .iter()
+            .filter(|(operand, _)| !matches!(operand, Operand::Literal(..)))
+            .map(|(operand, value)| match value {
+                Some(value) => format!("{operand} = {value}"),
+                None => format!("{operand} = ?"),
+            })
+            .collect::<Vec<_>>();
+        if !inputs.is_empty() {
+            write!(f, " ({})", inputs.join(", "))?;
+        }
+        // Print the stored registers, or the error.
+        match &self.error {
+            Some(error) => write!(f, " -> error: {error}"),
+            None => {
+                let outputs =
+                    self.outputs.iter().map(|(register, value)| format!("{register} = {value}")).collect::<Vec<_>>();
+                match outputs.is_empty() {
+                    true => Ok(()),
+                    false => write!(f, " -> {}", outputs.join(", ")),
+                }
+            }
+        }
+    }
+}
+
+/// A shared trace of the instructions evaluated in a call, including those in closures and external calls.
+#[derive(Clone, Default)]
+pub struct Trace<N: Network> {
+    /// The recorded entries, and the current call depth.
+    state: Arc<RwLock<(Vec<TraceEntry<N>>, usize)>>,
+}
+
+impl<N: Network> Trace<N> {
+    /// Initializes a new, empty trace.
+    pub fn new() -> Self {
+        Self { state: Default::default() }
+    }
+
+    /// Returns the recorded entries, in evaluation order.
+    pub fn entries(&self) -> Vec<TraceEntry<N>> {
+        self.state.read().0.clone()
+    }
+
+    /// Returns `true` if no entries were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.state.read().0.is_empty()
+    }
+
+    /// Enters a function or closure, increasing the call depth of subsequent entries.
+    pub(crate) fn enter(&self) {
+        self.state.write().1 += 1;
+    }
+
+    /// Exits a function or closure, decreasing the call depth of subsequent entries.
+    pub(crate) fn exit(&self) {
+        let depth = &mut self.state.write().1;
+        *depth = depth.saturating_sub(1);
+    }
+
+    /// Records the start of an instruction with the given resolved operands, returning the index of its entry.
+    pub(crate) fn start(
+        &self,
+        program_id: ProgramID<N>,
+        resource: Identifier<N>,
+        instruction: &Instruction<N>,
+        inputs: Vec<(Operand<N>, Option<Value<N>>)>,
+    ) -> usize {
+        let mut state = self.state.write();
+        // The depth of the entry is that of its function or closure.
+        let depth = state.1.saturating_sub(1);
+        state.0.push(TraceEntry {
+            depth,
+            program_id,
+            resource,
+            instruction: instruction.clone(),
+            inputs,
+            outputs: Vec::new(),
+            error: None,
+        });
+        state.0.len() - 1
+    }
+
+    /// Records the outcome of the instruction at the given index.
+    pub(crate) fn finish(&self, index: usize, outputs: Vec<(Register<N>, Value<N>)>, error: Option<String>) {
+        if let Some(entry) = self.state.write().0.get_mut(index) {
+            entry.outputs = outputs;
+            entry.error = error;
+        }
+    }
+}
+
+impl<N: Network> Display for Trace<N> {
+    /// Prints each trace entry on its own line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for entry in self.state.read().0.iter() {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
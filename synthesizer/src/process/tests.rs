@@ -13,13 +13,13 @@
 // limitations under the License.
 
 use super::*;
-use crate::store::helpers::memory::FinalizeMemory;
+use crate::{store::helpers::memory::FinalizeMemory, Diagnostic, SourceMap};
 use circuit::network::AleoV0;
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::Testnet3,
-    program::{Identifier, Literal, Value},
-    types::Field,
+    program::{Identifier, Literal, Register, Value},
+    types::{Field, U8},
 };
 
 type CurrentNetwork = Testnet3;
@@ -575,7 +575,8 @@ function compute:
     assert_eq!(authorization.len(), 1);
 
     // Execute the request.
-    let (response, execution, _inclusion, _metrics) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let (response, execution, _inclusion, metrics) =
+        process.execute_with_profile::<CurrentAleo, _>(authorization.replicate(), rng).unwrap();
     let candidate = response.outputs();
     assert_eq!(4, candidate.len());
     assert_eq!(r3, candidate[0]);
@@ -585,6 +586,24 @@ function compute:
 
     process.verify_execution::<false>(&execution).unwrap();
 
+    // Ensure each instruction is profiled, with the closure calls attributed to their closures.
+    assert_eq!(metrics.len(), 1);
+    let instructions = &metrics[0].instructions;
+    assert_eq!(instructions.len(), 3);
+    assert_eq!(instructions[0].closure, None);
+    assert_eq!(instructions[1].closure, Some(Identifier::from_str("check_not_equal").unwrap()));
+    assert_eq!(instructions[2].closure, Some(Identifier::from_str("execute").unwrap()));
+    assert!(instructions[1].num_constraints > 0);
+    assert!(instructions.iter().all(|instruction| instruction.num_public == 0));
+    // Ensure the instructions account for no more than the constraints of the function.
+    let num_constraints = instructions.iter().map(|instruction| instruction.num_constraints).sum::<u64>();
+    assert!(num_constraints <= metrics[0].num_function_constraints);
+
+    // Ensure the instructions are not profiled by default.
+    let (_response, _execution, _inclusion, metrics) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(metrics.len(), 1);
+    assert!(metrics[0].instructions.is_empty());
+
     // use circuit::Environment;
     //
     // assert_eq!(37080, CurrentAleo::num_constants());
@@ -594,6 +613,82 @@ function compute:
     // assert_eq!(159387, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_evaluate_with_trace() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program trace.aleo;
+
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    call twice r0 into r2;
+    assert.eq r2 r1;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Evaluate with inputs that satisfy the assertion.
+    let inputs = [Value::<CurrentNetwork>::from_str("3u64").unwrap(), Value::from_str("6u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let trace = Trace::new();
+    let response = process.evaluate_with_trace::<CurrentAleo>(authorization, &trace).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("6u64").unwrap()]);
+
+    // Ensure the call is recorded before the closure instructions, which are nested one level deeper.
+    let entries = trace.entries();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].instruction().to_string(), "call twice r0 into r2;");
+    assert_eq!(entries[0].depth(), 0);
+    assert_eq!(entries[1].instruction().to_string(), "add r0 r0 into r1;");
+    assert_eq!(entries[1].depth(), 1);
+    assert_eq!(entries[1].resource().to_string(), "twice");
+    assert_eq!(entries[1].inputs()[0].1, Some(Value::from_str("3u64").unwrap()));
+    assert_eq!(entries[1].outputs(), [(Register::Locator(1), Value::from_str("6u64").unwrap())]);
+    assert_eq!(entries[0].outputs(), [(Register::Locator(2), Value::from_str("6u64").unwrap())]);
+    assert_eq!(entries[2].instruction().to_string(), "assert.eq r2 r1;");
+    assert!(entries.iter().all(|entry| entry.error().is_none()));
+
+    // Evaluate with inputs that fail the assertion.
+    let inputs = [Value::<CurrentNetwork>::from_str("3u64").unwrap(), Value::from_str("7u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let trace = Trace::new();
+    assert!(process.evaluate_with_trace::<CurrentAleo>(authorization, &trace).is_err());
+
+    // Ensure the trace ends at the failing instruction, with its resolved operands.
+    let entries = trace.entries();
+    assert_eq!(entries.len(), 3);
+    let failed = entries.last().unwrap();
+    assert_eq!(failed.instruction().to_string(), "assert.eq r2 r1;");
+    assert_eq!(failed.inputs()[0].1, Some(Value::from_str("6u64").unwrap()));
+    assert_eq!(failed.inputs()[1].1, Some(Value::from_str("7u64").unwrap()));
+    assert!(failed.error().is_some());
+    assert!(failed.outputs().is_empty());
+}
+
 #[test]
 fn test_process_execute_call_external_function() {
     // Initialize a new program.
@@ -842,7 +937,7 @@ finalize compute:
     process.verify_execution::<true>(&execution).unwrap();
 
     // Now, finalize the execution.
-    process.finalize_execution(&store, &execution).unwrap();
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &execution).unwrap();
 
     // Check that the account balance is now 8.
     let candidate = store
@@ -852,6 +947,278 @@ finalize compute:
     assert_eq!(candidate, Value::from_str("8u64").unwrap());
 }
 
+#[test]
+fn test_process_upgrade_program() {
+    // Initialize the first edition of the program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+mapping counter:
+    key id as u8.public;
+    value count as u8.public;
+
+mapping legacy:
+    key id as u8.public;
+    value flag as boolean.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+
+    // Initialize the second edition of the program.
+    let upgrade = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+mapping counter:
+    key id as u8.public;
+    value count as u16.public;
+
+mapping fresh:
+    key id as u8.public;
+    value flag as boolean.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    mul r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+
+    // Declare the program ID.
+    let program_id = *program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    // Initialize a new finalize store.
+    let store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the first edition of the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    assert_eq!(deployment.edition(), CurrentNetwork::EDITION);
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let (stack, _) = process.finalize_deployment(&store, &deployment).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the inputs.
+    let inputs = [
+        Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(),
+        Value::<CurrentNetwork>::from_str("3u64").unwrap(),
+        Value::<CurrentNetwork>::from_str("5u64").unwrap(),
+    ];
+
+    // Execute and finalize the first edition.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, &program_id, function_name, inputs.iter(), rng)
+        .unwrap();
+    let (_, first_execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(first_execution.edition(&program_id), CurrentNetwork::EDITION);
+    process.verify_execution::<true>(&first_execution).unwrap();
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &first_execution).unwrap();
+
+    // Check that the account balance is now 8.
+    let key = Plaintext::from(Literal::Address(caller));
+    let candidate = store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("8u64").unwrap());
+
+    // Deploy the second edition of the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&upgrade, rng).unwrap();
+    assert_eq!(deployment.edition(), CurrentNetwork::EDITION + 1);
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let (stack, operations) = process.finalize_deployment(&store, &deployment).unwrap();
+    process.add_stack(stack);
+
+    // Check that only the dropped, changed, and new mappings were modified.
+    assert_eq!(operations.len(), 4);
+    let mapping_names = store.get_mapping_names_speculative(&program_id).unwrap().unwrap();
+    assert!(mapping_names.contains(&Identifier::from_str("account").unwrap()));
+    assert!(mapping_names.contains(&Identifier::from_str("counter").unwrap()));
+    assert!(mapping_names.contains(&Identifier::from_str("fresh").unwrap()));
+    assert!(!mapping_names.contains(&Identifier::from_str("legacy").unwrap()));
+
+    // Check that the preserved mapping retains its value.
+    let candidate = store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("8u64").unwrap());
+
+    // Execute and finalize the second edition.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, &program_id, function_name, inputs.iter(), rng)
+        .unwrap();
+    let (_, second_execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(second_execution.edition(&program_id), CurrentNetwork::EDITION + 1);
+    process.verify_execution::<true>(&second_execution).unwrap();
+
+    // Check that relabeling the edition invalidates the execution, and changes the transaction ID.
+    let relabeled = Execution::from(
+        second_execution.transitions().cloned(),
+        Default::default(),
+        second_execution.global_state_root(),
+        second_execution.inclusion_proof().cloned(),
+    )
+    .unwrap();
+    assert!(process.verify_execution::<true>(&relabeled).is_err());
+    let transaction = crate::Transaction::from_execution(second_execution.clone(), None).unwrap();
+    let relabeled = crate::Transaction::from_execution(relabeled, None).unwrap();
+    assert_ne!(transaction.id(), relabeled.id());
+
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &second_execution).unwrap();
+
+    // Check that the account balance is now 23.
+    let candidate = store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("23u64").unwrap());
+
+    // Check that the execution of the first edition still verifies against its own verifying key.
+    process.verify_execution::<true>(&first_execution).unwrap();
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &first_execution).unwrap();
+
+    // Check that the account balance is now 31.
+    let candidate = store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("31u64").unwrap());
+}
+
+#[test]
+fn test_process_execute_and_finalize_transfer_public() {
+    // Initialize the 'credits.aleo' program.
+    let program = Program::<CurrentNetwork>::credits().unwrap();
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Initialize a new finalize store.
+    let store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+    // Initialize the mappings for 'credits.aleo'.
+    for mapping in program.mappings().values() {
+        store.initialize_mapping(program_id, mapping.name()).unwrap();
+    }
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    // Initialize a new receiver account.
+    let receiver = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    // Prepare the keys of the public balances.
+    let caller_key = Plaintext::from(Literal::Address(caller));
+    let receiver_key = Plaintext::from(Literal::Address(receiver));
+
+    // Set the public balance of the caller to 100.
+    store.insert_key_value(program_id, &mapping_name, caller_key.clone(), Value::from_str("100u64").unwrap()).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&receiver.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("30u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program_id,
+            Identifier::from_str("transfer_public").unwrap(),
+            [r0, r1].iter(),
+            rng,
+        )
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, execution, _inclusion, _metrics) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(0, candidate.len());
+
+    // Verify the execution.
+    process.verify_execution::<true>(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &execution).unwrap();
+
+    // Check that the public balance of the caller is now 70.
+    let candidate = store.get_value_speculative(program_id, &mapping_name, &caller_key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("70u64").unwrap());
+    // Check that the public balance of the receiver is now 30.
+    let candidate = store.get_value_speculative(program_id, &mapping_name, &receiver_key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("30u64").unwrap());
+
+    // Execute the fee, which is paid from the public balance of the caller.
+    let (_response, fee_transition, _inclusion, _metrics) =
+        process.execute_fee_public::<CurrentAleo, _>(&caller_private_key, 5, rng).unwrap();
+    // Construct the fee.
+    let fee = Fee::from(fee_transition, Field::<CurrentNetwork>::from_u64(1).into(), None);
+    assert!(fee.is_fee_public());
+    assert_eq!(*fee.amount().unwrap(), 5);
+
+    // Verify the fee.
+    process.verify_fee(&fee).unwrap();
+
+    // Now, finalize the fee.
+    process.finalize_fee(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &fee).unwrap();
+
+    // Check that the public balance of the caller is now 65.
+    let candidate = store.get_value_speculative(program_id, &mapping_name, &caller_key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("65u64").unwrap());
+
+    // Execute a fee that exceeds the public balance of the caller.
+    let (_response, fee_transition, _inclusion, _metrics) =
+        process.execute_fee_public::<CurrentAleo, _>(&caller_private_key, 66, rng).unwrap();
+    let fee = Fee::from(fee_transition, Field::<CurrentNetwork>::from_u64(1).into(), None);
+    // Ensure the fee fails to finalize.
+    assert!(process.finalize_fee(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &fee).is_err());
+
+    // Check that the public balance of the caller is unchanged.
+    let candidate = store.get_value_speculative(program_id, &mapping_name, &caller_key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("65u64").unwrap());
+}
+
 #[test]
 fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
     // Initialize a new program.
@@ -945,7 +1312,7 @@ finalize compute:
     process.verify_execution::<true>(&execution).unwrap();
 
     // Now, finalize the execution.
-    process.finalize_execution(&store, &execution).unwrap();
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &execution).unwrap();
 
     // Check that the account balance is now 0.
     let candidate = store
@@ -955,6 +1322,115 @@ finalize compute:
     assert_eq!(candidate, Value::from_str("0u64").unwrap());
 }
 
+#[test]
+fn test_process_execute_and_finalize_contains_remove() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function check:
+    input r0 as address.public;
+    input r1 as boolean.public;
+    finalize r0 r1;
+
+finalize check:
+    input r0 as address.public;
+    input r1 as boolean.public;
+    contains account[r0] into r2;
+    assert.eq r2 r1;
+
+function clear:
+    input r0 as address.public;
+    finalize r0;
+
+finalize clear:
+    input r0 as address.public;
+    remove account[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new finalize store.
+    let store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(&store, &deployment).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    let caller_key = Plaintext::from(Literal::Address(caller));
+
+    // Executes the given function with the given inputs, and returns the execution.
+    let mut execute = |function_name: &str, inputs: &[&str]| {
+        let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap()).collect::<Vec<_>>();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+            .unwrap();
+        let (_, execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        process.verify_execution::<true>(&execution).unwrap();
+        execution
+    };
+
+    // Prepare the executions.
+    let caller_string = caller.to_string();
+    let expect_absent = execute("check", &[&caller_string, "false"]);
+    let expect_present = execute("check", &[&caller_string, "true"]);
+    let clear = execute("clear", &[&caller_string]);
+
+    // Prepare the finalize state.
+    let state = FinalizeGlobalState::from(0, 0, [0u8; 32]);
+
+    // Ensure `contains` is false for an absent key.
+    process.finalize_execution(state, &store, &expect_absent).unwrap();
+    assert!(process.finalize_execution(state, &store, &expect_present).is_err());
+
+    // Insert the key into the mapping.
+    store.insert_key_value(program_id, &mapping_name, caller_key.clone(), Value::from_str("1u64").unwrap()).unwrap();
+
+    // Ensure `contains` is true for a present key.
+    process.finalize_execution(state, &store, &expect_present).unwrap();
+    assert!(process.finalize_execution(state, &store, &expect_absent).is_err());
+
+    // Remove the key from the mapping.
+    let finalize = process.finalize_execution(state, &store, &clear).unwrap();
+    assert_eq!(finalize.len(), 1);
+    assert!(matches!(finalize[0], crate::FinalizeOperation::RemoveKeyValue(..)));
+    assert!(store.get_value_speculative(program_id, &mapping_name, &caller_key).unwrap().is_none());
+
+    // Ensure the execution is accepted with its remove operation.
+    let transaction = crate::Transaction::from_execution(clear, None).unwrap();
+    let confirmed = crate::ConfirmedTransaction::accepted_execute(0, transaction, finalize).unwrap();
+    assert!(confirmed.is_accepted());
+    assert_eq!(confirmed.num_finalize(), 1);
+
+    // Ensure `contains` is false once the key is removed.
+    process.finalize_execution(state, &store, &expect_absent).unwrap();
+}
+
 #[test]
 fn test_process_execute_mint_public() {
     // Initialize a new program.
@@ -1066,7 +1542,7 @@ finalize mint_public:
     process.verify_execution::<true>(&execution).unwrap();
 
     // Now, finalize the execution.
-    process.finalize_execution(&store, &execution).unwrap();
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &execution).unwrap();
 
     // Check the account balance.
     let candidate = store
@@ -1208,7 +1684,7 @@ function mint:
     process.verify_execution::<true>(&execution).unwrap();
 
     // Now, finalize the execution.
-    process.finalize_execution(&store, &execution).unwrap();
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &execution).unwrap();
 
     // Check the account balance.
     let candidate = store
@@ -1313,7 +1789,7 @@ finalize compute:
     process.verify_execution::<true>(&execution).unwrap();
 
     // Now, finalize the execution.
-    process.finalize_execution(&store, &execution).unwrap();
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &execution).unwrap();
 
     // Check that the account balance is now 8.
     let candidate = store
@@ -1324,23 +1800,344 @@ finalize compute:
 }
 
 #[test]
-fn test_process_execute_and_finalize_get_set_with_struct() {
+fn test_process_execute_and_finalize_branch() {
     // Initialize a new program.
     let (string, program) = Program::<CurrentNetwork>::parse(
         r"
 program testing.aleo;
 
-struct entry:
-    count as u8;
-    data as u8;
-
-mapping entries:
+mapping account:
     key owner as address.public;
-    value entry as entry.public;
+    value amount as u64.public;
 
 function compute:
-    input r0 as u8.public;
-    input r1 as u8.public;
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    gt r1 10u64 into r2;
+    branch.eq r2 true to clear;
+    set r1 into account[r0];
+    branch.eq true true to end;
+    position clear;
+    remove account[r0];
+    position end;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new finalize store.
+    let store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(&store, &deployment).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    let key = Plaintext::from(Literal::Address(caller));
+
+    // Execute and finalize the function, with the given inputs.
+    let mut execute = |r1: &str, r2: &str| {
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str(r1).unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str(r2).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0, r1, r2].iter(), rng)
+            .unwrap();
+        let (_, execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        process.verify_execution::<true>(&execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &execution).unwrap();
+    };
+
+    // Check that the branch is not taken, and the amount is set to 8.
+    execute("3u64", "5u64");
+    let candidate = store.get_value_speculative(program_id, &mapping_name, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("8u64").unwrap());
+
+    // Check that the branch is taken, and the amount is removed.
+    execute("6u64", "7u64");
+    assert!(store.get_value_speculative(program_id, &mapping_name, &key).unwrap().is_none());
+}
+
+#[test]
+fn test_process_finalize_branch_register_assignment() {
+    // Returns a program with the given finalize commands.
+    let sample_program = |commands: &str| {
+        Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+{commands}"
+        ))
+        .unwrap()
+    };
+
+    // Construct a new process.
+    let process =
+        Process::<CurrentNetwork> { universal_srs: Arc::new(UniversalSRS::load().unwrap()), stacks: IndexMap::new() };
+
+    // Ensure a register assigned in only one arm of the branch cannot be read after the position.
+    let program = sample_program(
+        r"    gt r1 10u64 into r2;
+    branch.eq r2 true to skip;
+    add r1 1u64 into r3;
+    position skip;
+    set r3 into account[r0];
+",
+    );
+    let errors = process.check_program(&program);
+    assert_eq!(errors.len(), 1);
+    let located = errors[0].downcast_ref::<LocatedError<CurrentNetwork>>().unwrap();
+    assert_eq!(located.location(), Location::Command(4));
+    assert!(located.to_string().contains("is not assigned on every path to"));
+
+    // Ensure a register assigned on both arms of the branch can be read after the position.
+    // Note: As registers are assigned once, this is a register assigned before the branch.
+    let program = sample_program(
+        r"    add r1 1u64 into r2;
+    gt r1 10u64 into r3;
+    branch.eq r3 true to skip;
+    add r2 1u64 into r4;
+    set r4 into account[r0];
+    position skip;
+    set r2 into account[r0];
+",
+    );
+    assert!(process.check_program(&program).is_empty());
+}
+
+#[test]
+fn test_process_execute_and_finalize_block_height() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping unlocked:
+    key owner as address.public;
+    value height as u32.public;
+
+function unlock:
+    input r0 as address.public;
+    input r1 as u32.public;
+    finalize r0 r1;
+
+finalize unlock:
+    input r0 as address.public;
+    input r1 as u32.public;
+    gte block.height r1 into r2;
+    assert.eq r2 true;
+    set block.height into unlocked[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("unlocked").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("unlock").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new finalize store.
+    let store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(&store, &deployment).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    let key = Plaintext::from(Literal::Address(caller));
+
+    // Execute the function, unlocking at block height 10.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("10u32").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    let (_, execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    process.verify_execution::<true>(&execution).unwrap();
+
+    // Check that finalize fails before block height 10.
+    assert!(process.finalize_execution(FinalizeGlobalState::from(9, 0, [0u8; 32]), &store, &execution).is_err());
+    assert!(store.get_value_speculative(program_id, &mapping_name, &key).unwrap().is_none());
+
+    // Check that finalize succeeds at block height 12, and stores the block height.
+    process.finalize_execution(FinalizeGlobalState::from(12, 0, [0u8; 32]), &store, &execution).unwrap();
+    let candidate = store.get_value_speculative(program_id, &mapping_name, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("12u32").unwrap());
+
+    // Ensure the block height cannot be used outside of finalize.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program outside.aleo;
+
+function compute:
+    input r0 as u32.public;
+    add r0 block.height into r1;
+    output r1 as u32.public;
+",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_rand_chacha() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping rolls:
+    key seed as u8.public;
+    value roll as u64.public;
+
+function roll:
+    input r0 as u8.public;
+    finalize r0;
+
+finalize roll:
+    input r0 as u8.public;
+    rand.chacha r0 into r1 as u64;
+    set r1 into rolls[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("rolls").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("roll").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new finalize store.
+    let store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(&store, &deployment).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute the function.
+    let r0 = Value::<CurrentNetwork>::from_str("7u8").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0].iter(), rng)
+        .unwrap();
+    let (_, execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    process.verify_execution::<true>(&execution).unwrap();
+
+    // Declare the key.
+    let key = Plaintext::from(Literal::U8(U8::new(7)));
+
+    // Finalize the execution, and retrieve the sampled value.
+    process.finalize_execution(FinalizeGlobalState::from(1, 0, [1u8; 32]), &store, &execution).unwrap();
+    let first = store.get_value_speculative(program_id, &mapping_name, &key).unwrap().unwrap();
+
+    // Check that finalizing with the same global state samples the same value.
+    process.finalize_execution(FinalizeGlobalState::from(1, 0, [1u8; 32]), &store, &execution).unwrap();
+    let second = store.get_value_speculative(program_id, &mapping_name, &key).unwrap().unwrap();
+    assert_eq!(first, second);
+
+    // Check that finalizing with a different random seed samples a different value.
+    process.finalize_execution(FinalizeGlobalState::from(1, 0, [2u8; 32]), &store, &execution).unwrap();
+    let third = store.get_value_speculative(program_id, &mapping_name, &key).unwrap().unwrap();
+    assert_ne!(first, third);
+
+    // Execute the function again with the same input, which produces a new transition ID.
+    let r0 = Value::<CurrentNetwork>::from_str("7u8").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0].iter(), rng)
+        .unwrap();
+    let (_, other_execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    process.verify_execution::<true>(&other_execution).unwrap();
+    assert_ne!(execution.peek().unwrap().id(), other_execution.peek().unwrap().id());
+
+    // Check that finalizing with the same global state, but a different transition ID, samples a different value.
+    process.finalize_execution(FinalizeGlobalState::from(1, 0, [1u8; 32]), &store, &other_execution).unwrap();
+    let fourth = store.get_value_speculative(program_id, &mapping_name, &key).unwrap().unwrap();
+    assert_ne!(first, fourth);
+}
+
+#[test]
+fn test_process_execute_and_finalize_get_set_with_struct() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+struct entry:
+    count as u8;
+    data as u8;
+
+mapping entries:
+    key owner as address.public;
+    value entry as entry.public;
+
+function compute:
+    input r0 as u8.public;
+    input r1 as u8.public;
     cast r0 r1 into r2 as entry;
     finalize self.caller r2;
 
@@ -1424,7 +2221,7 @@ finalize compute:
     process.verify_execution::<true>(&execution).unwrap();
 
     // Now, finalize the execution.
-    process.finalize_execution(&store, &execution).unwrap();
+    process.finalize_execution(FinalizeGlobalState::from(0, 0, [0u8; 32]), &store, &execution).unwrap();
 
     // Check that the struct is stored as expected.
     let candidate = store
@@ -1433,3 +2230,190 @@ finalize compute:
         .unwrap();
     assert_eq!(candidate, Value::from_str("{ count: 3u8, data: 6u8 }").unwrap());
 }
+
+#[test]
+fn test_process_execute_array() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program array.aleo;
+
+  function select:
+    input r0 as [u8; 4].private;
+    cast r0[1] r0[3] into r1 as [u8; 2];
+    add r0[0] r0[2] into r2;
+    output r1 as [u8; 2].private;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("select").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input value.
+    let input = Value::<CurrentNetwork>::from_str("[ 1u8, 2u8, 3u8, 4u8 ]").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [input].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Declare the output values.
+    let r1 = Value::from_str("[ 2u8, 4u8 ]").unwrap();
+    let r2 = Value::from_str("4u8").unwrap();
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(r1, candidate[0]);
+    assert_eq!(r2, candidate[1]);
+
+    // Execute the request.
+    let (response, execution, _inclusion, _metrics) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(r1, candidate[0]);
+    assert_eq!(r2, candidate[1]);
+
+    process.verify_execution::<true>(&execution).unwrap();
+}
+
+#[test]
+fn test_process_array_index_out_of_bounds() {
+    // Ensure a constant index beyond the array length is rejected when the program is added.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program array_oob.aleo;
+
+  function select:
+    input r0 as [u8; 4].private;
+    add r0[4] r0[0] into r1;
+    output r1 as u8.private;",
+    )
+    .unwrap();
+
+    // Initialize a new process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_lint() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program lint.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+closure unused:
+    input r0 as field;
+    double r0 into r1;
+    output r1 as field;
+
+function burn:
+    input r0 as token.record;
+    input r1 as u64.private;
+    input r2 as field.private;
+    add r0.amount r1 into r3;
+    hash.psd2 r2 into r4;
+    output r3 as u64.public;
+    finalize r0.owner r1;
+
+finalize burn:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+
+function airdrop:
+    input r0 as u64.public;
+    cast aleo1qr2ha4pfs5l28aze88yn6fhleeythklkczrule2v838uwj65n5gqxt9djx r0 into r1 as token.record;
+    output r1 as token.record;
+",
+    )
+    .unwrap();
+
+    // Construct the process, without 'credits.aleo'.
+    let mut process =
+        Process::<CurrentNetwork> { universal_srs: Arc::new(UniversalSRS::load().unwrap()), stacks: IndexMap::new() };
+    process.add_program(&program).unwrap();
+
+    // Lint the program.
+    let lints = process.get_stack(program.id()).unwrap().lint().unwrap();
+    let lints =
+        lints.iter().map(|lint| (lint.kind(), lint.resource().to_string(), lint.location())).collect::<Vec<_>>();
+    assert_eq!(lints, vec![
+        (LintKind::DeadClosure, "unused".to_string(), Location::Resource),
+        (LintKind::UnusedRegister, "burn".to_string(), Location::Instruction(1)),
+        (LintKind::PublicOutputLeak, "burn".to_string(), Location::Output(0)),
+        (LintKind::PrivateFinalizeInput, "burn".to_string(), Location::FinalizeCommand),
+        (LintKind::PrivateFinalizeInput, "burn".to_string(), Location::FinalizeCommand),
+        (LintKind::UnusedRegister, "burn".to_string(), Location::FinalizeInput(1)),
+        (LintKind::UnusedRegister, "burn".to_string(), Location::Command(0)),
+        (LintKind::RecordNotRecreated, "burn".to_string(), Location::Input(0)),
+        (LintKind::ConstantRecordOwner, "airdrop".to_string(), Location::Instruction(0)),
+    ]);
+}
+
+#[test]
+fn test_process_check_program() {
+    // Initialize a new program, with a type error in each function.
+    let source = r"
+program check.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as field.private;
+    add r0 r0 into r2;
+    add r0 r1 into r3;
+    output r3 as u64.private;
+
+function finish:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize finish:
+    input r0 as u64.public;
+    add r0 1field into r1;
+";
+    let program = Program::<CurrentNetwork>::from_str(source).unwrap();
+
+    // Construct a new process.
+    let mut process =
+        Process::<CurrentNetwork> { universal_srs: Arc::new(UniversalSRS::load().unwrap()), stacks: IndexMap::new() };
+
+    // Check the program.
+    let errors = process.check_program(&program);
+    assert_eq!(errors.len(), 2);
+
+    // Ensure each error is located at the offending statement.
+    let source_map = SourceMap::<CurrentNetwork>::new(source);
+    let expected = [("compute", Location::Instruction(1), 8), ("finish", Location::Command(0), 17)];
+    for (error, (resource, location, line)) in errors.iter().zip_eq(expected) {
+        let located = error.downcast_ref::<LocatedError<CurrentNetwork>>().unwrap();
+        assert_eq!(located.resource(), &Identifier::from_str(resource).unwrap());
+        assert_eq!(located.location(), location);
+
+        let diagnostic = Diagnostic::from_error(source, &source_map, error);
+        assert_eq!(diagnostic.span().unwrap().line(), line);
+        assert_eq!(diagnostic.token(), Some("add"));
+    }
+
+    // Ensure the program cannot be added to the process.
+    assert!(process.add_program(&program).is_err());
+}
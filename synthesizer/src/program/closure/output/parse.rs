@@ -14,6 +14,8 @@
 
 use super::*;
 
+use nom::combinator::cut;
+
 impl<N: Network> Parser for Output<N> {
     /// Parses a string into an output statement.
     /// The output statement is of the form `output {operand} as {register_type};`.
@@ -25,22 +27,25 @@ impl<N: Network> Parser for Output<N> {
         let (string, _) = tag(Self::type_name())(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the operand from the string.
-        let (string, operand) = Operand::parse(string)?;
-        // Parse the whitespace from the string.
-        let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the "as" from the string.
-        let (string, _) = tag("as")(string)?;
-        // Parse the whitespace from the string.
-        let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the register type from the string.
-        let (string, register_type) = RegisterType::parse(string)?;
-        // Parse the whitespace from the string.
-        let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the semicolon from the string.
-        let (string, _) = tag(";")(string)?;
-        // Return the output statement.
-        Ok((string, Self { operand, register_type }))
+        // Parse the rest of the statement, without backtracking, as the keyword has been parsed.
+        cut(|string| {
+            // Parse the operand from the string.
+            let (string, operand) = Operand::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "as" from the string.
+            let (string, _) = tag("as")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the register type from the string.
+            let (string, register_type) = RegisterType::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the semicolon from the string.
+            let (string, _) = tag(";")(string)?;
+            // Return the output statement.
+            Ok((string, Self { operand, register_type }))
+        })(string)
     }
 }
 
@@ -0,0 +1,283 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod source_map;
+pub use source_map::*;
+
+use crate::LocatedError;
+use console::network::prelude::*;
+
+/// A range of bytes in the source, along with the line and column at which it starts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The byte offset of the start of the span.
+    start: usize,
+    /// The byte offset of the end of the span.
+    end: usize,
+    /// The line of the start of the span, starting from 1.
+    line: usize,
+    /// The column of the start of the span, in characters, starting from 1.
+    column: usize,
+}
+
+impl Span {
+    /// Initializes a new span for the given byte range of the source.
+    pub fn new(source: &str, start: usize, end: usize) -> Self {
+        // Clamp the range to the source.
+        let start = start.min(source.len());
+        let end = end.clamp(start, source.len());
+        // Determine the line and column of the start of the span.
+        let prefix = &source[..start];
+        let line = prefix.matches('\n').count() + 1;
+        let column = prefix.rsplit('\n').next().map_or(0, |line| line.chars().count()) + 1;
+        Self { start, end, line, column }
+    }
+
+    /// Returns the byte offset of the start of the span.
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset of the end of the span.
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the line of the start of the span, starting from 1.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column of the start of the span, starting from 1.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the text of the span in the given source.
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        source.get(self.start..self.end).unwrap_or_default()
+    }
+
+    /// Returns the first token of the span in the given source, if any.
+    pub fn token<'a>(&self, source: &'a str) -> Option<&'a str> {
+        self.text(source).split(|c: char| c.is_whitespace() || c == ';' || c == ':').find(|token| !token.is_empty())
+    }
+}
+
+/// An error in a program, along with the span of the offending statement, if it is known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The message.
+    message: String,
+    /// The span of the offending statement, starting at the offending token.
+    span: Option<Span>,
+    /// The offending token.
+    token: Option<String>,
+}
+
+impl Diagnostic {
+    /// Initializes a new diagnostic, where the offending token is the first token in the span.
+    pub fn new(source: &str, message: String, span: Option<Span>) -> Self {
+        // Retrieve the first token in the span.
+        let token = span.and_then(|span| span.token(source)).map(ToString::to_string);
+        Self { message, span, token }
+    }
+
+    /// Initializes a new diagnostic for the given error from `Process::check_program`.
+    /// The span is known if the error is a `LocatedError` for a statement in the source map.
+    pub fn from_error<N: Network>(source: &str, source_map: &SourceMap<N>, error: &Error) -> Self {
+        // Retrieve the span of the offending statement.
+        let span = error
+            .downcast_ref::<LocatedError<N>>()
+            .and_then(|located| source_map.get(located.resource(), located.location()));
+        Self::new(source, error.to_string(), span)
+    }
+
+    /// Returns the message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the span of the offending statement, starting at the offending token.
+    pub const fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Returns the offending token.
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// Renders the diagnostic with the offending line of the source, and a caret under the offending token, e.g.
+    /// ```text
+    /// error: Unexpected 'add.x' in function 'compute'
+    ///  --> main.aleo:7:5
+    ///   |
+    /// 7 |     add.x r0 r1 into r2;
+    ///   |     ^^^^^
+    /// ```
+    pub fn render(&self, source: &str, path: &str) -> String {
+        let mut output = format!("error: {}", self.message);
+        if let Some(span) = self.span {
+            // Retrieve the offending line.
+            let line = source.lines().nth(span.line - 1).unwrap_or_default();
+            // Prepare the gutter, which is as wide as the line number.
+            let number = span.line.to_string();
+            let gutter = " ".repeat(number.len());
+            // Align the caret with the offending token, preserving any tabs in the line.
+            let indent = line
+                .chars()
+                .take(span.column - 1)
+                .map(|c| match c {
+                    '\t' => '\t',
+                    _ => ' ',
+                })
+                .collect::<String>();
+            let width = self.token.as_ref().map_or(1, |token| token.chars().count().max(1));
+            let caret = "^".repeat(width);
+
+            output.push_str(&format!("\n{gutter}--> {path}:{}:{}", span.line, span.column));
+            output.push_str(&format!("\n{gutter} |\n{number} | {line}\n{gutter} | {indent}{caret}"));
+        }
+        output
+    }
+}
+
+impl Display for Diagnostic {
+    /// Prints the diagnostic, e.g. `7:5: Unexpected 'add.x' in function 'compute'`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.span {
+            Some(span) => write!(f, "{}:{}: {}", span.line, span.column, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// The diagnostics collected from a program, in source order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diagnostics(Vec<Diagnostic>);
+
+impl Diagnostics {
+    /// Adds the given diagnostic.
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        self.0.push(diagnostic);
+    }
+
+    /// Returns `true` if there are no diagnostics.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of diagnostics.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the diagnostics.
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.0.iter()
+    }
+
+    /// Renders each diagnostic with the offending line of the source, followed by the number of errors.
+    pub fn render(&self, source: &str, path: &str) -> String {
+        let mut output = self.0.iter().map(|diagnostic| diagnostic.render(source, path)).collect::<Vec<_>>();
+        output.push(format!("Found {} error(s) in '{path}'", self.0.len()));
+        output.join("\n\n")
+    }
+}
+
+impl FromIterator<Diagnostic> for Diagnostics {
+    /// Collects the diagnostics, and sorts them in source order.
+    fn from_iter<I: IntoIterator<Item = Diagnostic>>(iter: I) -> Self {
+        let mut diagnostics = iter.into_iter().collect::<Vec<_>>();
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span.map(|span| span.start));
+        Self(diagnostics)
+    }
+}
+
+impl Display for Diagnostics {
+    /// Prints each diagnostic on its own line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.iter().map(ToString::to_string).join("\n"))
+    }
+}
+
+impl std::error::Error for Diagnostics {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// A program with a syntax error in each of its two functions.
+    const SOURCE: &str = "program diagnostics.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second r0 into r1;
+    output r1 as field.private;
+
+function bad:
+    input r0 as u64.private;
+    output r0 as u64.privat;
+";
+
+    #[test]
+    fn test_from_source_collects_every_error() {
+        // Parse the program.
+        let diagnostics = Program::<CurrentNetwork>::from_source(SOURCE).unwrap_err();
+        assert_eq!(diagnostics.len(), 2);
+
+        // Ensure each diagnostic points at the offending token.
+        let expected = [(9, 28, "r0", "function 'compute'"), (14, 18, "u64.privat", "function 'bad'")];
+        for (diagnostic, (line, column, token, context)) in diagnostics.iter().zip_eq(expected) {
+            let span = diagnostic.span().unwrap();
+            assert_eq!((span.line(), span.column()), (line, column), "{diagnostic}");
+            assert_eq!(diagnostic.token(), Some(token));
+            assert_eq!(diagnostic.message(), format!("Failed to parse '{token}' in {context}"));
+        }
+    }
+
+    #[test]
+    fn test_from_source_matches_from_str() {
+        // Fix the syntax errors.
+        let source = SOURCE.replace("r0.second r0 into", "r0.second into").replace("u64.privat;", "u64.private;");
+        // Ensure both parsers produce the same program.
+        let expected = Program::<CurrentNetwork>::from_str(&source).unwrap();
+        let candidate = Program::<CurrentNetwork>::from_source(&source).unwrap();
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_render() {
+        // Parse the program.
+        let diagnostics = Program::<CurrentNetwork>::from_source(SOURCE).unwrap_err();
+        // Render the first diagnostic.
+        let expected = "error: Failed to parse 'r0' in function 'compute'
+ --> main.aleo:9:28
+  |
+9 |     add r0.first r0.second r0 into r1;
+  |                            ^^";
+        assert_eq!(diagnostics.iter().next().unwrap().render(SOURCE, "main.aleo"), expected);
+        // Ensure the rendered diagnostics end with a summary.
+        assert!(diagnostics.render(SOURCE, "main.aleo").ends_with("Found 2 error(s) in 'main.aleo'"));
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Location;
+use console::program::Identifier;
+
+use indexmap::IndexMap;
+
+/// The keywords that begin a top-level definition.
+const DEFINITION_KEYWORDS: [&str; 5] = ["mapping", "struct", "record", "closure", "function"];
+
+/// The spans of the statements in a definition.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct DefinitionSpans {
+    /// The span of the header, e.g. `function foo:`.
+    header: Option<Span>,
+    /// The spans of the input statements.
+    inputs: Vec<Span>,
+    /// The spans of the instructions, or the members of a struct, record, or mapping.
+    instructions: Vec<Span>,
+    /// The spans of the output statements.
+    outputs: Vec<Span>,
+    /// The span of the `finalize` command.
+    finalize: Option<Span>,
+    /// The spans of the finalize input statements.
+    finalize_inputs: Vec<Span>,
+    /// The spans of the finalize commands.
+    commands: Vec<Span>,
+}
+
+/// The spans of the statements in the source of a program, derived without parsing the statements.
+/// Each statement ends with a `;`, or with a `:` for a definition header, e.g. `function foo:`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceMap<N: Network> {
+    /// The spans of all statements, in source order.
+    statements: Vec<Span>,
+    /// The keyword and span of each definition header, in source order.
+    headers: Vec<(&'static str, Span)>,
+    /// The spans of the statements in each definition, for the definitions with a valid name.
+    definitions: IndexMap<Identifier<N>, DefinitionSpans>,
+}
+
+impl<N: Network> SourceMap<N> {
+    /// Initializes a new source map for the given source.
+    pub fn new(source: &str) -> Self {
+        // Determine the span of each statement.
+        let statements = scan_statements(source);
+
+        let mut headers = Vec::new();
+        let mut definitions: IndexMap<Identifier<N>, DefinitionSpans> = IndexMap::new();
+        // The definition that the current statement belongs to, and whether the statement is in its finalize scope.
+        let mut current: Option<DefinitionSpans> = None;
+        let mut current_name = None;
+        let mut in_finalize = false;
+
+        for span in statements.iter().copied() {
+            let text = span.text(source);
+            let mut words = text.split_whitespace();
+            let keyword = words.next().unwrap_or_default();
+
+            // If the statement is a definition header, begin a new definition.
+            if text.ends_with(':') {
+                if let Some(keyword) = DEFINITION_KEYWORDS.iter().find(|candidate| **candidate == keyword) {
+                    headers.push((*keyword, span));
+                    // Store the previous definition.
+                    if let (Some(name), Some(spans)) = (current_name.take(), current.take()) {
+                        definitions.insert(name, spans);
+                    }
+                    // Parse the definition name.
+                    let name = words.next().map(|name| name.trim_end_matches(':'));
+                    current_name = name.and_then(|name| Identifier::<N>::from_str(name).ok());
+                    current = Some(DefinitionSpans { header: Some(span), ..Default::default() });
+                    in_finalize = false;
+                    continue;
+                }
+                // Otherwise, the header begins the finalize scope of the current definition.
+                if keyword == "finalize" {
+                    in_finalize = true;
+                    continue;
+                }
+            }
+
+            // Otherwise, add the statement to the current definition.
+            if let Some(spans) = current.as_mut() {
+                match (keyword, in_finalize) {
+                    ("input", false) => spans.inputs.push(span),
+                    ("input", true) => spans.finalize_inputs.push(span),
+                    ("output", _) => spans.outputs.push(span),
+                    ("finalize", false) => spans.finalize = Some(span),
+                    (_, false) => spans.instructions.push(span),
+                    (_, true) => spans.commands.push(span),
+                }
+            }
+        }
+        // Store the last definition.
+        if let (Some(name), Some(spans)) = (current_name, current) {
+            definitions.insert(name, spans);
+        }
+
+        Self { statements, headers, definitions }
+    }
+
+    /// Returns the span of the statement at the given location of the given closure or function.
+    pub fn get(&self, resource: &Identifier<N>, location: Location) -> Option<Span> {
+        let spans = self.definitions.get(resource)?;
+        match location {
+            Location::Resource => spans.header,
+            Location::Input(index) => spans.inputs.get(index).copied(),
+            Location::Instruction(index) => spans.instructions.get(index).copied(),
+            Location::Output(index) => spans.outputs.get(index).copied(),
+            Location::FinalizeCommand => spans.finalize,
+            Location::FinalizeInput(index) => spans.finalize_inputs.get(index).copied(),
+            Location::Command(index) => spans.commands.get(index).copied(),
+        }
+    }
+
+    /// Returns the keyword and span of each definition header, in source order.
+    pub(crate) fn headers(&self) -> &[(&'static str, Span)] {
+        &self.headers
+    }
+
+    /// Returns the span from the given offset to the end of its statement.
+    /// If the offset is between statements, the span of the next statement is returned.
+    pub(crate) fn span_at(&self, source: &str, offset: usize) -> Span {
+        match self.statements.iter().find(|span| offset < span.end()) {
+            Some(span) if span.start() < offset => Span::new(source, offset, span.end()),
+            Some(span) => *span,
+            None => Span::new(source, offset, source.len()),
+        }
+    }
+}
+
+/// Returns the span of each statement in the source, skipping whitespace and comments.
+/// Each statement ends with a `;` or a `:`, and a trailing unterminated statement ends with the source.
+fn scan_statements(source: &str) -> Vec<Span> {
+    let bytes = source.as_bytes();
+    let mut statements = Vec::new();
+    let mut start = None;
+    let mut index = 0;
+    while index < bytes.len() {
+        // Skip line comments.
+        if bytes[index..].starts_with(b"//") {
+            index = source[index..].find('\n').map_or(bytes.len(), |offset| index + offset);
+            continue;
+        }
+        // Skip block comments.
+        if bytes[index..].starts_with(b"/*") {
+            index = source[index + 2..].find("*/").map_or(bytes.len(), |offset| index + 2 + offset + 2);
+            continue;
+        }
+        match bytes[index] {
+            // End the current statement.
+            b';' | b':' => {
+                if let Some(start) = start.take() {
+                    statements.push(Span::new(source, start, index + 1));
+                }
+            }
+            // Begin a new statement.
+            byte if !byte.is_ascii_whitespace() && start.is_none() => start = Some(index),
+            _ => (),
+        }
+        index += 1;
+    }
+    // Add the trailing unterminated statement, if any.
+    if let Some(start) = start {
+        statements.push(Span::new(source, start, source.len()));
+    }
+    statements
+}
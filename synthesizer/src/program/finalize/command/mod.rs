@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod branch;
+pub use branch::*;
+
+mod contains;
+pub use contains::*;
+
 mod finalize;
 pub use finalize::*;
 
@@ -21,11 +27,28 @@ pub use get::*;
 mod get_or_init;
 pub use get_or_init::*;
 
+mod position;
+pub use position::*;
+
+mod rand_chacha;
+pub use rand_chacha::*;
+
+mod remove;
+pub use remove::*;
+
 mod set;
 pub use set::*;
 
-use crate::{program::Instruction, FinalizeOperation, FinalizeRegisters, FinalizeStorage, FinalizeStore, Stack};
-use console::network::prelude::*;
+use crate::{
+    program::Instruction,
+    FinalizeOperation,
+    FinalizeRegisters,
+    FinalizeStorage,
+    FinalizeStore,
+    Operand,
+    Stack,
+};
+use console::{network::prelude::*, program::Register};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Command<N: Network> {
@@ -38,6 +61,51 @@ pub enum Command<N: Network> {
     GetOrInit(GetOrInit<N>),
     /// Sets the value stored at the `key` operand in the `mapping` to `value`.
     Set(Set<N>),
+    /// Checks whether the `key` operand is present in `mapping` and stores the result into `destination`.
+    Contains(Contains<N>),
+    /// Removes the entry stored at the `key` operand in `mapping`, if it exists.
+    Remove(Remove<N>),
+    /// Jumps to `position`, if `first` equals `second`.
+    BranchEq(BranchEq<N>),
+    /// Jumps to `position`, if `first` does **not** equal `second`.
+    BranchNeq(BranchNeq<N>),
+    /// Declares a `position` that a preceding branch may jump to.
+    Position(Position<N>),
+    /// Samples a random literal of the `destination_type` and stores the result into `destination`.
+    RandChaCha(RandChaCha<N>),
+}
+
+impl<N: Network> Command<N> {
+    /// Returns the operands of the command.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        match self {
+            Command::Instruction(instruction) => instruction.operands().to_vec(),
+            Command::Get(get) => get.operands(),
+            Command::GetOrInit(get_or_init) => get_or_init.operands(),
+            Command::Set(set) => set.operands(),
+            Command::Contains(contains) => contains.operands(),
+            Command::Remove(remove) => remove.operands(),
+            Command::BranchEq(branch_eq) => branch_eq.operands(),
+            Command::BranchNeq(branch_neq) => branch_neq.operands(),
+            Command::Position(_) => vec![],
+            Command::RandChaCha(rand_chacha) => rand_chacha.operands(),
+        }
+    }
+
+    /// Returns the destination registers of the command.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        match self {
+            Command::Instruction(instruction) => instruction.destinations(),
+            Command::Get(get) => vec![get.destination().clone()],
+            Command::GetOrInit(get_or_init) => vec![get_or_init.destination().clone()],
+            Command::Contains(contains) => vec![contains.destination().clone()],
+            Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
+            Command::Set(_) | Command::Remove(_) => vec![],
+            Command::BranchEq(_) | Command::BranchNeq(_) | Command::Position(_) => vec![],
+        }
+    }
 }
 
 impl<N: Network> Command<N> {
@@ -58,6 +126,16 @@ impl<N: Network> Command<N> {
             Command::GetOrInit(get_or_init) => get_or_init.finalize(stack, store, registers),
             // Finalize the 'set' command, and return the finalize operation.
             Command::Set(set) => set.finalize(stack, store, registers).map(Some),
+            // Finalize the 'contains' command, and return no finalize operation.
+            Command::Contains(contains) => contains.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'remove' command, and return the (optional) finalize operation.
+            Command::Remove(remove) => remove.finalize(stack, store, registers),
+            // Branches are evaluated by the caller, as they determine the next command to execute.
+            Command::BranchEq(_) | Command::BranchNeq(_) => bail!("Cannot finalize a 'branch' command directly"),
+            // Finalize the 'position' command, and return no finalize operation.
+            Command::Position(_) => Ok(None),
+            // Finalize the 'rand.chacha' command, and return no finalize operation.
+            Command::RandChaCha(rand_chacha) => rand_chacha.finalize(stack, registers).map(|_| None),
         }
     }
 }
@@ -76,8 +154,20 @@ impl<N: Network> FromBytes for Command<N> {
             2 => Ok(Self::GetOrInit(GetOrInit::read_le(&mut reader)?)),
             // Read the `set` operation.
             3 => Ok(Self::Set(Set::read_le(&mut reader)?)),
+            // Read the `contains` operation.
+            4 => Ok(Self::Contains(Contains::read_le(&mut reader)?)),
+            // Read the `remove` operation.
+            5 => Ok(Self::Remove(Remove::read_le(&mut reader)?)),
+            // Read the `branch.eq` operation.
+            6 => Ok(Self::BranchEq(BranchEq::read_le(&mut reader)?)),
+            // Read the `branch.neq` operation.
+            7 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
+            // Read the `position` operation.
+            8 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `rand.chacha` operation.
+            9 => Ok(Self::RandChaCha(RandChaCha::read_le(&mut reader)?)),
             // Invalid variant.
-            4.. => Err(error(format!("Invalid command variant: {variant}"))),
+            10.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -110,6 +200,42 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the set.
                 set.write_le(&mut writer)
             }
+            Self::Contains(contains) => {
+                // Write the variant.
+                4u8.write_le(&mut writer)?;
+                // Write the `contains` operation.
+                contains.write_le(&mut writer)
+            }
+            Self::Remove(remove) => {
+                // Write the variant.
+                5u8.write_le(&mut writer)?;
+                // Write the `remove` operation.
+                remove.write_le(&mut writer)
+            }
+            Self::BranchEq(branch_eq) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the `branch.eq` operation.
+                branch_eq.write_le(&mut writer)
+            }
+            Self::BranchNeq(branch_neq) => {
+                // Write the variant.
+                7u8.write_le(&mut writer)?;
+                // Write the `branch.neq` operation.
+                branch_neq.write_le(&mut writer)
+            }
+            Self::Position(position) => {
+                // Write the variant.
+                8u8.write_le(&mut writer)?;
+                // Write the `position` operation.
+                position.write_le(&mut writer)
+            }
+            Self::RandChaCha(rand_chacha) => {
+                // Write the variant.
+                9u8.write_le(&mut writer)?;
+                // Write the `rand.chacha` operation.
+                rand_chacha.write_le(&mut writer)
+            }
         }
     }
 }
@@ -124,6 +250,12 @@ impl<N: Network> Parser for Command<N> {
             map(GetOrInit::parse, |get_or_init| Self::GetOrInit(get_or_init)),
             map(Get::parse, |get| Self::Get(get)),
             map(Set::parse, |set| Self::Set(set)),
+            map(Contains::parse, |contains| Self::Contains(contains)),
+            map(Remove::parse, |remove| Self::Remove(remove)),
+            map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
+            map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
+            map(Position::parse, |position| Self::Position(position)),
+            map(RandChaCha::parse, |rand_chacha| Self::RandChaCha(rand_chacha)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
         ))(string)
     }
@@ -162,6 +294,12 @@ impl<N: Network> Display for Command<N> {
             Self::Get(get) => Display::fmt(get, f),
             Self::GetOrInit(get_or_init) => Display::fmt(get_or_init, f),
             Self::Set(set) => Display::fmt(set, f),
+            Self::Contains(contains) => Display::fmt(contains, f),
+            Self::Remove(remove) => Display::fmt(remove, f),
+            Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
+            Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
+            Self::Position(position) => Display::fmt(position, f),
+            Self::RandChaCha(rand_chacha) => Display::fmt(rand_chacha, f),
         }
     }
 }
@@ -206,6 +344,42 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Contains
+        let expected = "contains object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchNeq
+        let expected = "branch.neq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // RandChaCha
+        let expected = "rand.chacha r0 r1 into r2 as u64;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -241,5 +415,46 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Set(Set::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Contains
+        let expected = "contains object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Contains(Contains::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Remove(Remove::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchEq(BranchEq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchNeq
+        let expected = "branch.neq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchNeq(BranchNeq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // RandChaCha
+        let expected = "rand.chacha into r0 as field;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::RandChaCha(RandChaCha::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        let expected = "rand.chacha r0 r1 into r2 as u64;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::RandChaCha(RandChaCha::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FinalizeRegisters, Opcode, Operand, RegistersLoad as LoadTrait, RegistersStore, Stack, StackProgram};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, Register, Value},
+};
+
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+/// The maximum number of additional seeds that may be provided.
+pub const MAX_ADDITIONAL_SEEDS: usize = 2;
+
+/// A random-number-generation command, e.g. `rand.chacha r0 r1 into r2 as u64;`.
+/// Samples a literal of type `destination_type` from a ChaCha RNG, and stores the result in `destination`.
+/// The RNG is seeded from the block-level random seed, the transition ID, and the (optional) additional seeds.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RandChaCha<N: Network> {
+    /// The (optional) additional seeds.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination register type.
+    destination_type: LiteralType,
+}
+
+impl<N: Network> RandChaCha<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("rand.chacha")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        self.operands.clone()
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> LiteralType {
+        self.destination_type
+    }
+}
+
+impl<N: Network> RandChaCha<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        // Ensure the number of additional seeds is within bounds.
+        ensure!(self.operands.len() <= MAX_ADDITIONAL_SEEDS, "Too many additional seeds for '{}'", Self::opcode());
+
+        // Load the additional seeds.
+        let seeds: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+
+        // Construct the preimage, which is comprised of:
+        //   1. the block-level random seed,
+        //   2. the transition ID,
+        //   3. the program ID,
+        //   4. the destination register and destination type,
+        //   5. the additional seeds.
+        let mut preimage = registers.state().random_seed().to_bits_le();
+        preimage.extend((**registers.transition_id()).to_bits_le());
+        preimage.extend(stack.program_id().to_bits_le());
+        preimage.extend(self.destination.locator().to_bits_le());
+        preimage.extend((self.destination_type as u16).to_bits_le());
+        preimage.extend(seeds.iter().flat_map(|seed| seed.to_bits_le()));
+
+        // Hash the preimage to derive the seed for the RNG.
+        let digest = N::hash_bhp1024(&preimage)?.to_bytes_le()?;
+        // Ensure the digest is 32 bytes.
+        let seed = match digest.try_into() {
+            Ok(seed) => seed,
+            Err(_) => bail!("Failed to derive the seed for '{}'", Self::opcode()),
+        };
+
+        // Sample a literal of the destination type.
+        let literal = Literal::sample(self.destination_type, &mut ChaCha20Rng::from_seed(seed));
+
+        // Assign the result to the destination register.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(literal)))
+    }
+}
+
+impl<N: Network> Parser for RandChaCha<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many0(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the number of operands is within the bounds.
+            match operands.len() <= MAX_ADDITIONAL_SEEDS {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'rand.chacha' opcode: too many operands")),
+            }
+        })(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" keyword from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        let (string, destination_type) = LiteralType::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { operands, destination, destination_type }))
+    }
+}
+
+impl<N: Network> FromStr for RandChaCha<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for RandChaCha<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for RandChaCha<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{}", Self::opcode())?;
+        // Print the additional seeds.
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        // Print the destination register and destination type.
+        write!(f, " into {} as {};", self.destination, self.destination_type)
+    }
+}
+
+impl<N: Network> FromBytes for RandChaCha<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within bounds.
+        if num_operands > MAX_ADDITIONAL_SEEDS {
+            return Err(error(format!("The number of operands must be <= {MAX_ADDITIONAL_SEEDS}")));
+        }
+        // Read the operands.
+        let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<Result<_, _>>()?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination register type.
+        let destination_type = LiteralType::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { operands, destination, destination_type })
+    }
+}
+
+impl<N: Network> ToBytes for RandChaCha<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within bounds.
+        if self.operands.len() > MAX_ADDITIONAL_SEEDS {
+            return Err(error(format!("The number of operands must be <= {MAX_ADDITIONAL_SEEDS}")));
+        }
+        // Write the number of operands.
+        (self.operands.len() as u8).write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination register type.
+        self.destination_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, rand) = RandChaCha::<CurrentNetwork>::parse("rand.chacha into r0 as u64;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(rand.operands().len(), 0, "The number of operands is incorrect");
+        assert_eq!(rand.destination, Register::Locator(0), "The destination register is incorrect");
+        assert_eq!(rand.destination_type, LiteralType::U64, "The destination type is incorrect");
+
+        let (string, rand) = RandChaCha::<CurrentNetwork>::parse("rand.chacha r0 r1 into r2 as field;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(rand.operands().len(), 2, "The number of operands is incorrect");
+        assert_eq!(rand.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(rand.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(rand.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(rand.destination_type, LiteralType::Field, "The destination type is incorrect");
+
+        // Ensure too many operands are rejected.
+        assert!(RandChaCha::<CurrentNetwork>::parse("rand.chacha r0 r1 r2 into r3 as u8;").is_err());
+        // Ensure a missing destination type is rejected.
+        assert!(RandChaCha::<CurrentNetwork>::parse("rand.chacha r0 into r1;").is_err());
+    }
+}
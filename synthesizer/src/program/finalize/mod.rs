@@ -27,7 +27,7 @@ use console::{
     program::{Identifier, PlaintextType, Register, RegisterType},
 };
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Finalize<N: Network> {
@@ -38,12 +38,14 @@ pub struct Finalize<N: Network> {
     inputs: IndexSet<Input<N>>,
     /// The commands, in order of execution.
     commands: Vec<Command<N>>,
+    /// The mapping of position names to their command index.
+    positions: IndexMap<Identifier<N>, usize>,
 }
 
 impl<N: Network> Finalize<N> {
     /// Initializes a new finalize with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), commands: Vec::new() }
+        Self { name, inputs: IndexSet::new(), commands: Vec::new(), positions: IndexMap::new() }
     }
 
     /// Returns the name of the associated function.
@@ -58,13 +60,18 @@ impl<N: Network> Finalize<N> {
 
     /// Returns the finalize input types.
     pub fn input_types(&self) -> Vec<PlaintextType<N>> {
-        self.inputs.iter().map(|input| *input.plaintext_type()).collect()
+        self.inputs.iter().map(|input| input.plaintext_type().clone()).collect()
     }
 
     /// Returns the finalize commands.
     pub fn commands(&self) -> &[Command<N>] {
         &self.commands
     }
+
+    /// Returns the mapping of position names to their command index.
+    pub const fn positions(&self) -> &IndexMap<Identifier<N>, usize> {
+        &self.positions
+    }
 }
 
 impl<N: Network> Finalize<N> {
@@ -96,12 +103,14 @@ impl<N: Network> Finalize<N> {
     ///
     /// # Errors
     /// This method will halt if the maximum number of commands has been reached.
+    /// This method will halt if a branch jumps to a previously declared position.
+    /// This method will halt if a position was previously declared.
     #[inline]
     pub fn add_command(&mut self, command: Command<N>) -> Result<()> {
         // Ensure the maximum number of commands has not been exceeded.
         ensure!(self.commands.len() < N::MAX_COMMANDS, "Cannot add more than {} commands", N::MAX_COMMANDS);
 
-        // If the command is an instruction, `get` command, or `get.or_init` command, perform additional checks.
+        // If the command is an instruction, `get` command, `get.or_init` command, `contains` command, or `rand.chacha` command, perform additional checks.
         match &command {
             Command::Instruction(instruction) => {
                 match instruction {
@@ -129,7 +138,47 @@ impl<N: Network> Finalize<N> {
                     "Destination register must be a locator"
                 );
             }
-            Command::Set(_) => {}
+            Command::Contains(contains) => {
+                // Ensure the destination register is a locator.
+                ensure!(
+                    matches!(contains.destination(), Register::Locator(..)),
+                    "Destination register must be a locator"
+                );
+            }
+            Command::RandChaCha(rand_chacha) => {
+                // Ensure the destination register is a locator.
+                ensure!(
+                    matches!(rand_chacha.destination(), Register::Locator(..)),
+                    "Destination register must be a locator"
+                );
+            }
+            Command::BranchEq(branch_eq) => {
+                // Ensure the branch jumps forward.
+                ensure!(
+                    !self.positions.contains_key(branch_eq.position()),
+                    "Cannot branch to the earlier position '{}'",
+                    branch_eq.position()
+                );
+            }
+            Command::BranchNeq(branch_neq) => {
+                // Ensure the branch jumps forward.
+                ensure!(
+                    !self.positions.contains_key(branch_neq.position()),
+                    "Cannot branch to the earlier position '{}'",
+                    branch_neq.position()
+                );
+            }
+            Command::Position(position) => {
+                // Ensure the position was not previously declared.
+                ensure!(
+                    !self.positions.contains_key(position.name()),
+                    "Cannot redeclare position '{}'",
+                    position.name()
+                );
+                // Insert the position, with the index of the position command.
+                self.positions.insert(*position.name(), self.commands.len());
+            }
+            Command::Set(_) | Command::Remove(_) => {}
         }
 
         // Insert the command.
@@ -136,6 +136,49 @@ finalize foo:
         assert_eq!(1, finalize.commands.len());
     }
 
+    #[test]
+    fn test_finalize_parse_branch() {
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u64.public;
+    branch.eq r0 0u64 to end;
+    add r0 1u64 into r1;
+    branch.neq r1 2u64 to end;
+    position end;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("foo", finalize.name().to_string());
+        assert_eq!(1, finalize.inputs.len());
+        assert_eq!(4, finalize.commands.len());
+        assert_eq!(Some(&3), finalize.positions().get(&Identifier::from_str("end").unwrap()));
+
+        // Ensure a branch cannot jump backwards.
+        assert!(
+            Finalize::<CurrentNetwork>::parse(
+                r"
+finalize foo:
+    input r0 as u64.public;
+    position start;
+    branch.eq r0 0u64 to start;",
+            )
+            .is_err()
+        );
+
+        // Ensure a position cannot be declared twice.
+        assert!(
+            Finalize::<CurrentNetwork>::parse(
+                r"
+finalize foo:
+    input r0 as u64.public;
+    position end;
+    position end;",
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn test_finalize_display() {
         let expected = r"finalize foo:
@@ -0,0 +1,298 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod syntax_tree;
+pub use syntax_tree::*;
+
+use crate::Program;
+use console::network::prelude::*;
+
+/// The style of a formatted program.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FormatStyle {
+    /// The number of spaces that the statements of a definition are indented by.
+    indent: usize,
+    /// The maximum number of consecutive blank lines that are retained.
+    max_blank_lines: usize,
+}
+
+impl FormatStyle {
+    /// Initializes a new format style.
+    pub const fn new(indent: usize, max_blank_lines: usize) -> Self {
+        Self { indent, max_blank_lines }
+    }
+
+    /// Returns the number of spaces that the statements of a definition are indented by.
+    pub const fn indent(&self) -> usize {
+        self.indent
+    }
+
+    /// Returns the maximum number of consecutive blank lines that are retained.
+    pub const fn max_blank_lines(&self) -> usize {
+        self.max_blank_lines
+    }
+}
+
+impl Default for FormatStyle {
+    /// Returns the default style, which indents by 4 spaces and retains up to 1 blank line.
+    fn default() -> Self {
+        Self::new(4, 1)
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Formats the given program source in the default style, retaining its comments.
+    pub fn format(source: &str) -> Result<String> {
+        Self::format_with_style(source, FormatStyle::default())
+    }
+
+    /// Formats the given program source in the given style, retaining its comments.
+    /// Returns an error if the formatted source does not parse back to an identical program.
+    pub fn format_with_style(source: &str, style: FormatStyle) -> Result<String> {
+        // Parse the program, to ensure the source is well-formed.
+        let program = Self::from_str(source)?;
+        // Parse the syntax tree, which retains the comments and whitespace.
+        let tree = SyntaxTree::from_str(source)?;
+
+        // Format the syntax tree.
+        let formatted = tree.format(style);
+
+        // Ensure the formatted source parses back to an identical program.
+        let candidate = Self::from_str(&formatted)?;
+        ensure!(
+            program.to_bytes_le()? == candidate.to_bytes_le()?,
+            "The formatted program '{}' does not match the original program",
+            program.id()
+        );
+        Ok(formatted)
+    }
+}
+
+impl SyntaxTree {
+    /// Formats the syntax tree in the given style.
+    /// Every definition header begins a line with no indentation, and is preceded by a blank line,
+    /// while the statements of a definition are indented, one per line. Comments are retained,
+    /// either at the end of the statement that they trail, or on their own line.
+    pub fn format(&self, style: FormatStyle) -> String {
+        let nodes = self.nodes();
+
+        let mut output = String::new();
+        // The previous comment or statement, if any.
+        let mut previous: Option<&SyntaxNode> = None;
+        // The number of newlines since the previous comment or statement.
+        let mut newlines = 0;
+        // Whether a definition header has been printed, after which statements are indented.
+        let mut in_definition = false;
+
+        for (index, node) in nodes.iter().enumerate() {
+            // Count the newlines in whitespace, which is otherwise discarded.
+            if let SyntaxNode::Whitespace(whitespace) = node {
+                newlines += whitespace.matches('\n').count();
+                continue;
+            }
+
+            // Separate the node from the previous comment or statement.
+            if let Some(previous) = previous {
+                match (newlines, node) {
+                    // Retain a comment that trails the previous node on the same line.
+                    (0, SyntaxNode::Comment(_)) => output.push(' '),
+                    _ => {
+                        // Retain the blank lines, up to the maximum.
+                        let mut blank_lines = newlines.saturating_sub(1).min(style.max_blank_lines());
+                        // Ensure a blank line separates each definition from the previous statement.
+                        if matches!(previous, SyntaxNode::Statement(_)) && self.begins_definition(index) {
+                            blank_lines = blank_lines.max(1);
+                        }
+                        output.push_str(&"\n".repeat(1 + blank_lines));
+                    }
+                }
+            }
+
+            // Indent the node, if it begins a line.
+            if output.is_empty() || output.ends_with('\n') {
+                // Determine whether the node belongs to the statements of a definition.
+                // A comment is indented as the next statement, or else the previous statement.
+                let next = nodes[index..].iter().find(|node| matches!(node, SyntaxNode::Statement(_)));
+                let is_indented = match next {
+                    Some(next) => !next.is_header() && in_definition,
+                    None => in_definition,
+                };
+                if is_indented {
+                    output.push_str(&" ".repeat(style.indent()));
+                }
+            }
+
+            // Print the node.
+            match node {
+                SyntaxNode::Statement(statement) => output.push_str(&normalize_statement(statement)),
+                SyntaxNode::Comment(comment) if comment.starts_with("//") => output.push_str(comment.trim_end()),
+                SyntaxNode::Comment(comment) => output.push_str(comment),
+                SyntaxNode::Whitespace(_) => unreachable!("Whitespace is skipped above"),
+            }
+
+            in_definition |= node.is_header();
+            previous = Some(node);
+            newlines = 0;
+        }
+
+        // End the output with a newline.
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Returns `true` if the node at the given index begins a definition, which is either the definition header,
+    /// or the first of the comments that directly precede the header.
+    fn begins_definition(&self, index: usize) -> bool {
+        for node in &self.nodes()[index..] {
+            match node {
+                SyntaxNode::Statement(_) => return node.is_header(),
+                SyntaxNode::Comment(_) => continue,
+                // A blank line separates the comments from the header.
+                SyntaxNode::Whitespace(whitespace) if whitespace.matches('\n').count() > 1 => return false,
+                SyntaxNode::Whitespace(_) => continue,
+            }
+        }
+        false
+    }
+}
+
+/// Returns the given statement with each run of whitespace replaced by a single space,
+/// and with no whitespace before its terminator. String literals are left unchanged.
+fn normalize_statement(statement: &str) -> String {
+    let mut output = String::with_capacity(statement.len());
+    let mut chars = statement.chars().peekable();
+    // Whether whitespace precedes the next character.
+    let mut is_spaced = false;
+    while let Some(c) = chars.next() {
+        match c {
+            // Skip whitespace and line continuations.
+            c if c.is_whitespace() => is_spaced = true,
+            '\\' if chars.peek() == Some(&'\n') => {
+                chars.next();
+                is_spaced = true;
+            }
+            c => {
+                // Replace the preceding whitespace with a single space.
+                if is_spaced && !output.is_empty() && c != ';' && c != ':' {
+                    output.push(' ');
+                }
+                is_spaced = false;
+                output.push(c);
+                // Copy a string literal as is, up to and including its closing quote.
+                if c == '"' {
+                    while let Some(c) = chars.next() {
+                        output.push(c);
+                        match c {
+                            '\\' => output.extend(chars.next()),
+                            '"' => break,
+                            _ => (),
+                        }
+                    }
+                }
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// An unformatted program, with comments.
+    const SOURCE: &str = r"// A token program.
+import credits.aleo;
+program   format.aleo;
+record token:
+  owner as address.private;   // The owner.
+      amount as u64.private ;
+
+
+
+/* Mints a token. */
+function mint:
+input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1   into r2 as token.record; output r2 as token.record;
+    finalize r1;
+finalize mint:
+    input r0 as u64.public;
+        // Double the amount.
+    add r0 r0 into r1;
+";
+
+    /// The formatted program.
+    const EXPECTED: &str = r"// A token program.
+import credits.aleo;
+program format.aleo;
+
+record token:
+    owner as address.private; // The owner.
+    amount as u64.private;
+
+/* Mints a token. */
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+    finalize r1;
+
+finalize mint:
+    input r0 as u64.public;
+    // Double the amount.
+    add r0 r0 into r1;
+";
+
+    #[test]
+    fn test_syntax_tree_is_lossless() {
+        let tree = SyntaxTree::from_str(SOURCE).unwrap();
+        assert_eq!(tree.to_string(), SOURCE);
+        // Ensure the comments are retained.
+        let comments = tree.nodes().iter().filter(|node| matches!(node, SyntaxNode::Comment(_))).count();
+        assert_eq!(comments, 4);
+    }
+
+    #[test]
+    fn test_format() {
+        // Format the program.
+        let formatted = Program::<CurrentNetwork>::format(SOURCE).unwrap();
+        assert_eq!(formatted, EXPECTED);
+        // Ensure formatting is idempotent.
+        assert_eq!(Program::<CurrentNetwork>::format(&formatted).unwrap(), formatted);
+        // Ensure the formatted program is identical to the original program.
+        let expected = Program::<CurrentNetwork>::from_str(SOURCE).unwrap();
+        let candidate = Program::<CurrentNetwork>::from_str(&formatted).unwrap();
+        assert_eq!(expected.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+    }
+
+    #[test]
+    fn test_format_with_style() {
+        // Format the program with an indent of 2 spaces, and no blank lines.
+        let formatted = Program::<CurrentNetwork>::format_with_style(SOURCE, FormatStyle::new(2, 0)).unwrap();
+        assert!(formatted.contains("\nrecord token:\n  owner as address.private; // The owner.\n"));
+        // Ensure the definitions are still separated by a blank line.
+        assert!(formatted.contains("finalize r1;\n\nfinalize mint:\n"));
+    }
+
+    #[test]
+    fn test_format_fails_on_invalid_program() {
+        assert!(Program::<CurrentNetwork>::format("program format.aleo;\n\nfunction mint:\n    add r0;\n").is_err());
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use nom::{
+    bytes::complete::{is_not, take_until},
+    character::complete::{anychar, multispace1, not_line_ending},
+    combinator::not,
+    multi::{many0_count, many1_count},
+    sequence::{delimited, tuple},
+};
+
+/// A node in the lossless syntax tree of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SyntaxNode {
+    /// A run of whitespace, including any newlines.
+    Whitespace(String),
+    /// A line comment, e.g. `// ...`, or a block comment, e.g. `/* ... */`.
+    Comment(String),
+    /// A statement, including its terminator, e.g. `add r0 r1 into r2;` or `function foo:`.
+    Statement(String),
+}
+
+impl SyntaxNode {
+    /// Returns the source text of the node.
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Whitespace(text) | Self::Comment(text) | Self::Statement(text) => text,
+        }
+    }
+
+    /// Returns `true` if the node is a definition header, e.g. `function foo:` or `finalize foo:`.
+    pub fn is_header(&self) -> bool {
+        matches!(self, Self::Statement(text) if text.ends_with(':'))
+    }
+
+    /// Parses a string into a syntax node.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        alt((
+            map(Self::parse_whitespace, |whitespace| Self::Whitespace(whitespace.to_string())),
+            map(Self::parse_comment, |comment| Self::Comment(comment.to_string())),
+            map(Self::parse_statement, |statement| Self::Statement(statement.to_string())),
+        ))(string)
+    }
+
+    /// Parses a run of whitespace, including line continuations.
+    fn parse_whitespace(string: &str) -> ParserResult<&str> {
+        recognize(many1_count(alt((multispace1, tag("\\\n")))))(string)
+    }
+
+    /// Parses a line comment, excluding the newline, or a block comment.
+    fn parse_comment(string: &str) -> ParserResult<&str> {
+        // Parses a line comment.
+        let line_comment = recognize(pair(tag("//"), not_line_ending));
+        // Parses a block comment.
+        let block_comment = recognize(tuple((tag("/*"), take_until("*/"), tag("*/"))));
+
+        alt((line_comment, block_comment))(string)
+    }
+
+    /// Parses a statement, up to and including its terminating `;` or `:`.
+    fn parse_statement(string: &str) -> ParserResult<&str> {
+        // Parses a string literal, which may contain a terminator.
+        let string_literal = recognize(delimited(
+            char('"'),
+            many0_count(alt((recognize(pair(char('\\'), anychar)), is_not("\"\\")))),
+            char('"'),
+        ));
+        // Parses a `/` that does not begin a comment, e.g. in `token.aleo/mint`.
+        let slash = recognize(terminated(char('/'), not(one_of("/*"))));
+
+        recognize(pair(many1_count(alt((string_literal, is_not(";:/\""), slash))), one_of(";:")))(string)
+    }
+}
+
+/// A lossless syntax tree of a program, which retains the comments and whitespace between statements.
+/// Printing the tree reproduces the source that it was parsed from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxTree {
+    /// The nodes of the tree, in source order.
+    nodes: Vec<SyntaxNode>,
+}
+
+impl SyntaxTree {
+    /// Returns the nodes of the tree, in source order.
+    pub fn nodes(&self) -> &[SyntaxNode] {
+        &self.nodes
+    }
+}
+
+impl Parser for SyntaxTree {
+    /// Parses a string into a syntax tree.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        map(many0(SyntaxNode::parse), |nodes| Self { nodes })(string)
+    }
+}
+
+impl FromStr for SyntaxTree {
+    type Err = Error;
+
+    /// Returns a syntax tree from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Remaining invalid string is: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl Display for SyntaxTree {
+    /// Prints the source of the syntax tree.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.nodes.iter().try_for_each(|node| write!(f, "{}", node.text()))
+    }
+}
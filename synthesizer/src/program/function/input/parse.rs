@@ -14,6 +14,8 @@
 
 use super::*;
 
+use nom::combinator::cut;
+
 impl<N: Network> Parser for Input<N> {
     /// Parses a string into an input statement.
     /// The input statement is of the form `input {register} as {value_type};`.
@@ -28,28 +30,33 @@ impl<N: Network> Parser for Input<N> {
         let (string, _) = tag(Self::type_name())(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the register from the string.
-        let (string, register) = map_res(Register::parse, |register| {
-            // Ensure the register is not a register member.
-            match &register {
-                Register::Locator(..) => Ok(register),
-                Register::Member(..) => Err(error(format!("Input register {register} cannot be a register member"))),
-            }
-        })(string)?;
-        // Parse the whitespace from the string.
-        let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the "as" from the string.
-        let (string, _) = tag("as")(string)?;
-        // Parse the whitespace from the string.
-        let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the value type from the string.
-        let (string, value_type) = ValueType::parse(string)?;
-        // Parse the whitespace from the string.
-        let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the semicolon from the string.
-        let (string, _) = tag(";")(string)?;
-        // Return the input statement.
-        Ok((string, Self { register, value_type }))
+        // Parse the rest of the statement, without backtracking, as the keyword has been parsed.
+        cut(|string| {
+            // Parse the register from the string.
+            let (string, register) = map_res(Register::parse, |register| {
+                // Ensure the register is not a register member.
+                match &register {
+                    Register::Locator(..) => Ok(register),
+                    Register::Access(..) => {
+                        Err(error(format!("Input register {register} cannot be a register access")))
+                    }
+                }
+            })(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "as" from the string.
+            let (string, _) = tag("as")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the value type from the string.
+            let (string, value_type) = ValueType::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the semicolon from the string.
+            let (string, _) = tag(";")(string)?;
+            // Return the input statement.
+            Ok((string, Self { register, value_type }))
+        })(string)
     }
 }
 
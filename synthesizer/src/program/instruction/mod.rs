@@ -169,6 +169,14 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Computes whether `signature` is valid for the given `address` and `message`, storing the outcome in `destination`.
+    SignVerify(SignVerify<N>),
+    /// Performs a Keccak hash, outputting a 256-bit digest.
+    HashKeccak256(HashKeccak256<N>),
+    /// Performs a SHA3 hash, outputting a 256-bit digest.
+    HashSha3_256(HashSha3_256<N>),
+    /// Performs a SHA-256 hash, outputting a 256-bit digest.
+    HashSha256(HashSha256<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -256,6 +264,11 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: New instructions must be appended here, as the opcode index is serialized.
+            SignVerify,
+            HashKeccak256,
+            HashSha3_256,
+            HashSha256,
         }}
     };
     // A variant **without** curly braces:
@@ -416,9 +429,26 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            60,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
     }
+
+    #[test]
+    fn test_opcode_indices() {
+        // Sanity check the serialized opcode indices are unchanged.
+        let index = |opcode: Opcode| Instruction::<CurrentNetwork>::OPCODES.iter().position(|o| *o == opcode);
+        assert_eq!(Some(0), index(Abs::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(28), index(HashPSD8::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(29), index(Inv::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(49), index(ShrWrapped::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(50), index(Square::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(55), index(Xor::<CurrentNetwork>::opcode()));
+        // Ensure new instructions are appended.
+        assert_eq!(Some(56), index(SignVerify::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(57), index(HashKeccak256::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(58), index(HashSha3_256::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(59), index(HashSha256::<CurrentNetwork>::opcode()));
+    }
 }
@@ -35,6 +35,8 @@ pub enum Opcode {
     Is(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
+    /// The opcode is for a signature operation (i.e. `sign.verify`).
+    Sign(&'static str),
 }
 
 impl Deref for Opcode {
@@ -52,6 +54,7 @@ impl Deref for Opcode {
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
+            Opcode::Sign(opcode) => opcode,
         }
     }
 }
@@ -77,6 +80,7 @@ impl Display for Opcode {
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
+            Self::Sign(opcode) => write!(f, "{opcode}"),
         }
     }
 }
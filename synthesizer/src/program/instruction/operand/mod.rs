@@ -33,6 +33,12 @@ pub enum Operand<N: Network> {
     ProgramID(ProgramID<N>),
     /// The operand is the caller address.
     Caller,
+    /// The operand is the block height.
+    /// Note: This operand is only accessible in `finalize`.
+    BlockHeight,
+    /// The operand is the block timestamp.
+    /// Note: This operand is only accessible in `finalize`.
+    BlockTimestamp,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
@@ -24,6 +24,8 @@ impl<N: Network> Parser for Operand<N> {
             // This ensures correctness in the case where a special operand is a prefix of, or could be parsed as, a literal, register, or program ID.
             map(tag("group::GEN"), |_| Self::Literal(Literal::Group(Group::generator()))),
             map(tag("self.caller"), |_| Self::Caller),
+            map(tag("block.height"), |_| Self::BlockHeight),
+            map(tag("block.timestamp"), |_| Self::BlockTimestamp),
             map(Literal::parse, |literal| Self::Literal(literal)),
             map(Register::parse, |register| Self::Register(register)),
             map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
@@ -68,6 +70,10 @@ impl<N: Network> Display for Operand<N> {
             Self::ProgramID(program_id) => Display::fmt(program_id, f),
             // Prints the caller, i.e. self.caller
             Self::Caller => write!(f, "self.caller"),
+            // Prints the block height, i.e. block.height
+            Self::BlockHeight => write!(f, "block.height"),
+            // Prints the block timestamp, i.e. block.timestamp
+            Self::BlockTimestamp => write!(f, "block.timestamp"),
         }
     }
 }
@@ -96,6 +102,12 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(Operand::Caller, operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("block.height").unwrap().1;
+        assert_eq!(Operand::BlockHeight, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(Operand::BlockTimestamp, operand);
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(Operand::Literal(Literal::Group(Group::generator())), operand);
 
@@ -124,6 +136,12 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(format!("{operand}"), "self.caller");
 
+        let operand = Operand::<CurrentNetwork>::parse("block.height").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.height");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.timestamp");
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(
             format!("{operand}"),
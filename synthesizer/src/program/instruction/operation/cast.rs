@@ -27,6 +27,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{
+        ArrayType,
         Entry,
         EntryType,
         Identifier,
@@ -94,10 +95,13 @@ impl<N: Network> Cast<N> {
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
-        match self.register_type {
+        match &self.register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
-                self.cast_to_struct(stack, registers, struct_name, inputs)
+                self.cast_to_struct(stack, registers, *struct_name, inputs)
+            }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                self.cast_to_array(stack, registers, array_type, inputs)
             }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
@@ -106,7 +110,7 @@ impl<N: Network> Cast<N> {
                 }
 
                 // Retrieve the struct and ensure it is defined in the program.
-                let record_type = stack.program().get_record(&record_name)?;
+                let record_type = stack.program().get_record(record_name)?;
 
                 // Ensure that the number of operands is equal to the number of record entries, including the `owner`.
                 if inputs.len() != record_type.entries().len() + 1 {
@@ -136,7 +140,7 @@ impl<N: Network> Cast<N> {
                     inputs.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries())
                 {
                     // Compute the register type.
-                    let register_type = RegisterType::from(ValueType::from(*entry_type));
+                    let register_type = RegisterType::from(ValueType::from(entry_type.clone()));
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match entry {
                         Value::Plaintext(plaintext) => {
@@ -187,7 +191,7 @@ impl<N: Network> Cast<N> {
         let inputs: Vec<_> =
             self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
 
-        match self.register_type {
+        match &self.register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
             RegisterType::Plaintext(PlaintextType::Struct(struct_)) => {
                 // Ensure the operands length is at least the minimum.
@@ -196,7 +200,7 @@ impl<N: Network> Cast<N> {
                 }
 
                 // Retrieve the struct and ensure it is defined in the program.
-                let struct_ = stack.program().get_struct(&struct_)?;
+                let struct_ = stack.program().get_struct(struct_)?;
 
                 // Ensure that the number of operands is equal to the number of struct members.
                 if inputs.len() != struct_.members().len() {
@@ -212,7 +216,7 @@ impl<N: Network> Cast<N> {
                 let mut members = IndexMap::new();
                 for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
                     // Compute the register type.
-                    let register_type = RegisterType::Plaintext(*member_type);
+                    let register_type = RegisterType::Plaintext(member_type.clone());
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         circuit::Value::Plaintext(plaintext) => {
@@ -238,6 +242,45 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure that the number of operands is equal to the length of the array.
+                if inputs.len() != **array_type.length() as usize {
+                    bail!(
+                        "Casting to the array {} requires {} operands, but {} were provided",
+                        array_type,
+                        array_type.length(),
+                        inputs.len()
+                    )
+                }
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type().clone());
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        circuit::Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(
+                                &circuit::Value::Plaintext(plaintext.clone()).eject_value(),
+                                &register_type,
+                            )?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        circuit::Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = circuit::Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -245,7 +288,7 @@ impl<N: Network> Cast<N> {
                 }
 
                 // Retrieve the struct and ensure it is defined in the program.
-                let record_type = stack.program().get_record(&record_name)?;
+                let record_type = stack.program().get_record(record_name)?;
 
                 // Ensure that the number of operands is equal to the number of record entries, including the `owner`.
                 if inputs.len() != record_type.entries().len() + 1 {
@@ -278,7 +321,7 @@ impl<N: Network> Cast<N> {
                     inputs.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries())
                 {
                     // Compute the register type.
-                    let register_type = RegisterType::from(ValueType::from(*entry_type));
+                    let register_type = RegisterType::from(ValueType::from(entry_type.clone()));
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match entry {
                         circuit::Value::Plaintext(plaintext) => {
@@ -331,10 +374,13 @@ impl<N: Network> Cast<N> {
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
-        match self.register_type {
+        match &self.register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
-                self.cast_to_struct(stack, registers, struct_name, inputs)
+                self.cast_to_struct(stack, registers, *struct_name, inputs)
+            }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                self.cast_to_array(stack, registers, array_type, inputs)
             }
             RegisterType::Record(_record_name) => {
                 bail!("Illegal operation: Cannot cast to a record in a finalize block.")
@@ -362,11 +408,11 @@ impl<N: Network> Cast<N> {
         );
 
         // Ensure the output type is defined in the program.
-        match self.register_type {
+        match &self.register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
-                let struct_ = stack.program().get_struct(&struct_name)?;
+                let struct_ = stack.program().get_struct(struct_name)?;
                 // Ensure that the input types length is at least the minimum.
                 ensure!(
                     input_types.len() >= N::MIN_STRUCT_ENTRIES,
@@ -402,9 +448,40 @@ impl<N: Network> Cast<N> {
                     }
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure that the number of input types is equal to the length of the array.
+                ensure!(
+                    input_types.len() == **array_type.length() as usize,
+                    "Casting to the array {} requires {} operands, but {} were provided",
+                    array_type,
+                    array_type.length(),
+                    input_types.len()
+                );
+                // Ensure the input types match the element type.
+                let element_type = array_type.element_type();
+                for input_type in input_types {
+                    match input_type {
+                        // Ensure the plaintext type matches the element type.
+                        RegisterType::Plaintext(plaintext_type) => {
+                            ensure!(
+                                element_type == plaintext_type,
+                                "Array '{array_type}' element type mismatch: expected '{element_type}', found '{plaintext_type}'"
+                            )
+                        }
+                        // Ensure the input type cannot be a record (this is unsupported behavior).
+                        RegisterType::Record(record_name) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{element_type}', found record '{record_name}'"
+                        ),
+                        // Ensure the input type cannot be an external record (this is unsupported behavior).
+                        RegisterType::ExternalRecord(locator) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{element_type}', found external record '{locator}'"
+                        ),
+                    }
+                }
+            }
             RegisterType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
-                let record = stack.program().get_record(&record_name)?;
+                let record = stack.program().get_record(record_name)?;
 
                 // Ensure the input types length is at least the minimum.
                 ensure!(
@@ -458,7 +535,7 @@ impl<N: Network> Cast<N> {
             }
         }
 
-        Ok(vec![self.register_type])
+        Ok(vec![self.register_type.clone()])
     }
 }
 
@@ -512,6 +589,48 @@ impl<N: Network> Cast<N> {
         // Store the struct.
         registers.store(stack, &self.destination, Value::Plaintext(struct_))
     }
+
+    /// A helper method to handle casting to an array.
+    fn cast_to_array(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersStore<N>,
+        array_type: &ArrayType<N>,
+        inputs: Vec<Value<N>>,
+    ) -> Result<()> {
+        // Ensure that the number of operands is equal to the length of the array.
+        if inputs.len() != **array_type.length() as usize {
+            bail!(
+                "Casting to the array {} requires {} operands, but {} were provided",
+                array_type,
+                array_type.length(),
+                inputs.len()
+            )
+        }
+
+        // Initialize the array elements.
+        let mut elements = Vec::with_capacity(inputs.len());
+        for element in inputs.into_iter() {
+            // Retrieve the plaintext value from the element.
+            let plaintext = match element {
+                Value::Plaintext(plaintext) => {
+                    // Ensure the plaintext matches the element type.
+                    stack.matches_plaintext(&plaintext, array_type.element_type())?;
+                    // Output the plaintext.
+                    plaintext
+                }
+                // Ensure the array element is not a record.
+                Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+            };
+            // Append the element to the array elements.
+            elements.push(plaintext);
+        }
+
+        // Construct the array.
+        let array = Plaintext::Array(elements, Default::default());
+        // Store the array.
+        registers.store(stack, &self.destination, Value::Plaintext(array))
+    }
 }
 
 impl<N: Network> Parser for Cast<N> {
@@ -672,7 +791,11 @@ impl<N: Network> ToBytes for Cast<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{network::Testnet3, program::Identifier};
+    use console::{
+        network::Testnet3,
+        program::{Access, Identifier},
+        types::U32,
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -684,12 +807,12 @@ mod tests {
         assert_eq!(cast.operands.len(), 2, "The number of operands is incorrect");
         assert_eq!(
             cast.operands[0],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("owner").unwrap()])),
+            Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("owner").unwrap())])),
             "The first operand is incorrect"
         );
         assert_eq!(
             cast.operands[1],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("token_amount").unwrap()])),
+            Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("token_amount").unwrap())])),
             "The second operand is incorrect"
         );
         assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
@@ -700,6 +823,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_cast_into_array() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0[1] r0[0] into r1 as [u8; 2]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(
+            cast.operands[0],
+            Operand::Register(Register::Access(0, vec![Access::Index(U32::new(1))])),
+            "The first operand is incorrect"
+        );
+        assert_eq!(
+            cast.operands[1],
+            Operand::Register(Register::Access(0, vec![Access::Index(U32::new(0))])),
+            "The second operand is incorrect"
+        );
+        assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Array(
+                ArrayType::new(PlaintextType::Literal(LiteralType::U8), 2).unwrap()
+            )),
+            "The value type is incorrect"
+        );
+        assert_eq!(cast.to_string(), "cast r0[1] r0[0] into r1 as [u8; 2]");
+    }
+
     #[test]
     fn test_parse_cast_into_plaintext_max_operands() {
         let mut string = "cast ".to_string();
@@ -24,7 +24,8 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U8,
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -48,6 +49,13 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::PSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::PSD8 as u8 }>;
 
+/// Keccak256 is a cryptographic hash function that outputs a 256-bit digest, as used in Ethereum.
+pub type HashKeccak256<N> = HashInstruction<N, { Hasher::Keccak256 as u8 }>;
+/// SHA3-256 is a cryptographic hash function that outputs a 256-bit digest, as standardized in FIPS 202.
+pub type HashSha3_256<N> = HashInstruction<N, { Hasher::Sha3_256 as u8 }>;
+/// SHA-256 is a cryptographic hash function that outputs a 256-bit digest, as standardized in FIPS 180-4.
+pub type HashSha256<N> = HashInstruction<N, { Hasher::Sha256 as u8 }>;
+
 enum Hasher {
     BHP256,
     BHP512,
@@ -58,9 +66,14 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    Keccak256,
+    Sha3_256,
+    Sha256,
 }
 
 /// Hashes the operand into the declared type.
+/// Note: The 256-bit digests (i.e. `hash.keccak256`, `hash.sha3_256`, and `hash.sha256`) are output as a `[u8; 32u32]`,
+/// in the byte order of the digest.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HashInstruction<N: Network, const VARIANT: u8> {
     /// The operand as `input`.
@@ -83,6 +96,9 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash.keccak256"),
+            10 => Opcode::Hash("hash.sha3_256"),
+            11 => Opcode::Hash("hash.sha256"),
             _ => panic!("Invalid 'hash' instruction opcode"),
         }
     }
@@ -119,19 +135,22 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         let input = registers.load(stack, &self.operands[0])?;
         // Hash the input.
         let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
+            0 => Plaintext::from(Literal::Field(N::hash_bhp256(&input.to_bits_le())?)),
+            1 => Plaintext::from(Literal::Field(N::hash_bhp512(&input.to_bits_le())?)),
+            2 => Plaintext::from(Literal::Field(N::hash_bhp768(&input.to_bits_le())?)),
+            3 => Plaintext::from(Literal::Field(N::hash_bhp1024(&input.to_bits_le())?)),
+            4 => Plaintext::from(Literal::Field(N::hash_ped64(&input.to_bits_le())?)),
+            5 => Plaintext::from(Literal::Field(N::hash_ped128(&input.to_bits_le())?)),
+            6 => Plaintext::from(Literal::Field(N::hash_psd2(&input.to_fields()?)?)),
+            7 => Plaintext::from(Literal::Field(N::hash_psd4(&input.to_fields()?)?)),
+            8 => Plaintext::from(Literal::Field(N::hash_psd8(&input.to_fields()?)?)),
+            9 => Self::digest_to_plaintext(&N::hash_keccak256(&input.to_bits_le())?)?,
+            10 => Self::digest_to_plaintext(&N::hash_sha3_256(&input.to_bits_le())?)?,
+            11 => Self::digest_to_plaintext(&N::hash_sha256(&input.to_bits_le())?)?,
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
         // Store the output.
-        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(output))))
+        registers.store(stack, &self.destination, Value::Plaintext(output))
     }
 
     /// Executes the instruction.
@@ -151,22 +170,49 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         let input = registers.load_circuit(stack, &self.operands[0])?;
         // Hash the input.
         let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
+            0 => circuit::Plaintext::Literal(
+                circuit::Literal::Field(A::hash_bhp256(&input.to_bits_le())),
+                Default::default(),
+            ),
+            1 => circuit::Plaintext::Literal(
+                circuit::Literal::Field(A::hash_bhp512(&input.to_bits_le())),
+                Default::default(),
+            ),
+            2 => circuit::Plaintext::Literal(
+                circuit::Literal::Field(A::hash_bhp768(&input.to_bits_le())),
+                Default::default(),
+            ),
+            3 => circuit::Plaintext::Literal(
+                circuit::Literal::Field(A::hash_bhp1024(&input.to_bits_le())),
+                Default::default(),
+            ),
+            4 => circuit::Plaintext::Literal(
+                circuit::Literal::Field(A::hash_ped64(&input.to_bits_le())),
+                Default::default(),
+            ),
+            5 => circuit::Plaintext::Literal(
+                circuit::Literal::Field(A::hash_ped128(&input.to_bits_le())),
+                Default::default(),
+            ),
+            6 => circuit::Plaintext::Literal(
+                circuit::Literal::Field(A::hash_psd2(&input.to_fields())),
+                Default::default(),
+            ),
+            7 => circuit::Plaintext::Literal(
+                circuit::Literal::Field(A::hash_psd4(&input.to_fields())),
+                Default::default(),
+            ),
+            8 => circuit::Plaintext::Literal(
+                circuit::Literal::Field(A::hash_psd8(&input.to_fields())),
+                Default::default(),
+            ),
+            9 => Self::digest_to_circuit_plaintext(&A::hash_keccak256(&input.to_bits_le())),
+            10 => Self::digest_to_circuit_plaintext(&A::hash_sha3_256(&input.to_bits_le())),
+            11 => Self::digest_to_circuit_plaintext(&A::hash_sha256(&input.to_bits_le())),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
-        // Convert the output to a stack value.
-        let output =
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
         // Store the output.
-        registers.store_circuit(stack, &self.destination, output)
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
     }
 
     /// Finalizes the instruction.
@@ -201,11 +247,39 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
                 Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
             }
+            9..=11 => {
+                let array_type = ArrayType::new(PlaintextType::Literal(LiteralType::U8), 32)?;
+                Ok(vec![RegisterType::Plaintext(PlaintextType::Array(array_type))])
+            }
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
 }
 
+impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
+    /// Returns the given digest as an array of `u8` literals, in the byte order of the digest.
+    fn digest_to_plaintext(digest: &[bool]) -> Result<Plaintext<N>> {
+        let bytes = digest
+            .chunks(8)
+            .map(|bits| Ok(Plaintext::from(Literal::U8(U8::from_bits_le(bits)?))))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Plaintext::Array(bytes, Default::default()))
+    }
+
+    /// Returns the given digest as an array of `u8` literals, in the byte order of the digest.
+    fn digest_to_circuit_plaintext<A: circuit::Aleo<Network = N>>(
+        digest: &[circuit::Boolean<A>],
+    ) -> circuit::Plaintext<A> {
+        use circuit::FromBits;
+
+        let bytes = digest
+            .chunks(8)
+            .map(|bits| circuit::Plaintext::from(circuit::Literal::U8(circuit::U8::from_bits_le(bits))))
+            .collect();
+        circuit::Plaintext::Array(bytes, Default::default())
+    }
+}
+
 impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
@@ -426,6 +500,9 @@ mod tests {
 
     macro_rules! test_hash {
         ($name: tt, $hash:ident) => {
+            test_hash!($name, $hash, ITERATIONS);
+        };
+        ($name: tt, $hash:ident, $iterations:expr) => {
             paste::paste! {
                 #[test]
                 fn [<test _ $name _ is _ consistent>]() {
@@ -443,7 +520,7 @@ mod tests {
                     // Prepare the key cache.
                     let mut cache = Default::default();
 
-                    for _ in 0..ITERATIONS {
+                    for _ in 0..$iterations {
                         let literals = crate::sample_literals!(CurrentNetwork, &mut rng);
                         for literal in literals.iter() {
                             for mode in modes.iter() {
@@ -463,6 +540,10 @@ mod tests {
     test_hash!(hash_psd2, HashPSD2);
     test_hash!(hash_psd4, HashPSD4);
     test_hash!(hash_psd8, HashPSD8);
+    // Note: The 256-bit digests are sampled over fewer iterations, as their circuits are larger.
+    test_hash!(hash_keccak256, HashKeccak256, 5);
+    test_hash!(hash_sha3_256, HashSha3_256, 5);
+    test_hash!(hash_sha256, HashSha256, 5);
 
     // Note this test must be explicitly written, instead of using the macro, because HashPED64 fails on certain input types.
     #[test]
@@ -543,5 +624,11 @@ mod tests {
         assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, hash) = HashKeccak256::<CurrentNetwork>::parse("hash.keccak256 r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
     }
 }
@@ -356,6 +356,7 @@ mod tests {
                 console::program::Literal::U128(console::types::U128::rand($rng)),
                 console::program::Literal::Scalar(console::types::Scalar::rand($rng)),
                 console::program::Literal::String(console::types::StringType::rand($rng)),
+                console::program::Literal::sample(console::program::LiteralType::Signature, $rng),
             ]
         };
     }
@@ -33,6 +33,9 @@ pub use is::*;
 mod literals;
 pub use literals::*;
 
+mod sign_verify;
+pub use sign_verify::*;
+
 mod macros;
 
 use crate::Opcode;
@@ -774,6 +777,7 @@ pub(crate) mod test_helpers {
     use crate::{
         Authorization,
         CallStack,
+        FinalizeGlobalState,
         FinalizeRegisters,
         Registers,
         RegistersStore,
@@ -830,8 +834,11 @@ pub(crate) mod test_helpers {
         literals: &[&Literal<CurrentNetwork>],
     ) -> Result<FinalizeRegisters<CurrentNetwork>> {
         // Initialize the registers.
-        let mut finalize_registers =
-            FinalizeRegisters::<CurrentNetwork>::new(stack.get_finalize_types(function_name)?.clone());
+        let mut finalize_registers = FinalizeRegisters::<CurrentNetwork>::new(
+            FinalizeGlobalState::from(0, 0, [0u8; 32]),
+            Default::default(),
+            stack.get_finalize_types(function_name)?.clone(),
+        );
 
         // For each literal,
         for (index, literal) in literals.iter().enumerate() {
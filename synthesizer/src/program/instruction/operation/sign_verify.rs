@@ -0,0 +1,457 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    Opcode,
+    Operand,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    StackMatches,
+    StackProgram,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Computes whether `signature` is valid for the given `address` and `message`, storing the outcome in `destination`.
+/// Note: The `message` is signed over its field element encoding (i.e. `message.to_fields()`).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SignVerify<N: Network> {
+    /// The operands, as `(signature, address, message)`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Sign("sign.verify")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly three inputs.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the signature.
+        let signature = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Signature(signature) => signature,
+            _ => bail!("Invalid signature type for the '{}' evaluation, expected a signature", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Address(address) => address,
+            _ => bail!("Invalid address type for the '{}' evaluation, expected an address", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = registers.load(stack, &self.operands[2])?.to_fields()?;
+        // Ensure the message does not exceed the maximum size.
+        ensure!(
+            message.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize,
+            "Instruction '{}' cannot verify a message exceeding {} field elements",
+            Self::opcode(),
+            N::MAX_DATA_SIZE_IN_FIELDS
+        );
+
+        // Verify the signature.
+        let output = Literal::Boolean(Boolean::new(signature.verify(&address, &message)));
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::ToFields;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the signature.
+        let signature = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Signature(signature) => signature,
+            _ => bail!("Invalid signature type for the '{}' execution, expected a signature", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Address(address) => address,
+            _ => bail!("Invalid address type for the '{}' execution, expected an address", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = registers.load_circuit(stack, &self.operands[2])?.to_fields();
+        // Ensure the message does not exceed the maximum size.
+        ensure!(
+            message.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize,
+            "Instruction '{}' cannot verify a message exceeding {} field elements",
+            Self::opcode(),
+            N::MAX_DATA_SIZE_IN_FIELDS
+        );
+
+        // Verify the signature.
+        let output = circuit::Literal::Boolean(signature.verify(&address, &message));
+        // Convert the output to a stack value.
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the first input is a signature.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Signature)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a 'signature'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+        // Ensure the second input is an address.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) {
+            bail!(
+                "Instruction '{}' expects the second input to be an 'address'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+        // Ensure the message is not a record.
+        if matches!(input_types[2], RegisterType::Record(..) | RegisterType::ExternalRecord(..)) {
+            bail!(
+                "Instruction '{}' expects the third input to be a plaintext. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for SignVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network> FromStr for SignVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for SignVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for SignVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            eprintln!("The number of operands must be 3, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for SignVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for SignVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        process::Stack,
+        program::test_helpers::{sample_finalize_registers, sample_registers},
+    };
+    use circuit::{AleoV0, Eject};
+    use console::{
+        account::{PrivateKey, Signature},
+        network::Testnet3,
+        program::Identifier,
+        types::{Address, Field},
+    };
+    use snarkvm_synthesizer_snark::{ProvingKey, VerifyingKey};
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    const ITERATIONS: usize = 10;
+
+    /// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+    #[allow(clippy::type_complexity)]
+    fn sample_stack(
+        mode: circuit::Mode,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) -> Result<(Stack<CurrentNetwork>, Vec<Operand<CurrentNetwork>>, Register<CurrentNetwork>)> {
+        use crate::{Process, Program};
+
+        // Initialize the opcode.
+        let opcode = SignVerify::<CurrentNetwork>::opcode();
+
+        // Initialize the function name.
+        let function_name = Identifier::<CurrentNetwork>::from_str("run")?;
+
+        // Initialize the registers.
+        let r0 = Register::Locator(0);
+        let r1 = Register::Locator(1);
+        let r2 = Register::Locator(2);
+        let r3 = Register::Locator(3);
+
+        // Initialize the program.
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            function {function_name}:
+                input {r0} as signature.{mode};
+                input {r1} as address.{mode};
+                input {r2} as field.{mode};
+                {opcode} {r0} {r1} {r2} into {r3};
+                finalize {r0} {r1} {r2};
+
+            finalize {function_name}:
+                input {r0} as signature.public;
+                input {r1} as address.public;
+                input {r2} as field.public;
+                {opcode} {r0} {r1} {r2} into {r3};
+        "
+        ))?;
+
+        // Initialize the operands.
+        let operands = vec![Operand::Register(r0), Operand::Register(r1), Operand::Register(r2)];
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(cache)?, &program)?;
+
+        Ok((stack, operands, r3))
+    }
+
+    fn check_sign_verify(
+        literals: [&Literal<CurrentNetwork>; 3],
+        mode: circuit::Mode,
+        expected: bool,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) {
+        // Initialize the stack.
+        let (stack, operands, destination) = sample_stack(mode, cache).unwrap();
+        // Initialize the operation.
+        let operation = SignVerify { operands, destination: destination.clone() };
+        // Initialize the function name.
+        let function_name = Identifier::from_str("run").unwrap();
+        // Initialize a destination operand.
+        let destination_operand = Operand::Register(destination);
+
+        // Evaluate the operation.
+        let values = literals.map(|literal| (literal, None));
+        let mut evaluate_registers = sample_registers(&stack, &function_name, &values).unwrap();
+        operation.evaluate(&stack, &mut evaluate_registers).unwrap();
+
+        // Execute the operation.
+        let values = literals.map(|literal| (literal, Some(mode)));
+        let mut execute_registers = sample_registers(&stack, &function_name, &values).unwrap();
+        operation.execute::<CurrentAleo>(&stack, &mut execute_registers).unwrap();
+
+        // Finalize the operation.
+        let mut finalize_registers = sample_finalize_registers(&stack, &function_name, &literals).unwrap();
+        operation.finalize(&stack, &mut finalize_registers).unwrap();
+
+        // Retrieve the outputs.
+        let output_a = evaluate_registers.load(&stack, &destination_operand).unwrap();
+        let output_b = execute_registers.load_circuit(&stack, &destination_operand).unwrap();
+        let output_c = finalize_registers.load(&stack, &destination_operand).unwrap();
+
+        // Check that the outputs are correct and consistent.
+        let expected = Value::Plaintext(Plaintext::from(Literal::Boolean(Boolean::new(expected))));
+        assert_eq!(expected, output_a, "The result of the evaluation is incorrect");
+        assert_eq!(output_a, output_b.eject_value(), "The results of the evaluation and execution are inconsistent");
+        assert_eq!(output_a, output_c, "The results of the evaluation and finalization are inconsistent");
+
+        // Reset the circuit.
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+
+    #[test]
+    fn test_sign_verify_is_consistent() {
+        // Prepare the rng.
+        let rng = &mut TestRng::default();
+
+        // Prepare the key cache.
+        let mut cache = Default::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a private key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let address = Literal::Address(Address::try_from(&private_key).unwrap());
+
+            // Sample a message, and sign over its field encoding.
+            let message = Literal::Field(Field::rand(rng));
+            let message_fields = Value::Plaintext(Plaintext::from(message.clone())).to_fields().unwrap();
+            let signature = Signature::sign(&private_key, &message_fields, rng).unwrap();
+            let signature = Literal::Signature(Box::new(signature));
+
+            // Sample a different message.
+            let other_message = Literal::Field(Field::rand(rng));
+
+            for mode in [circuit::Mode::Public, circuit::Mode::Private] {
+                // Check that the signature is valid for the signed message.
+                check_sign_verify([&signature, &address, &message], mode, true, &mut cache);
+                // Check that the signature is invalid for a different message.
+                check_sign_verify([&signature, &address, &other_message], mode, false, &mut cache);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let (string, sign) = SignVerify::<CurrentNetwork>::parse("sign.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(sign.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(sign.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(sign.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(sign.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(sign.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+}
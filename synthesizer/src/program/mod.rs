@@ -15,8 +15,14 @@
 mod closure;
 pub use closure::*;
 
+mod diagnostic;
+pub use diagnostic::*;
+
 pub mod finalize;
 
+mod format;
+pub use format::*;
+
 mod function;
 pub use function::*;
 
@@ -102,6 +108,10 @@ impl<N: Network> Program<N> {
             r"
 program credits.aleo;
 
+mapping account:
+    key owner as address.public;
+    value microcredits as u64.public;
+
 record credits:
     owner as address.private;
     microcredits as u64.private;
@@ -145,6 +155,64 @@ function fee:
     sub r0.microcredits r1 into r2;
     cast r0.owner r2 into r3 as credits.record;
     output r3 as credits.record;
+
+function transfer_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize self.caller r0 r1;
+
+finalize transfer_public:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    get.or_init account[r0] 0u64 into r3;
+    sub r3 r2 into r4;
+    set r4 into account[r0];
+    get.or_init account[r1] 0u64 into r5;
+    add r5 r2 into r6;
+    set r6 into account[r1];
+
+function transfer_private_to_public:
+    input r0 as credits.record;
+    input r1 as address.public;
+    input r2 as u64.public;
+    sub r0.microcredits r2 into r3;
+    cast r0.owner r3 into r4 as credits.record;
+    output r4 as credits.record;
+    finalize r1 r2;
+
+finalize transfer_private_to_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+
+function transfer_public_to_private:
+    input r0 as address.private;
+    input r1 as u64.public;
+    cast r0 r1 into r2 as credits.record;
+    output r2 as credits.record;
+    finalize self.caller r1;
+
+finalize transfer_public_to_private:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    sub r2 r1 into r3;
+    set r3 into account[r0];
+
+function fee_public:
+    input r0 as u64.public;
+    assert.neq r0 0u64;
+    finalize self.caller r0;
+
+finalize fee_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    sub r2 r1 into r3;
+    set r3 into account[r0];
 ",
         )
     }
@@ -369,6 +437,14 @@ impl<N: Network> Program<N> {
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
+                PlaintextType::Array(array_type) => {
+                    // Ensure the base element struct name exists in the program.
+                    if let PlaintextType::Struct(member_identifier) = array_type.base_element_type() {
+                        if !self.structs.contains_key(member_identifier) {
+                            bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
+                        }
+                    }
+                }
             }
         }
 
@@ -419,6 +495,13 @@ impl<N: Network> Program<N> {
                             bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                         }
                     }
+                    PlaintextType::Array(array_type) => {
+                        if let PlaintextType::Struct(identifier) = array_type.base_element_type() {
+                            if !self.structs.contains_key(identifier) {
+                                bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
+                            }
+                        }
+                    }
                 },
             }
         }
@@ -551,6 +634,7 @@ impl<N: Network> Program<N> {
         "u128",
         "scalar",
         "string",
+        "signature",
         // Boolean
         "true",
         "false",
@@ -685,6 +769,14 @@ struct message:
         // Ensure the retrieved struct matches.
         assert_eq!(struct_, program.get_struct(&Identifier::from_str("message")?)?);
 
+        // Ensure a struct cannot be named after a literal type.
+        let struct_ = Struct::<CurrentNetwork>::from_str(
+            r"
+struct signature:
+    first as field;",
+        )?;
+        assert!(program.add_struct(struct_).is_err());
+
         Ok(())
     }
 
@@ -14,19 +14,21 @@
 
 use super::*;
 
-impl<N: Network> Parser for Program<N> {
-    /// Parses a string into a program.
-    #[inline]
-    fn parse(string: &str) -> ParserResult<Self> {
-        // A helper to parse a program.
-        enum P<N: Network> {
-            M(Mapping<N>),
-            I(Struct<N>),
-            R(RecordType<N>),
-            C(Closure<N>),
-            F(Function<N>),
-        }
+use nom::error::VerboseError;
+
+/// A mapping, struct, record, closure, or function in a program.
+#[derive(Clone)]
+enum Definition<N: Network> {
+    Mapping(Mapping<N>),
+    Struct(Struct<N>),
+    Record(RecordType<N>),
+    Closure(Closure<N>),
+    Function(Function<N>),
+}
 
+impl<N: Network> Program<N> {
+    /// Parses the imports and the program ID from the string.
+    fn parse_header(string: &str) -> ParserResult<(Vec<Import<N>>, ProgramID<N>)> {
         // Parse the imports from the string.
         let (string, imports) = many0(Import::parse)(string)?;
         // Parse the whitespace and comments from the string.
@@ -41,15 +43,65 @@ impl<N: Network> Parser for Program<N> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
+        // Output the imports and the program ID.
+        Ok((string, (imports, id)))
+    }
+
+    /// Parses a mapping, struct, record, closure, or function from the string.
+    fn parse_definition(string: &str) -> ParserResult<Definition<N>> {
+        alt((
+            map(Mapping::parse, |mapping| Definition::Mapping(mapping)),
+            map(Struct::parse, |struct_| Definition::Struct(struct_)),
+            map(RecordType::parse, |record| Definition::Record(record)),
+            map(Closure::parse, |closure| Definition::Closure(closure)),
+            map(Function::parse, |function| Definition::Function(function)),
+        ))(string)
+    }
+
+    /// Returns the offset of the furthest point that any instruction or command parser reaches in the given statement.
+    /// If the statement is a valid instruction or command, the offset is `0`.
+    fn furthest_in_statement(statement: &str) -> usize {
+        // Returns the offset that the given parser reached before failing.
+        let progress = |result: ParserResult<()>| match result {
+            Err(nom::Err::Error(error)) | Err(nom::Err::Failure(error)) => {
+                error.errors.iter().map(|(remaining, _)| statement.len() - remaining.len()).max().unwrap_or_default()
+            }
+            Ok(_) | Err(nom::Err::Incomplete(_)) => 0,
+        };
+
+        /// Returns the furthest offset that the parser of each instruction reaches in the statement.
+        macro_rules! instruction_progress {
+            ($object:expr, |_instruction| $_operation:block, { $( $variant:ident, )+ }) => {{
+                [ $( progress(map($variant::parse, |_: $variant<N>| ())(statement)) ),+ ]
+            }};
+        }
+
+        // Determine the furthest offset that an instruction or command parser reaches.
+        let instructions = crate::instruction!(instruction_progress!(self, _instruction));
+        let command = progress(map(finalize::Command::<N>::parse, |_| ())(statement));
+        instructions.into_iter().fold(command, usize::max)
+    }
 
+    /// Adds the given definition to the program.
+    fn add_definition(&mut self, definition: Definition<N>) -> Result<()> {
+        match definition {
+            Definition::Mapping(mapping) => self.add_mapping(mapping),
+            Definition::Struct(struct_) => self.add_struct(struct_),
+            Definition::Record(record) => self.add_record(record),
+            Definition::Closure(closure) => self.add_closure(closure),
+            Definition::Function(function) => self.add_function(function),
+        }
+    }
+}
+
+impl<N: Network> Parser for Program<N> {
+    /// Parses a string into a program.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the imports and the program ID from the string.
+        let (string, (imports, id)) = Self::parse_header(string)?;
         // Parse the struct or function from the string.
-        let (string, components) = many1(alt((
-            map(Mapping::parse, |mapping| P::<N>::M(mapping)),
-            map(Struct::parse, |struct_| P::<N>::I(struct_)),
-            map(RecordType::parse, |record| P::<N>::R(record)),
-            map(Closure::parse, |closure| P::<N>::C(closure)),
-            map(Function::parse, |function| P::<N>::F(function)),
-        )))(string)?;
+        let (string, definitions) = many1(Self::parse_definition)(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
 
@@ -64,16 +116,8 @@ impl<N: Network> Parser for Program<N> {
                 }
             };
             // Construct the program with the parsed components.
-            for component in components.iter() {
-                let result = match component {
-                    P::M(mapping) => program.add_mapping(mapping.clone()),
-                    P::I(struct_) => program.add_struct(struct_.clone()),
-                    P::R(record) => program.add_record(record.clone()),
-                    P::C(closure) => program.add_closure(closure.clone()),
-                    P::F(function) => program.add_function(function.clone()),
-                };
-
-                match result {
+            for definition in definitions.iter() {
+                match program.add_definition(definition.clone()) {
                     Ok(_) => (),
                     Err(error) => {
                         eprintln!("{error}");
@@ -97,6 +141,113 @@ impl<N: Network> Parser for Program<N> {
     }
 }
 
+impl<N: Network> Program<N> {
+    /// Parses a program from the given source, collecting a diagnostic for each definition that fails to parse.
+    /// Unlike `Program::from_str`, parsing continues past an invalid definition, so that every definition is checked,
+    /// and each diagnostic carries the span of the offending statement.
+    pub fn from_source(source: &str) -> Result<Self, Diagnostics> {
+        // Determine the span of each statement.
+        let source_map = SourceMap::<N>::new(source);
+        let headers = source_map.headers();
+
+        // Returns a diagnostic for a parser that failed at the given offset in the source.
+        let failure = |offset: usize, context: &str| {
+            // If the parser failed at the start of a statement, advance the offset to the furthest point that
+            // an instruction or command reaches in the statement, as a definition backtracks to the statement.
+            let statement = source_map.span_at(source, offset);
+            let offset = match offset <= statement.start() {
+                true => statement.start() + Self::furthest_in_statement(statement.text(source)),
+                false => offset,
+            };
+            // Move the offset back to the start of the offending token.
+            let is_delimiter = |c: char| c.is_whitespace() || c == ';' || c == ':';
+            let offset = source[..offset].trim_end_matches(|c: char| !is_delimiter(c)).len();
+            let span = source_map.span_at(source, offset);
+            let message = match span.token(source) {
+                Some(token) => format!("Failed to parse '{token}' in {context}"),
+                None => format!("Failed to parse {context}"),
+            };
+            Diagnostic::new(source, message, Some(span))
+        };
+        // Returns the offset in the given chunk of the furthest point that the parser reached.
+        let furthest = |chunk: &str, error: nom::Err<VerboseError<&str>>| match error {
+            nom::Err::Error(error) | nom::Err::Failure(error) => {
+                error.errors.iter().map(|(remaining, _)| chunk.len() - remaining.len()).max().unwrap_or_default()
+            }
+            nom::Err::Incomplete(_) => 0,
+        };
+
+        let mut diagnostics = Vec::new();
+
+        // Parse the imports and the program ID, which precede the first definition.
+        let header = &source[..headers.first().map_or(source.len(), |(_, span)| span.start())];
+        let header =
+            match Self::parse_header(header).and_then(|(string, header)| Ok((Sanitizer::parse(string)?.0, header))) {
+                Ok(("", header)) => Some(header),
+                Ok((remaining, _)) => {
+                    diagnostics.push(failure(header.len() - remaining.len(), "the program header"));
+                    None
+                }
+                Err(error) => {
+                    diagnostics.push(failure(furthest(header, error), "the program header"));
+                    None
+                }
+            };
+
+        // Parse each definition, up to the next definition.
+        let mut definitions = Vec::with_capacity(headers.len());
+        for (index, (keyword, span)) in headers.iter().enumerate() {
+            let start = span.start();
+            let chunk = &source[start..headers.get(index + 1).map_or(source.len(), |(_, span)| span.start())];
+            // Describe the definition, e.g. `function 'compute'`.
+            let name = span.text(source).split_whitespace().nth(1).unwrap_or_default().trim_end_matches(':');
+            let context = format!("{keyword} '{name}'");
+
+            match Self::parse_definition(chunk)
+                .and_then(|(string, definition)| Ok((Sanitizer::parse(string)?.0, definition)))
+            {
+                Ok(("", definition)) => definitions.push((*span, definition)),
+                Ok((remaining, _)) => diagnostics.push(failure(start + chunk.len() - remaining.len(), &context)),
+                Err(error) => diagnostics.push(failure(start + furthest(chunk, error), &context)),
+            }
+        }
+
+        // Ensure the program contains a definition.
+        if headers.is_empty() && diagnostics.is_empty() {
+            diagnostics.push(Diagnostic::new(
+                source,
+                "A program must contain at least one definition".to_string(),
+                None,
+            ));
+        }
+
+        // Construct the program, if every statement is parsed.
+        if let (Some((imports, id)), true) = (header, diagnostics.is_empty()) {
+            match Program::<N>::new(id) {
+                Ok(mut program) => {
+                    // Add the definitions, reporting each error at the definition header.
+                    for (span, definition) in definitions {
+                        if let Err(error) = program.add_definition(definition) {
+                            diagnostics.push(Diagnostic::new(source, error.to_string(), Some(span)));
+                        }
+                    }
+                    // Lastly, add the imports (if any) to the program.
+                    for import in imports {
+                        if let Err(error) = program.add_import(import) {
+                            diagnostics.push(Diagnostic::new(source, error.to_string(), None));
+                        }
+                    }
+                    if diagnostics.is_empty() {
+                        return Ok(program);
+                    }
+                }
+                Err(error) => diagnostics.push(Diagnostic::new(source, error.to_string(), None)),
+            }
+        }
+        Err(diagnostics.into_iter().collect())
+    }
+}
+
 impl<N: Network> FromStr for Program<N> {
     type Err = Error;
 
@@ -18,7 +18,7 @@ use crate::{
     cow_to_cloned,
     cow_to_copied,
     store::{
-        helpers::{Map, MapRead},
+        helpers::{Map, MapRead, SnapshotReader, SnapshotWriter, StorageMode},
         TransactionStorage,
         TransactionStore,
         TransitionStorage,
@@ -71,13 +71,23 @@ fn to_confirmed_tuple<N: Network>(
             // Return the confirmed tuple.
             Ok((ConfirmedTxType::AcceptedExecute(index), tx, (num_finalize, finalize).to_bytes_le()?))
         }
-        ConfirmedTransaction::RejectedDeploy(index, tx, rejected) => {
+        ConfirmedTransaction::RejectedDeploy(index, tx, rejected, finalize) => {
+            // Retrieve the number of finalize operations.
+            let num_finalize = NumFinalizeSize::try_from(finalize.len())?;
+            // Serialize the rejected transaction, followed by the finalize operations.
+            let mut blob = rejected.to_bytes_le()?;
+            blob.extend((num_finalize, finalize).to_bytes_le()?);
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::RejectedDeploy(index), tx, rejected.to_bytes_le()?))
+            Ok((ConfirmedTxType::RejectedDeploy(index), tx, blob))
         }
-        ConfirmedTransaction::RejectedExecute(index, tx, rejected) => {
+        ConfirmedTransaction::RejectedExecute(index, tx, rejected, finalize) => {
+            // Retrieve the number of finalize operations.
+            let num_finalize = NumFinalizeSize::try_from(finalize.len())?;
+            // Serialize the rejected transaction, followed by the finalize operations.
+            let mut blob = rejected.to_bytes_le()?;
+            blob.extend((num_finalize, finalize).to_bytes_le()?);
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::RejectedExecute(index), tx, rejected.to_bytes_le()?))
+            Ok((ConfirmedTxType::RejectedExecute(index), tx, blob))
         }
     }
 }
@@ -109,10 +119,28 @@ fn to_confirmed_transaction<N: Network>(
             ConfirmedTransaction::accepted_execute(index, transaction, finalize)
         }
         ConfirmedTxType::RejectedDeploy(index) => {
-            ConfirmedTransaction::rejected_deploy(index, transaction, FromBytes::read_le(&*blob)?)
+            // Initialize a cursor.
+            let mut cursor = Cursor::new(blob);
+            // Read the rejected transaction.
+            let rejected = FromBytes::read_le(&mut cursor)?;
+            // Read the number of finalize operations.
+            let num_finalize = NumFinalizeSize::read_le(&mut cursor)?;
+            // Read the finalize operations.
+            let finalize = (0..num_finalize).map(|_| FromBytes::read_le(&mut cursor)).collect::<Result<Vec<_>, _>>()?;
+            // Return the confirmed transaction.
+            ConfirmedTransaction::rejected_deploy(index, transaction, rejected, finalize)
         }
         ConfirmedTxType::RejectedExecute(index) => {
-            ConfirmedTransaction::rejected_execute(index, transaction, FromBytes::read_le(&*blob)?)
+            // Initialize a cursor.
+            let mut cursor = Cursor::new(blob);
+            // Read the rejected transaction.
+            let rejected = FromBytes::read_le(&mut cursor)?;
+            // Read the number of finalize operations.
+            let num_finalize = NumFinalizeSize::read_le(&mut cursor)?;
+            // Read the finalize operations.
+            let finalize = (0..num_finalize).map(|_| FromBytes::read_le(&mut cursor)).collect::<Result<Vec<_>, _>>()?;
+            // Return the confirmed transaction.
+            ConfirmedTransaction::rejected_execute(index, transaction, rejected, finalize)
         }
     }
 }
@@ -145,7 +173,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type SignatureMap: for<'a> Map<'a, N::BlockHash, Signature<N>>;
 
     /// Initializes the block storage.
-    fn open(dev: Option<u16>) -> Result<Self>;
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
 
     /// Returns the state root map.
     fn state_root_map(&self) -> &Self::StateRootMap;
@@ -174,10 +202,15 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
         self.transaction_store().transition_store()
     }
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        debug_assert!(self.transaction_store().storage_mode() == self.transition_store().storage_mode());
+        self.transition_store().storage_mode()
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
-        debug_assert!(self.transaction_store().dev() == self.transition_store().dev());
-        self.transition_store().dev()
+        self.storage_mode().dev()
     }
 
     /// Starts an atomic batch write operation.
@@ -285,6 +318,38 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.signature_map().finish_atomic()
     }
 
+    /// Writes the maps of the block storage into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        snapshot.write_map("block.state_root", self.state_root_map())?;
+        snapshot.write_map("block.reverse_state_root", self.reverse_state_root_map())?;
+        snapshot.write_map("block.id", self.id_map())?;
+        snapshot.write_map("block.reverse_id", self.reverse_id_map())?;
+        snapshot.write_map("block.header", self.header_map())?;
+        snapshot.write_map("block.transactions", self.transactions_map())?;
+        snapshot.write_map("block.confirmed_transactions", self.confirmed_transactions_map())?;
+        snapshot.write_map("block.coinbase_solution", self.coinbase_solution_map())?;
+        snapshot.write_map("block.coinbase_puzzle_commitment", self.coinbase_puzzle_commitment_map())?;
+        snapshot.write_map("block.signature", self.signature_map())?;
+        self.transaction_store().export_snapshot(snapshot)?;
+        Ok(())
+    }
+
+    /// Reads the maps of the block storage from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        snapshot.read_map("block.state_root", self.state_root_map())?;
+        snapshot.read_map("block.reverse_state_root", self.reverse_state_root_map())?;
+        snapshot.read_map("block.id", self.id_map())?;
+        snapshot.read_map("block.reverse_id", self.reverse_id_map())?;
+        snapshot.read_map("block.header", self.header_map())?;
+        snapshot.read_map("block.transactions", self.transactions_map())?;
+        snapshot.read_map("block.confirmed_transactions", self.confirmed_transactions_map())?;
+        snapshot.read_map("block.coinbase_solution", self.coinbase_solution_map())?;
+        snapshot.read_map("block.coinbase_puzzle_commitment", self.coinbase_puzzle_commitment_map())?;
+        snapshot.read_map("block.signature", self.signature_map())?;
+        self.transaction_store().import_snapshot(snapshot)?;
+        Ok(())
+    }
+
     /// Stores the given `(state root, block)` pair into storage.
     fn insert(&self, state_root: N::StateRoot, block: &Block<N>) -> Result<()> {
         // Prepare the confirmed transactions.
@@ -657,32 +722,38 @@ pub struct BlockStore<N: Network, B: BlockStorage<N>> {
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     /// Initializes the block store.
-    pub fn open(dev: Option<u16>) -> Result<Self> {
+    pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize the block storage.
-        let storage = B::open(dev)?;
+        Self::from(B::open(storage)?)
+    }
 
+    /// Initializes a block store from storage.
+    pub fn from(storage: B) -> Result<Self> {
         // Compute the block tree.
-        let tree = {
-            // Prepare an iterator over the block heights.
-            let heights = storage.id_map().keys_confirmed();
-            // Prepare the leaves of the block tree.
-            let hashes = match heights.max() {
-                Some(height) => cfg_into_iter!(0..=cow_to_copied!(height))
-                    .map(|height| match storage.get_block_hash(height)? {
-                        Some(hash) => Ok(hash.to_bits_le()),
-                        None => bail!("Missing block hash for block {height}"),
-                    })
-                    .collect::<Result<Vec<Vec<bool>>>>()?,
-                None => vec![],
-            };
-            // Construct the block tree.
-            Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
-        };
+        let tree = Arc::new(RwLock::new(Self::compute_tree(&storage)?));
 
         // Return the block store.
         Ok(Self { storage, tree })
     }
 
+    /// Computes the block tree from the block hashes in the given storage.
+    fn compute_tree(storage: &B) -> Result<BlockTree<N>> {
+        // Prepare an iterator over the block heights.
+        let heights = storage.id_map().keys_confirmed();
+        // Prepare the leaves of the block tree.
+        let hashes = match heights.max() {
+            Some(height) => cfg_into_iter!(0..=cow_to_copied!(height))
+                .map(|height| match storage.get_block_hash(height)? {
+                    Some(hash) => Ok(hash.to_bits_le()),
+                    None => bail!("Missing block hash for block {height}"),
+                })
+                .collect::<Result<Vec<Vec<bool>>>>()?,
+            None => vec![],
+        };
+        // Construct the block tree.
+        N::merkle_tree_bhp(&hashes)
+    }
+
     /// Stores the given block into storage.
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
         // Acquire the write lock on the block tree.
@@ -796,6 +867,27 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the block storage into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the block storage from the given snapshot, and recomputes the block tree.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Read the maps of the block storage.
+        self.storage.import_snapshot(snapshot)?;
+        // Recompute the block tree.
+        *tree = Self::compute_tree(&self.storage)?;
+        Ok(())
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
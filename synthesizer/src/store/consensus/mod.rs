@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::store::{
+    helpers::{SnapshotReader, SnapshotWriter, StorageMode},
     BlockStorage,
     BlockStore,
     FinalizeStorage,
@@ -39,7 +40,7 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     type TransitionStorage: TransitionStorage<N>;
 
     /// Initializes the consensus storage.
-    fn open(dev: Option<u16>) -> Result<Self>;
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
 
     /// Returns the finalize storage.
     fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage>;
@@ -53,11 +54,16 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
         self.block_store().transition_store()
     }
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        debug_assert!(self.block_store().storage_mode() == self.transaction_store().storage_mode());
+        debug_assert!(self.transaction_store().storage_mode() == self.transition_store().storage_mode());
+        self.transition_store().storage_mode()
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
-        debug_assert!(self.block_store().dev() == self.transaction_store().dev());
-        debug_assert!(self.transaction_store().dev() == self.transition_store().dev());
-        self.transition_store().dev()
+        self.storage_mode().dev()
     }
 
     /// Starts an atomic batch write operation.
@@ -100,6 +106,18 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
         self.finalize_store().finish_atomic()?;
         self.block_store().finish_atomic()
     }
+
+    /// Writes the maps of the consensus storage into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.finalize_store().export_snapshot(snapshot)?;
+        self.block_store().export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the consensus storage from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.finalize_store().import_snapshot(snapshot)?;
+        self.block_store().import_snapshot(snapshot)
+    }
 }
 
 /// The consensus store.
@@ -113,9 +131,9 @@ pub struct ConsensusStore<N: Network, C: ConsensusStorage<N>> {
 
 impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
     /// Initializes the consensus store.
-    pub fn open(dev: Option<u16>) -> Result<Self> {
+    pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize the consensus storage.
-        let storage = C::open(dev)?;
+        let storage = C::open(storage)?;
         // Return the consensus store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -180,6 +198,21 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the consensus storage into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the consensus storage from the given snapshot.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.storage.import_snapshot(snapshot)
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
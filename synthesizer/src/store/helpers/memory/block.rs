@@ -15,7 +15,10 @@
 use crate::{
     block::Header,
     store::{
-        helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
+        helpers::{
+            memory::{MemoryMap, TransactionMemory, TransitionMemory},
+            StorageMode,
+        },
         BlockStorage,
         ConfirmedTxType,
         TransactionStore,
@@ -68,9 +71,9 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type SignatureMap = MemoryMap<N::BlockHash, Signature<N>>;
 
     /// Initializes the block storage.
-    fn open(dev: Option<u16>) -> Result<Self> {
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize the transition store.
-        let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(dev)?;
+        let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(storage.clone())?;
         // Initialize the transaction store.
         let transaction_store = TransactionStore::<N, TransactionMemory<N>>::open(transition_store)?;
         // Return the block storage.
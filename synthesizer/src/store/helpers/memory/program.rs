@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::store::{helpers::memory::MemoryMap, FinalizeStorage};
+use crate::store::{
+    helpers::{memory::MemoryMap, StorageMode},
+    FinalizePreimage,
+    FinalizeStorage,
+};
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
@@ -34,8 +38,10 @@ pub struct FinalizeMemory<N: Network> {
     key_map: MemoryMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: MemoryMap<Field<N>, Value<N>>,
-    /// The optional development ID.
-    dev: Option<u16>,
+    /// The pre-image map.
+    preimage_map: MemoryMap<u32, FinalizePreimage<N>>,
+    /// The storage mode.
+    storage: StorageMode,
 }
 
 #[rustfmt::skip]
@@ -45,16 +51,18 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type KeyValueIDMap = MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = MemoryMap<Field<N>, Plaintext<N>>;
     type ValueMap = MemoryMap<Field<N>, Value<N>>;
+    type PreimageMap = MemoryMap<u32, FinalizePreimage<N>>;
 
     /// Initializes the program state storage.
-    fn open(dev: Option<u16>) -> Result<Self> {
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         Ok(Self {
             program_id_map: MemoryMap::default(),
             mapping_id_map: MemoryMap::default(),
             key_value_id_map: MemoryMap::default(),
             key_map: MemoryMap::default(),
             value_map: MemoryMap::default(),
-            dev,
+            preimage_map: MemoryMap::default(),
+            storage: storage.into(),
         })
     }
 
@@ -83,8 +91,13 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.value_map
     }
 
-    /// Returns the optional development ID.
-    fn dev(&self) -> Option<u16> {
-        self.dev
+    /// Returns the pre-image map.
+    fn preimage_map(&self) -> &Self::PreimageMap {
+        &self.preimage_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage
     }
 }
@@ -33,6 +33,8 @@ use console::{
     program::{Identifier, ProgramID, ProgramOwner},
 };
 
+use indexmap::IndexMap;
+
 /// An in-memory transaction storage.
 #[derive(Clone)]
 pub struct TransactionMemory<N: Network> {
@@ -92,7 +94,7 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
 #[allow(clippy::type_complexity)]
 pub struct DeploymentMemory<N: Network> {
     /// The ID map.
-    id_map: MemoryMap<N::TransactionID, ProgramID<N>>,
+    id_map: MemoryMap<N::TransactionID, (ProgramID<N>, u16)>,
     /// The edition map.
     edition_map: MemoryMap<ProgramID<N>, u16>,
     /// The reverse ID map.
@@ -111,7 +113,7 @@ pub struct DeploymentMemory<N: Network> {
 
 #[rustfmt::skip]
 impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
-    type IDMap = MemoryMap<N::TransactionID, ProgramID<N>>;
+    type IDMap = MemoryMap<N::TransactionID, (ProgramID<N>, u16)>;
     type EditionMap = MemoryMap<ProgramID<N>, u16>;
     type ReverseIDMap = MemoryMap<(ProgramID<N>, u16), N::TransactionID>;
     type OwnerMap = MemoryMap<(ProgramID<N>, u16), ProgramOwner<N>>;
@@ -185,6 +187,8 @@ pub struct ExecutionMemory<N: Network> {
     reverse_id_map: MemoryMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
     inclusion_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The edition map.
+    edition_map: MemoryMap<N::TransactionID, IndexMap<ProgramID<N>, u16>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeMemory<N>>,
 }
@@ -194,6 +198,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = MemoryMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type EditionMap = MemoryMap<N::TransactionID, IndexMap<ProgramID<N>, u16>>;
     type FeeStorage = FeeMemory<N>;
 
     /// Initializes the execution storage.
@@ -202,6 +207,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
             id_map: MemoryMap::default(),
             reverse_id_map: MemoryMap::default(),
             inclusion_map: MemoryMap::default(),
+            edition_map: MemoryMap::default(),
             fee_store
         })
     }
@@ -221,6 +227,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
         &self.inclusion_map
     }
 
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap {
+        &self.edition_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
@@ -16,6 +16,12 @@ pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
 
+mod snapshot;
+pub use snapshot::*;
+
+mod storage_mode;
+pub use storage_mode::*;
+
 use console::network::prelude::*;
 
 use core::{borrow::Borrow, hash::Hash};
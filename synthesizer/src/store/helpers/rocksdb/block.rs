@@ -22,6 +22,7 @@ use crate::{
             TransactionDB,
             TransitionDB,
         },
+        helpers::StorageMode,
         BlockStorage,
         ConfirmedTxType,
         TransactionStore,
@@ -58,6 +59,31 @@ pub struct BlockDB<N: Network> {
     signature_map: DataMap<N::BlockHash, Signature<N>>,
 }
 
+#[rustfmt::skip]
+impl<N: Network> BlockDB<N> {
+    /// Initializes the block storage in the given database.
+    pub(crate) fn open_database(database: &internal::RocksDB) -> Result<Self> {
+        // Initialize the transition store.
+        let transition_store = TransitionStore::<N, TransitionDB<N>>::from(TransitionDB::open_database(database)?);
+        // Initialize the transaction store.
+        let transaction_store = TransactionStore::<N, TransactionDB<N>>::open(transition_store)?;
+        // Return the block storage.
+        Ok(Self {
+            state_root_map: database.open_map(MapID::Block(BlockMap::StateRoot))?,
+            reverse_state_root_map: database.open_map(MapID::Block(BlockMap::ReverseStateRoot))?,
+            id_map: database.open_map(MapID::Block(BlockMap::ID))?,
+            reverse_id_map: database.open_map(MapID::Block(BlockMap::ReverseID))?,
+            header_map: database.open_map(MapID::Block(BlockMap::Header))?,
+            transactions_map: database.open_map(MapID::Block(BlockMap::Transactions))?,
+            confirmed_transactions_map: database.open_map(MapID::Block(BlockMap::ConfirmedTransactions))?,
+            transaction_store,
+            coinbase_solution_map: database.open_map(MapID::Block(BlockMap::CoinbaseSolution))?,
+            coinbase_puzzle_commitment_map: database.open_map(MapID::Block(BlockMap::CoinbasePuzzleCommitment))?,
+            signature_map: database.open_map(MapID::Block(BlockMap::Signature))?,
+        })
+    }
+}
+
 #[rustfmt::skip]
 impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type StateRootMap = DataMap<u32, N::StateRoot>;
@@ -74,25 +100,8 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type SignatureMap = DataMap<N::BlockHash, Signature<N>>;
 
     /// Initializes the block storage.
-    fn open(dev: Option<u16>) -> Result<Self> {
-        // Initialize the transition store.
-        let transition_store = TransitionStore::<N, TransitionDB<N>>::open(dev)?;
-        // Initialize the transaction store.
-        let transaction_store = TransactionStore::<N, TransactionDB<N>>::open(transition_store)?;
-        // Return the block storage.
-        Ok(Self {
-            state_root_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::StateRoot))?,
-            reverse_state_root_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::ReverseStateRoot))?,
-            id_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::ID))?,
-            reverse_id_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::ReverseID))?,
-            header_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Header))?,
-            transactions_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Transactions))?,
-            confirmed_transactions_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::ConfirmedTransactions))?,
-            transaction_store,
-            coinbase_solution_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::CoinbaseSolution))?,
-            coinbase_puzzle_commitment_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::CoinbasePuzzleCommitment))?,
-            signature_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Signature))?,
-        })
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Self::open_database(&internal::RocksDB::open(N::ID, storage)?)
     }
 
     /// Returns the state root map.
@@ -13,7 +13,16 @@
 // limitations under the License.
 
 use crate::store::{
-    helpers::rocksdb::{BlockDB, FinalizeDB, TransactionDB, TransitionDB},
+    helpers::{
+        rocksdb::{
+            internal::{self, Database},
+            BlockDB,
+            FinalizeDB,
+            TransactionDB,
+            TransitionDB,
+        },
+        StorageMode,
+    },
     BlockStore,
     ConsensusStorage,
     FinalizeStore,
@@ -37,11 +46,13 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
     type TransitionStorage = TransitionDB<N>;
 
     /// Initializes the consensus storage.
-    fn open(dev: Option<u16>) -> Result<Self> {
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Open the database, which is shared by the stores of this ledger.
+        let database = internal::RocksDB::open(N::ID, storage)?;
         // Initialize the finalize store.
-        let finalize_store = FinalizeStore::<N, FinalizeDB<N>>::open(dev)?;
+        let finalize_store = FinalizeStore::<N, FinalizeDB<N>>::from(FinalizeDB::open_database(&database)?)?;
         // Initialize the block store.
-        let block_store = BlockStore::<N, BlockDB<N>>::open(dev)?;
+        let block_store = BlockStore::<N, BlockDB<N>>::from(BlockDB::open_database(&database)?)?;
         // Return the consensus storage.
         Ok(Self {
             finalize_store,
@@ -90,6 +90,7 @@ pub enum ExecutionMap {
     ID = DataID::ExecutionIDMap as u16,
     ReverseID = DataID::ExecutionReverseIDMap as u16,
     Inclusion = DataID::ExecutionInclusionMap as u16,
+    Edition = DataID::ExecutionEditionMap as u16,
 }
 
 /// The RocksDB map prefix for fee-related entries.
@@ -169,6 +170,7 @@ pub enum ProgramMap {
     KeyValueID = DataID::KeyValueIDMap as u16,
     Key = DataID::KeyMap as u16,
     Value = DataID::ValueMap as u16,
+    Preimage = DataID::PreimageMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -248,6 +250,10 @@ enum DataID {
     KeyValueIDMap,
     KeyMap,
     ValueMap,
+    // Execution (continued)
+    ExecutionEditionMap,
+    // Program (continued)
+    PreimageMap,
 
     // Testing
     #[cfg(test)]
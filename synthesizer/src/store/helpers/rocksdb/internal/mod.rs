@@ -24,9 +24,10 @@ pub use map::*;
 #[cfg(test)]
 mod tests;
 
+use crate::store::helpers::StorageMode;
+
 use anyhow::{bail, Result};
 use core::{fmt::Debug, hash::Hash};
-use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
@@ -40,14 +41,13 @@ pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
 
 pub trait Database {
     /// Opens the database.
-    fn open(network_id: u16, dev: Option<u16>) -> Result<Self>
+    fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self>
     where
         Self: Sized;
 
-    /// Opens the map with the given `network_id`, `(optional) development ID`, and `map_id` from storage.
+    /// Opens the map with the given `map_id` from the database.
     fn open_map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
-        network_id: u16,
-        dev: Option<u16>,
+        &self,
         map_id: T,
     ) -> Result<DataMap<K, V>>;
 }
@@ -59,8 +59,8 @@ pub struct RocksDB {
     rocksdb: Arc<rocksdb::DB>,
     /// The network ID.
     network_id: u16,
-    /// The optional development ID.
-    dev: Option<u16>,
+    /// The storage mode.
+    storage: StorageMode,
 }
 
 impl Deref for RocksDB {
@@ -76,55 +76,56 @@ impl Database for RocksDB {
     ///
     /// In production mode, the database opens directory `~/.aleo/storage/ledger-{network}`.
     /// In development mode, the database opens directory `/path/to/repo/.ledger-{network}-{id}`.
-    fn open(network_id: u16, dev: Option<u16>) -> Result<Self> {
-        static DB: OnceCell<RocksDB> = OnceCell::new();
-
-        // Retrieve the database.
-        let database = DB
-            .get_or_try_init(|| {
-                // Customize database options.
-                let mut options = rocksdb::Options::default();
-                options.set_compression_type(rocksdb::DBCompressionType::Lz4);
-
-                // Register the prefix length.
-                let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
-                options.set_prefix_extractor(prefix_extractor);
-
-                let primary = aleo_std::aleo_ledger_dir(network_id, dev);
-                let rocksdb = {
-                    options.increase_parallelism(2);
-                    options.create_if_missing(true);
-
-                    Arc::new(rocksdb::DB::open(&options, primary)?)
-                };
-
-                Ok::<_, anyhow::Error>(RocksDB { rocksdb, network_id, dev })
-            })?
-            .clone();
-
-        // Ensure the database network ID and development ID match.
-        match database.network_id == network_id && database.dev == dev {
-            true => Ok(database),
-            false => bail!("Mismatching network ID or development ID in the database"),
-        }
+    /// In custom mode, the database opens the given directory.
+    ///
+    /// Each call opens a new instance, which is shared by the maps opened from it,
+    /// and is closed once the last of them is dropped.
+    fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self> {
+        let storage = storage.into();
+        // Determine the directory of the database.
+        let primary = match &storage {
+            StorageMode::Custom(path) => path.clone(),
+            storage => aleo_std::aleo_ledger_dir(network_id, storage.dev()),
+        };
+        // Canonicalize the directory, so that the same ledger is always referred to by the same path.
+        std::fs::create_dir_all(&primary)?;
+        let primary = primary.canonicalize()?;
+        // Record the canonical directory for a custom storage.
+        let storage = match storage {
+            StorageMode::Custom(_) => StorageMode::Custom(primary.clone()),
+            storage => storage,
+        };
+
+        // Customize database options.
+        let mut options = rocksdb::Options::default();
+        options.set_compression_type(rocksdb::DBCompressionType::Lz4);
+
+        // Register the prefix length.
+        let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
+        options.set_prefix_extractor(prefix_extractor);
+
+        let rocksdb = {
+            options.increase_parallelism(2);
+            options.create_if_missing(true);
+
+            Arc::new(rocksdb::DB::open(&options, &primary)?)
+        };
+
+        Ok(RocksDB { rocksdb, network_id, storage })
     }
 
-    /// Opens the map with the given `network_id`, `(optional) development ID`, and `map_id` from storage.
+    /// Opens the map with the given `map_id` from the database.
     fn open_map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
-        network_id: u16,
-        dev: Option<u16>,
+        &self,
         map_id: T,
     ) -> Result<DataMap<K, V>> {
-        // Open the RocksDB database.
-        let database = Self::open(network_id, dev)?;
-
         // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
+        let mut context = self.network_id.to_le_bytes().to_vec();
         context.extend_from_slice(&(map_id.into()).to_le_bytes());
 
         // Return the DataMap.
         Ok(DataMap {
-            database,
+            database: self.clone(),
             context,
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
@@ -134,6 +135,11 @@ impl Database for RocksDB {
 }
 
 impl RocksDB {
+    /// Returns the storage mode.
+    pub const fn storage_mode(&self) -> &StorageMode {
+        &self.storage
+    }
+
     /// Opens the test database.
     #[cfg(test)]
     fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
@@ -158,11 +164,11 @@ impl RocksDB {
                 Arc::new(rocksdb::DB::open(&options, primary)?)
             };
 
-            Ok::<_, anyhow::Error>(RocksDB { rocksdb, network_id: u16::MAX, dev })
+            Ok::<_, anyhow::Error>(RocksDB { rocksdb, network_id: u16::MAX, storage: StorageMode::from(dev) })
         }?;
 
         // Ensure the database development ID match.
-        match database.dev == dev {
+        match database.storage.dev() == dev {
             true => Ok(database),
             false => bail!("Mismatching development ID in the test database"),
         }
@@ -175,21 +181,8 @@ impl RocksDB {
         dev: Option<u16>,
         map_id: T,
     ) -> Result<DataMap<K, V>> {
-        // Open the RocksDB test database.
-        let database = Self::open_testing(temp_dir, dev)?;
-
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
-        // Return the DataMap.
-        Ok(DataMap {
-            database,
-            context,
-            batch_in_progress: Default::default(),
-            atomic_batch: Default::default(),
-            checkpoint: Default::default(),
-        })
+        // Open the RocksDB test database, and the map within it.
+        Self::open_testing(temp_dir, dev)?.open_map(map_id)
     }
 }
 
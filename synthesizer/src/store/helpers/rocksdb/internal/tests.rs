@@ -13,9 +13,10 @@
 // limitations under the License.
 
 use crate::store::helpers::{
-    rocksdb::{DataMap, MapID, RocksDB, TestMap as TestMapID},
+    rocksdb::{DataMap, Database, MapID, RocksDB, TestMap as TestMapID},
     Map,
     MapRead,
+    StorageMode,
 };
 use console::{
     network::{Network, Testnet3},
@@ -150,6 +151,38 @@ fn test_reopen() {
     }
 }
 
+#[test]
+#[serial]
+fn test_open_custom_storage() {
+    let (directory_a, directory_b) = (temp_dir(), temp_dir());
+
+    // Open two databases side by side, each in its own directory.
+    let database_a = RocksDB::open(0, directory_a.clone()).expect("Failed to open database");
+    let database_b = RocksDB::open(0, directory_b).expect("Failed to open database");
+    let map_a: TestMap = database_a.open_map(MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+    let map_b: TestMap = database_b.open_map(MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+    map_a.insert(1, "1".to_string()).expect("Failed to insert");
+    map_b.insert(2, "2".to_string()).expect("Failed to insert");
+
+    // Ensure the databases are independent.
+    assert!(map_a.contains_key_confirmed(&1).expect("Failed to call contains key"));
+    assert!(!map_a.contains_key_confirmed(&2).expect("Failed to call contains key"));
+    assert!(map_b.contains_key_confirmed(&2).expect("Failed to call contains key"));
+    assert!(!map_b.contains_key_confirmed(&1).expect("Failed to call contains key"));
+
+    // Ensure the storage mode records the canonical directory.
+    let canonical = directory_a.canonicalize().expect("Failed to canonicalize");
+    assert_eq!(database_a.storage_mode(), &StorageMode::Custom(canonical));
+    // Ensure a directory that is already open cannot be opened again, even through a different path.
+    assert!(RocksDB::open(0, directory_a.join(".")).is_err());
+
+    // Ensure the database is closed once its handle and maps are dropped, and can be reopened.
+    drop((database_a, map_a));
+    let database_a = RocksDB::open(0, directory_a).expect("Failed to open database");
+    let map_a: TestMap = database_a.open_map(MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+    assert!(map_a.contains_key_confirmed(&1).expect("Failed to call contains key"));
+}
+
 // #[test]
 // #[serial]
 // fn test_export_import() {
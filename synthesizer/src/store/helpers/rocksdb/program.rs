@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use crate::store::{
-    helpers::rocksdb::{self, DataMap, Database, MapID, ProgramMap},
+    helpers::{
+        rocksdb::{self, DataMap, Database, MapID, ProgramMap},
+        StorageMode,
+    },
+    FinalizePreimage,
     FinalizeStorage,
 };
 use console::{
@@ -37,8 +41,26 @@ pub struct FinalizeDB<N: Network> {
     key_map: DataMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: DataMap<Field<N>, Value<N>>,
-    /// The optional development ID.
-    dev: Option<u16>,
+    /// The pre-image map.
+    preimage_map: DataMap<u32, FinalizePreimage<N>>,
+    /// The database.
+    database: rocksdb::RocksDB,
+}
+
+#[rustfmt::skip]
+impl<N: Network> FinalizeDB<N> {
+    /// Initializes the program state storage in the given database.
+    pub(crate) fn open_database(database: &rocksdb::RocksDB) -> Result<Self> {
+        Ok(Self {
+            program_id_map: database.open_map(MapID::Program(ProgramMap::ProgramID))?,
+            mapping_id_map: database.open_map(MapID::Program(ProgramMap::MappingID))?,
+            key_value_id_map: database.open_map(MapID::Program(ProgramMap::KeyValueID))?,
+            key_map: database.open_map(MapID::Program(ProgramMap::Key))?,
+            value_map: database.open_map(MapID::Program(ProgramMap::Value))?,
+            preimage_map: database.open_map(MapID::Program(ProgramMap::Preimage))?,
+            database: database.clone(),
+        })
+    }
 }
 
 #[rustfmt::skip]
@@ -48,17 +70,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type KeyValueIDMap = DataMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = DataMap<Field<N>, Plaintext<N>>;
     type ValueMap = DataMap<Field<N>, Value<N>>;
+    type PreimageMap = DataMap<u32, FinalizePreimage<N>>;
 
     /// Initializes the program state storage.
-    fn open(dev: Option<u16>) -> Result<Self> {
-        Ok(Self {
-            program_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::ProgramID))?,
-            mapping_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::MappingID))?,
-            key_value_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueID))?,
-            key_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Key))?,
-            value_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Value))?,
-            dev,
-        })
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Self::open_database(&rocksdb::RocksDB::open(N::ID, storage)?)
     }
 
     /// Returns the program ID map.
@@ -86,8 +102,13 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.value_map
     }
 
-    /// Returns the optional development ID.
-    fn dev(&self) -> Option<u16> {
-        self.dev
+    /// Returns the pre-image map.
+    fn preimage_map(&self) -> &Self::PreimageMap {
+        &self.preimage_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        self.database.storage_mode()
     }
 }
@@ -16,16 +16,9 @@ use crate::{
     program::Program,
     snark::{Certificate, Proof, VerifyingKey},
     store::{
-        helpers::rocksdb::{
-            self,
-            DataMap,
-            Database,
-            DeploymentMap,
-            ExecutionMap,
-            FeeMap,
-            MapID,
-            TransactionMap,
-            TransitionDB,
+        helpers::{
+            rocksdb::{DataMap, Database, DeploymentMap, ExecutionMap, FeeMap, MapID, TransactionMap, TransitionDB},
+            MapRead,
         },
         DeploymentStorage,
         DeploymentStore,
@@ -43,6 +36,8 @@ use console::{
     program::{Identifier, ProgramID, ProgramOwner},
 };
 
+use indexmap::IndexMap;
+
 /// A database transaction storage.
 #[derive(Clone)]
 pub struct TransactionDB<N: Network> {
@@ -72,8 +67,10 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
         let deployment_store = DeploymentStore::<N, DeploymentDB<N>>::open(fee_store.clone())?;
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionDB<N>>::open(fee_store.clone())?;
+        // Retrieve the database.
+        let database = fee_store.transition_store().storage().database();
         // Return the transaction storage.
-        Ok(Self { id_map: rocksdb::RocksDB::open_map(N::ID, execution_store.dev(), MapID::Transaction(TransactionMap::ID))?, deployment_store, execution_store, fee_store })
+        Ok(Self { id_map: database.open_map(MapID::Transaction(TransactionMap::ID))?, deployment_store, execution_store, fee_store })
     }
 
     /// Returns the ID map.
@@ -102,7 +99,7 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
 #[allow(clippy::type_complexity)]
 pub struct DeploymentDB<N: Network> {
     /// The ID map.
-    id_map: DataMap<N::TransactionID, ProgramID<N>>,
+    id_map: DataMap<N::TransactionID, (ProgramID<N>, u16)>,
     /// The edition map.
     edition_map: DataMap<ProgramID<N>, u16>,
     /// The reverse ID map.
@@ -121,7 +118,7 @@ pub struct DeploymentDB<N: Network> {
 
 #[rustfmt::skip]
 impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
-    type IDMap = DataMap<N::TransactionID, ProgramID<N>>;
+    type IDMap = DataMap<N::TransactionID, (ProgramID<N>, u16)>;
     type EditionMap = DataMap<ProgramID<N>, u16>;
     type ReverseIDMap = DataMap<(ProgramID<N>, u16), N::TransactionID>;
     type OwnerMap = DataMap<(ProgramID<N>, u16), ProgramOwner<N>>;
@@ -132,18 +129,25 @@ impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
 
     /// Initializes the deployment storage.
     fn open(fee_store: FeeStore<N, Self::FeeStorage>) -> Result<Self> {
-        // Retrieve the optional development ID.
-        let dev = fee_store.dev();
-        Ok(Self {
-            id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::ID))?,
-            edition_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::Edition))?,
-            reverse_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::ReverseID))?,
-            owner_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::Owner))?,
-            program_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::Program))?,
-            verifying_key_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::VerifyingKey))?,
-            certificate_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Deployment(DeploymentMap::Certificate))?,
+        // Retrieve the database.
+        let database = fee_store.transition_store().storage().database().clone();
+        let storage = Self {
+            id_map: database.open_map(MapID::Deployment(DeploymentMap::ID))?,
+            edition_map: database.open_map(MapID::Deployment(DeploymentMap::Edition))?,
+            reverse_id_map: database.open_map(MapID::Deployment(DeploymentMap::ReverseID))?,
+            owner_map: database.open_map(MapID::Deployment(DeploymentMap::Owner))?,
+            program_map: database.open_map(MapID::Deployment(DeploymentMap::Program))?,
+            verifying_key_map: database.open_map(MapID::Deployment(DeploymentMap::VerifyingKey))?,
+            certificate_map: database.open_map(MapID::Deployment(DeploymentMap::Certificate))?,
             fee_store,
-        })
+        };
+        // Ensure the storage was not written before program editions, as its deployments cannot be read.
+        // Note: A storage from before program editions must be rebuilt from genesis.
+        ensure!(
+            storage.id_map.keys_confirmed().next().is_none() || storage.edition_map.keys_confirmed().next().is_some(),
+            "The deployment storage predates program editions, and must be rebuilt from genesis"
+        );
+        Ok(storage)
     }
 
     /// Returns the ID map.
@@ -197,6 +201,8 @@ pub struct ExecutionDB<N: Network> {
     reverse_id_map: DataMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
     inclusion_map: DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The edition map.
+    edition_map: DataMap<N::TransactionID, IndexMap<ProgramID<N>, u16>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeDB<N>>,
 }
@@ -206,16 +212,18 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
     type IDMap = DataMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = DataMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type EditionMap = DataMap<N::TransactionID, IndexMap<ProgramID<N>, u16>>;
     type FeeStorage = FeeDB<N>;
 
     /// Initializes the execution storage.
     fn open(fee_store: FeeStore<N, Self::FeeStorage>) -> Result<Self> {
-        // Retrieve the optional development ID.
-        let dev = fee_store.dev();
+        // Retrieve the database.
+        let database = fee_store.transition_store().storage().database().clone();
         Ok(Self {
-            id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Execution(ExecutionMap::ID))?,
-            reverse_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Execution(ExecutionMap::ReverseID))?,
-            inclusion_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Execution(ExecutionMap::Inclusion))?,
+            id_map: database.open_map(MapID::Execution(ExecutionMap::ID))?,
+            reverse_id_map: database.open_map(MapID::Execution(ExecutionMap::ReverseID))?,
+            inclusion_map: database.open_map(MapID::Execution(ExecutionMap::Inclusion))?,
+            edition_map: database.open_map(MapID::Execution(ExecutionMap::Edition))?,
             fee_store,
         })
     }
@@ -235,6 +243,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
         &self.inclusion_map
     }
 
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap {
+        &self.edition_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
@@ -261,11 +274,11 @@ impl<N: Network> FeeStorage<N> for FeeDB<N> {
 
     /// Initializes the fee storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
-        // Retrieve the optional development ID.
-        let dev = transition_store.dev();
+        // Retrieve the database.
+        let database = transition_store.storage().database().clone();
         Ok(Self {
-            fee_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Fee(FeeMap::Fee))?,
-            reverse_fee_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Fee(FeeMap::ReverseFee))?,
+            fee_map: database.open_map(MapID::Fee(FeeMap::Fee))?,
+            reverse_fee_map: database.open_map(MapID::Fee(FeeMap::ReverseFee))?,
             transition_store,
         })
     }
@@ -15,7 +15,10 @@
 use crate::{
     snark::Proof,
     store::{
-        helpers::rocksdb::{self, DataMap, Database, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+        helpers::{
+            rocksdb::{self, DataMap, Database, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+            StorageMode,
+        },
         InputStorage,
         InputStore,
         OutputStorage,
@@ -50,6 +53,32 @@ pub struct TransitionDB<N: Network> {
     tcm_map: DataMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: DataMap<Field<N>, N::TransitionID>,
+    /// The database.
+    database: rocksdb::RocksDB,
+}
+
+#[rustfmt::skip]
+impl<N: Network> TransitionDB<N> {
+    /// Initializes the transition storage in the given database.
+    pub(crate) fn open_database(database: &rocksdb::RocksDB) -> Result<Self> {
+        Ok(Self {
+            locator_map: database.open_map(MapID::Transition(TransitionMap::Locator))?,
+            input_store: InputStore::from(InputDB::open_database(database)?),
+            output_store: OutputStore::from(OutputDB::open_database(database)?),
+            finalize_map: database.open_map(MapID::Transition(TransitionMap::Finalize))?,
+            proof_map: database.open_map(MapID::Transition(TransitionMap::Proof))?,
+            tpk_map: database.open_map(MapID::Transition(TransitionMap::TPK))?,
+            reverse_tpk_map: database.open_map(MapID::Transition(TransitionMap::ReverseTPK))?,
+            tcm_map: database.open_map(MapID::Transition(TransitionMap::TCM))?,
+            reverse_tcm_map: database.open_map(MapID::Transition(TransitionMap::ReverseTCM))?,
+            database: database.clone(),
+        })
+    }
+
+    /// Returns the database.
+    pub(crate) const fn database(&self) -> &rocksdb::RocksDB {
+        &self.database
+    }
 }
 
 #[rustfmt::skip]
@@ -65,18 +94,8 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     type ReverseTCMMap = DataMap<Field<N>, N::TransitionID>;
 
     /// Initializes the transition storage.
-    fn open(dev: Option<u16>) -> Result<Self> {
-        Ok(Self {
-            locator_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::Locator))?,
-            input_store: InputStore::open(dev)?,
-            output_store: OutputStore::open(dev)?,
-            finalize_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::Finalize))?,
-            proof_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::Proof))?,
-            tpk_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::TPK))?,
-            reverse_tpk_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::ReverseTPK))?,
-            tcm_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::TCM))?,
-            reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, dev,  MapID::Transition(TransitionMap::ReverseTCM))?,
-        })
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Self::open_database(&rocksdb::RocksDB::open(N::ID, storage)?)
     }
 
     /// Returns the transition program IDs and function names.
@@ -144,8 +163,26 @@ pub struct InputDB<N: Network> {
     record_tag: DataMap<Field<N>, Field<N>>,
     /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
     external_record: DataMap<Field<N>, ()>,
-    /// The optional development ID.
-    dev: Option<u16>,
+    /// The database.
+    database: rocksdb::RocksDB,
+}
+
+#[rustfmt::skip]
+impl<N: Network> InputDB<N> {
+    /// Initializes the transition input storage in the given database.
+    fn open_database(database: &rocksdb::RocksDB) -> Result<Self> {
+        Ok(Self {
+            id_map: database.open_map(MapID::TransitionInput(TransitionInputMap::ID))?,
+            reverse_id_map: database.open_map(MapID::TransitionInput(TransitionInputMap::ReverseID))?,
+            constant: database.open_map(MapID::TransitionInput(TransitionInputMap::Constant))?,
+            public: database.open_map(MapID::TransitionInput(TransitionInputMap::Public))?,
+            private: database.open_map(MapID::TransitionInput(TransitionInputMap::Private))?,
+            record: database.open_map(MapID::TransitionInput(TransitionInputMap::Record))?,
+            record_tag: database.open_map(MapID::TransitionInput(TransitionInputMap::RecordTag))?,
+            external_record: database.open_map(MapID::TransitionInput(TransitionInputMap::ExternalRecord))?,
+            database: database.clone(),
+        })
+    }
 }
 
 #[rustfmt::skip]
@@ -160,18 +197,8 @@ impl<N: Network> InputStorage<N> for InputDB<N> {
     type ExternalRecordMap = DataMap<Field<N>, ()>;
 
     /// Initializes the transition input storage.
-    fn open(dev: Option<u16>) -> Result<Self> {
-        Ok(Self {
-            id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionInput(TransitionInputMap::ID))?,
-            reverse_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionInput(TransitionInputMap::ReverseID))?,
-            constant: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionInput(TransitionInputMap::Constant))?,
-            public: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionInput(TransitionInputMap::Public))?,
-            private: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionInput(TransitionInputMap::Private))?,
-            record: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionInput(TransitionInputMap::Record))?,
-            record_tag: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionInput(TransitionInputMap::RecordTag))?,
-            external_record: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionInput(TransitionInputMap::ExternalRecord))?,
-            dev,
-        })
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Self::open_database(&rocksdb::RocksDB::open(N::ID, storage)?)
     }
 
     /// Returns the ID map.
@@ -214,9 +241,9 @@ impl<N: Network> InputStorage<N> for InputDB<N> {
         &self.external_record
     }
 
-    /// Returns the optional development ID.
-    fn dev(&self) -> Option<u16> {
-        self.dev
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        self.database.storage_mode()
     }
 }
 
@@ -240,8 +267,26 @@ pub struct OutputDB<N: Network> {
     record_nonce: DataMap<Group<N>, Field<N>>,
     /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
     external_record: DataMap<Field<N>, ()>,
-    /// The optional development ID.
-    dev: Option<u16>,
+    /// The database.
+    database: rocksdb::RocksDB,
+}
+
+#[rustfmt::skip]
+impl<N: Network> OutputDB<N> {
+    /// Initializes the transition output storage in the given database.
+    fn open_database(database: &rocksdb::RocksDB) -> Result<Self> {
+        Ok(Self {
+            id_map: database.open_map(MapID::TransitionOutput(TransitionOutputMap::ID))?,
+            reverse_id_map: database.open_map(MapID::TransitionOutput(TransitionOutputMap::ReverseID))?,
+            constant: database.open_map(MapID::TransitionOutput(TransitionOutputMap::Constant))?,
+            public: database.open_map(MapID::TransitionOutput(TransitionOutputMap::Public))?,
+            private: database.open_map(MapID::TransitionOutput(TransitionOutputMap::Private))?,
+            record: database.open_map(MapID::TransitionOutput(TransitionOutputMap::Record))?,
+            record_nonce: database.open_map(MapID::TransitionOutput(TransitionOutputMap::RecordNonce))?,
+            external_record: database.open_map(MapID::TransitionOutput(TransitionOutputMap::ExternalRecord))?,
+            database: database.clone(),
+        })
+    }
 }
 
 #[rustfmt::skip]
@@ -256,18 +301,8 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
     type ExternalRecordMap = DataMap<Field<N>, ()>;
 
     /// Initializes the transition output storage.
-    fn open(dev: Option<u16>) -> Result<Self> {
-        Ok(Self {
-            id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::ID))?,
-            reverse_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::ReverseID))?,
-            constant: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::Constant))?,
-            public: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::Public))?,
-            private: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::Private))?,
-            record: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::Record))?,
-            record_nonce: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::RecordNonce))?,
-            external_record: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::ExternalRecord))?,
-            dev,
-        })
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Self::open_database(&rocksdb::RocksDB::open(N::ID, storage)?)
     }
 
     /// Returns the ID map.
@@ -310,8 +345,8 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
         &self.external_record
     }
 
-    /// Returns the optional development ID.
-    fn dev(&self) -> Option<u16> {
-        self.dev
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        self.database.storage_mode()
     }
 }
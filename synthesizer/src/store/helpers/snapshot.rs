@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{atomic_batch_scope, store::helpers::Map};
+use console::network::prelude::*;
+
+use blake2::{Blake2s256, Digest};
+use core::hash::Hash;
+use serde::de::DeserializeOwned;
+
+/// The number of bytes in the checksum of a snapshot section.
+pub const SNAPSHOT_CHECKSUM_SIZE: usize = 32;
+
+/// Returns the checksum of a snapshot section, with the given name, number of entries, and entries.
+fn section_checksum(name: &str, num_entries: u64, entries: &[u8]) -> [u8; SNAPSHOT_CHECKSUM_SIZE] {
+    let mut hasher = Blake2s256::new();
+    hasher.update(name.as_bytes());
+    hasher.update(num_entries.to_le_bytes());
+    hasher.update(entries);
+    hasher.finalize().into()
+}
+
+/// A writer of the maps of a storage into a snapshot.
+///
+/// Each map is written as a section, consisting of the map name, the number of entries,
+/// the serialized entries, and a checksum over all of the above.
+pub struct SnapshotWriter<W: Write> {
+    /// The underlying writer.
+    writer: W,
+}
+
+impl<W: Write> SnapshotWriter<W> {
+    /// Initializes a new snapshot writer.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Writes the confirmed entries of the given map, as a section with the given name.
+    pub fn write_map<K, V, M>(&mut self, name: &str, map: &M) -> Result<()>
+    where
+        K: 'static + Copy + Clone + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+        V: 'static + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+        M: for<'a> Map<'a, K, V>,
+    {
+        // Serialize the entries of the map.
+        let mut entries = Vec::new();
+        let mut num_entries = 0u64;
+        for (key, value) in map.iter_confirmed() {
+            bincode::serialize_into(&mut entries, &(&*key, &*value))?;
+            num_entries += 1;
+        }
+
+        // Write the section.
+        let name_length = u16::try_from(name.len())?;
+        name_length.write_le(&mut self.writer)?;
+        self.writer.write_all(name.as_bytes())?;
+        num_entries.write_le(&mut self.writer)?;
+        (entries.len() as u64).write_le(&mut self.writer)?;
+        self.writer.write_all(&entries)?;
+        self.writer.write_all(&section_checksum(name, num_entries, &entries))?;
+        Ok(())
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// A reader of the maps of a storage from a snapshot.
+pub struct SnapshotReader<'a, R: Read> {
+    /// The underlying reader.
+    reader: R,
+    /// The functions to remove the entries of each map that a section has been imported into.
+    imported: Vec<Box<dyn Fn() -> Result<()> + 'a>>,
+}
+
+impl<'a, R: Read> SnapshotReader<'a, R> {
+    /// Initializes a new snapshot reader.
+    pub const fn new(reader: R) -> Self {
+        Self { reader, imported: Vec::new() }
+    }
+
+    /// Reads the section with the given name, and inserts its entries into the given (empty) map.
+    pub fn read_map<K, V, M>(&mut self, name: &str, map: &'a M) -> Result<()>
+    where
+        K: 'static + Copy + Clone + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+        V: 'static + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+        M: for<'b> Map<'b, K, V>,
+    {
+        // Read the section name.
+        let name_length = u16::read_le(&mut self.reader)?;
+        let mut section = vec![0u8; name_length as usize];
+        self.reader.read_exact(&mut section)?;
+        let section = String::from_utf8(section)?;
+        ensure!(section == name, "Expected the snapshot section '{name}', found '{section}'");
+
+        // Read the entries.
+        let num_entries = u64::read_le(&mut self.reader)?;
+        let entries_length = u64::read_le(&mut self.reader)?;
+        let mut entries = Vec::new();
+        (&mut self.reader).take(entries_length).read_to_end(&mut entries)?;
+        ensure!(entries.len() as u64 == entries_length, "The snapshot section '{name}' is truncated");
+
+        // Ensure the checksum matches.
+        let mut checksum = [0u8; SNAPSHOT_CHECKSUM_SIZE];
+        self.reader.read_exact(&mut checksum)?;
+        ensure!(
+            checksum == section_checksum(name, num_entries, &entries),
+            "The checksum of the snapshot section '{name}' does not match"
+        );
+
+        // Deserialize the entries.
+        let mut cursor = entries.as_slice();
+        let entries = (0..num_entries)
+            .map(|_| Ok(bincode::deserialize_from::<_, (K, V)>(&mut cursor)?))
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(cursor.is_empty(), "The snapshot section '{name}' contains trailing bytes");
+
+        // Ensure the map is empty.
+        ensure!(
+            map.keys_confirmed().next().is_none(),
+            "Cannot import the snapshot section '{name}' into a non-empty map"
+        );
+        // Insert the entries into the map.
+        atomic_batch_scope!(map, {
+            for (key, value) in entries {
+                map.insert(key, value)?;
+            }
+            Ok(())
+        })?;
+
+        // Track the map, so that its entries can be removed if the import fails.
+        self.imported.push(Box::new(move || {
+            let keys = map.keys_confirmed().map(|key| *key).collect::<Vec<_>>();
+            atomic_batch_scope!(map, {
+                for key in keys {
+                    map.remove(&key)?;
+                }
+                Ok(())
+            })
+        }));
+        Ok(())
+    }
+
+    /// Ensures the snapshot has no trailing bytes.
+    pub fn ensure_finished(&mut self) -> Result<()> {
+        ensure!(self.reader.read(&mut [0u8])? == 0, "The snapshot contains trailing bytes");
+        Ok(())
+    }
+
+    /// Removes the entries of every map that a section has been imported into,
+    /// restoring the storage to its (empty) state prior to the import.
+    pub fn remove_imported(&mut self) -> Result<()> {
+        // Remove the entries in the reverse order of the import.
+        while let Some(remove) = self.imported.pop() {
+            remove()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::helpers::{memory::MemoryMap, MapRead};
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        // Initialize a map.
+        let map: MemoryMap<u32, String> = (0..10).map(|i| (i, i.to_string())).collect();
+
+        // Write the map into a snapshot.
+        let mut writer = SnapshotWriter::new(Vec::new());
+        writer.write_map("test", &map).unwrap();
+        let bytes = writer.into_inner();
+
+        // Read the snapshot into a new map.
+        let imported = MemoryMap::<u32, String>::default();
+        SnapshotReader::new(bytes.as_slice()).read_map("test", &imported).unwrap();
+        for i in 0..10 {
+            assert_eq!(imported.get_confirmed(&i).unwrap().unwrap().into_owned(), i.to_string());
+        }
+        assert_eq!(imported.keys_confirmed().count(), 10);
+
+        // Ensure the snapshot is not read into a non-empty map.
+        assert!(SnapshotReader::new(bytes.as_slice()).read_map("test", &imported).is_err());
+        // Ensure the snapshot is not read under a different name.
+        let other = MemoryMap::<u32, String>::default();
+        assert!(SnapshotReader::new(bytes.as_slice()).read_map("other", &other).is_err());
+
+        // Ensure a corrupted snapshot is rejected.
+        let mut corrupted = bytes.clone();
+        let index = corrupted.len() / 2;
+        corrupted[index] ^= 1;
+        assert!(SnapshotReader::new(corrupted.as_slice()).read_map("test", &other).is_err());
+        // Ensure a truncated snapshot is rejected.
+        assert!(SnapshotReader::new(&bytes[..bytes.len() - 1]).read_map("test", &other).is_err());
+        assert!(other.keys_confirmed().next().is_none());
+
+        // Ensure the imported entries are removed.
+        let mut reader = SnapshotReader::new(bytes.as_slice());
+        reader.read_map("test", &other).unwrap();
+        assert_eq!(other.keys_confirmed().count(), 10);
+        reader.remove_imported().unwrap();
+        assert!(other.keys_confirmed().next().is_none());
+    }
+}
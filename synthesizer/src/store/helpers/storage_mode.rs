@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+
+/// The mode in which the ledger storage is opened.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StorageMode {
+    /// The production mode, which stores the ledger in `~/.aleo/storage/ledger-{network}`.
+    Production,
+    /// The development mode, which stores the ledger in `/path/to/repo/.ledger-{network}-{id}`.
+    Development(u16),
+    /// The custom mode, which stores the ledger in the given directory.
+    Custom(PathBuf),
+}
+
+impl StorageMode {
+    /// Returns the development ID, if the storage is in development mode.
+    pub const fn dev(&self) -> Option<u16> {
+        match self {
+            Self::Development(id) => Some(*id),
+            Self::Production | Self::Custom(..) => None,
+        }
+    }
+}
+
+impl From<Option<u16>> for StorageMode {
+    /// Returns the development mode for the given development ID, or the production mode if there is none.
+    fn from(dev: Option<u16>) -> Self {
+        match dev {
+            Some(id) => Self::Development(id),
+            None => Self::Production,
+        }
+    }
+}
+
+impl From<PathBuf> for StorageMode {
+    /// Returns the custom mode for the given directory.
+    fn from(path: PathBuf) -> Self {
+        Self::Custom(path)
+    }
+}
@@ -17,7 +17,7 @@ use crate::{
     block::FinalizeOperation,
     cow_to_cloned,
     cow_to_copied,
-    store::helpers::{Map, MapRead},
+    store::helpers::{Map, MapRead, SnapshotReader, SnapshotWriter, StorageMode},
 };
 use console::{
     network::prelude::*,
@@ -26,8 +26,60 @@ use console::{
 };
 
 use anyhow::Result;
-use core::marker::PhantomData;
+use core::{hash::Hash, marker::PhantomData};
 use indexmap::{IndexMap, IndexSet};
+use serde::de::DeserializeOwned;
+
+/// The finalize state that was overwritten by the finalize operations of a block.
+///
+/// For each map of the finalize storage, the pre-image contains every key that was written by the block,
+/// along with its value before the block (or `None`, if the key did not exist).
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct FinalizePreimage<N: Network> {
+    /// The pre-image of the program ID map.
+    program_ids: Vec<(ProgramID<N>, Option<IndexSet<Identifier<N>>>)>,
+    /// The pre-image of the mapping ID map.
+    mapping_ids: Vec<((ProgramID<N>, Identifier<N>), Option<Field<N>>)>,
+    /// The pre-image of the key-value ID map.
+    key_value_ids: Vec<(Field<N>, Option<IndexMap<Field<N>, Field<N>>>)>,
+    /// The pre-image of the key map.
+    keys: Vec<(Field<N>, Option<Plaintext<N>>)>,
+    /// The pre-image of the value map.
+    values: Vec<(Field<N>, Option<Value<N>>)>,
+}
+
+/// Returns the confirmed value of each key in the atomic batch of the given map.
+fn pending_preimage<K, V, M>(map: &M) -> Result<Vec<(K, Option<V>)>>
+where
+    K: 'static + Copy + Clone + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+    V: 'static + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+    M: for<'a> Map<'a, K, V>,
+{
+    map.iter_pending()
+        .map(|(key, _)| {
+            let key = cow_to_copied!(key);
+            let value = map.get_confirmed(&key)?.map(|value| cow_to_cloned!(value));
+            Ok((key, value))
+        })
+        .collect()
+}
+
+/// Restores the given pre-image into the given map, within the current atomic batch.
+fn restore_preimage<K, V, M>(map: &M, preimage: Vec<(K, Option<V>)>) -> Result<()>
+where
+    K: 'static + Copy + Clone + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+    V: 'static + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+    M: for<'a> Map<'a, K, V>,
+{
+    for (key, value) in preimage {
+        match value {
+            Some(value) => map.insert(key, value)?,
+            None => map.remove(&key)?,
+        }
+    }
+    Ok(())
+}
 
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
@@ -51,9 +103,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type KeyMap: for<'a> Map<'a, Field<N>, Plaintext<N>>;
     /// The mapping of `key ID` to `value`.
     type ValueMap: for<'a> Map<'a, Field<N>, Value<N>>;
+    /// The mapping of `block height` to `finalize pre-image`.
+    type PreimageMap: for<'a> Map<'a, u32, FinalizePreimage<N>>;
 
     /// Initializes the program state storage.
-    fn open(dev: Option<u16>) -> Result<Self>;
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
 
     /// Returns the program ID map.
     fn program_id_map(&self) -> &Self::ProgramIDMap;
@@ -65,9 +119,16 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn key_map(&self) -> &Self::KeyMap;
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap;
+    /// Returns the pre-image map.
+    fn preimage_map(&self) -> &Self::PreimageMap;
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode;
 
     /// Returns the optional development ID.
-    fn dev(&self) -> Option<u16>;
+    fn dev(&self) -> Option<u16> {
+        self.storage_mode().dev()
+    }
 
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
@@ -76,6 +137,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().start_atomic();
         self.key_map().start_atomic();
         self.value_map().start_atomic();
+        self.preimage_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -85,6 +147,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.key_value_id_map().is_atomic_in_progress()
             || self.key_map().is_atomic_in_progress()
             || self.value_map().is_atomic_in_progress()
+            || self.preimage_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -94,6 +157,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().atomic_checkpoint();
         self.key_map().atomic_checkpoint();
         self.value_map().atomic_checkpoint();
+        self.preimage_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -103,6 +167,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().clear_latest_checkpoint();
         self.key_map().clear_latest_checkpoint();
         self.value_map().clear_latest_checkpoint();
+        self.preimage_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -112,6 +177,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().atomic_rewind();
         self.key_map().atomic_rewind();
         self.value_map().atomic_rewind();
+        self.preimage_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -121,6 +187,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().abort_atomic();
         self.key_map().abort_atomic();
         self.value_map().abort_atomic();
+        self.preimage_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -129,7 +196,30 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().finish_atomic()?;
         self.key_value_id_map().finish_atomic()?;
         self.key_map().finish_atomic()?;
-        self.value_map().finish_atomic()
+        self.value_map().finish_atomic()?;
+        self.preimage_map().finish_atomic()
+    }
+
+    /// Writes the maps of the finalize storage into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        snapshot.write_map("finalize.program_id", self.program_id_map())?;
+        snapshot.write_map("finalize.mapping_id", self.mapping_id_map())?;
+        snapshot.write_map("finalize.key_value_id", self.key_value_id_map())?;
+        snapshot.write_map("finalize.key", self.key_map())?;
+        snapshot.write_map("finalize.value", self.value_map())?;
+        snapshot.write_map("finalize.preimage", self.preimage_map())?;
+        Ok(())
+    }
+
+    /// Reads the maps of the finalize storage from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        snapshot.read_map("finalize.program_id", self.program_id_map())?;
+        snapshot.read_map("finalize.mapping_id", self.mapping_id_map())?;
+        snapshot.read_map("finalize.key_value_id", self.key_value_id_map())?;
+        snapshot.read_map("finalize.key", self.key_map())?;
+        snapshot.read_map("finalize.value", self.value_map())?;
+        snapshot.read_map("finalize.preimage", self.preimage_map())?;
+        Ok(())
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -434,6 +524,76 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Records the pending writes of the current atomic batch, as the finalize pre-image of the given block height,
+    /// and prunes the finalize pre-images that are `max_reorg_depth` or more blocks before the given block height.
+    /// This method must be called within the atomic batch, after all of the finalize operations of the block.
+    fn record_preimage(&self, block_height: u32, max_reorg_depth: u32) -> Result<()> {
+        // Ensure an atomic batch is in progress.
+        if !self.is_atomic_in_progress() {
+            bail!("Illegal operation: cannot record the finalize pre-image outside of an atomic batch.")
+        }
+        // Ensure the pre-image does not already exist.
+        if self.preimage_map().contains_key_speculative(&block_height)? {
+            bail!("Illegal operation: the finalize pre-image of block {block_height} already exists in storage.")
+        }
+
+        // Retrieve the prior value of each pending key.
+        let preimage = FinalizePreimage {
+            program_ids: pending_preimage(self.program_id_map())?,
+            mapping_ids: pending_preimage(self.mapping_id_map())?,
+            key_value_ids: pending_preimage(self.key_value_id_map())?,
+            keys: pending_preimage(self.key_map())?,
+            values: pending_preimage(self.value_map())?,
+        };
+
+        // Store the pre-image, if the block can be reverted.
+        if max_reorg_depth > 0 {
+            self.preimage_map().insert(block_height, preimage)?;
+        }
+
+        // Prune the pre-images of the blocks that can no longer be reverted.
+        let pruned = self
+            .preimage_map()
+            .keys_confirmed()
+            .map(|height| cow_to_copied!(height))
+            .filter(|height| height.saturating_add(max_reorg_depth) <= block_height)
+            .collect::<Vec<_>>();
+        for height in pruned {
+            self.preimage_map().remove(&height)?;
+        }
+        Ok(())
+    }
+
+    /// Reverts the finalize operations of the given block height, by restoring its finalize pre-image.
+    /// Note: The blocks must be reverted in descending order of block height.
+    fn revert_preimage(&self, block_height: u32) -> Result<()> {
+        // Retrieve the pre-image.
+        let preimage = match self.preimage_map().get_speculative(&block_height)? {
+            Some(preimage) => cow_to_cloned!(preimage),
+            None => {
+                bail!("Illegal operation: the finalize pre-image of block {block_height} is missing - cannot revert.")
+            }
+        };
+
+        atomic_batch_scope!(self, {
+            // Restore the prior values.
+            restore_preimage(self.program_id_map(), preimage.program_ids)?;
+            restore_preimage(self.mapping_id_map(), preimage.mapping_ids)?;
+            restore_preimage(self.key_value_id_map(), preimage.key_value_ids)?;
+            restore_preimage(self.key_map(), preimage.keys)?;
+            restore_preimage(self.value_map(), preimage.values)?;
+            // Remove the pre-image.
+            self.preimage_map().remove(&block_height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns `true` if the finalize pre-image of the given block height exists.
+    fn contains_preimage_confirmed(&self, block_height: u32) -> Result<bool> {
+        self.preimage_map().contains_key_confirmed(&block_height)
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
@@ -547,8 +707,8 @@ pub struct FinalizeStore<N: Network, P: FinalizeStorage<N>> {
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Initializes the finalize store.
-    pub fn open(dev: Option<u16>) -> Result<Self> {
-        Self::from(P::open(dev)?)
+    pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Self::from(P::open(storage)?)
     }
 
     /// Initializes a finalize store from storage.
@@ -620,6 +780,19 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.remove_program(program_id)
     }
 
+    /// Records the pending writes of the current atomic batch, as the finalize pre-image of the given block height,
+    /// and prunes the finalize pre-images that are `max_reorg_depth` or more blocks before the given block height.
+    /// This method must be called within the atomic batch, after all of the finalize operations of the block.
+    pub fn record_preimage(&self, block_height: u32, max_reorg_depth: u32) -> Result<()> {
+        self.storage.record_preimage(block_height, max_reorg_depth)
+    }
+
+    /// Reverts the finalize operations of the given block height, by restoring its finalize pre-image.
+    /// Note: The blocks must be reverted in descending order of block height.
+    pub fn revert_preimage(&self, block_height: u32) -> Result<()> {
+        self.storage.revert_preimage(block_height)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -655,6 +828,21 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the finalize storage into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the finalize storage from the given snapshot.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.storage.import_snapshot(snapshot)
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
@@ -662,6 +850,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns `true` if the finalize pre-image of the given block height exists.
+    pub fn contains_preimage_confirmed(&self, block_height: u32) -> Result<bool> {
+        self.storage.contains_preimage_confirmed(block_height)
+    }
+
     /// Returns `true` if the given `program ID` exist.
     pub fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.storage.contains_program_confirmed(program_id)
@@ -1121,6 +1314,99 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_record_revert_preimage() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Prepare a key and values.
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let value_1 = Value::from_str("1u64").unwrap();
+        let value_2 = Value::from_str("2u64").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<CurrentNetwork>>::open(None).unwrap();
+        // Ensure a pre-image cannot be recorded outside of an atomic batch.
+        assert!(finalize_store.record_preimage(0, 2).is_err());
+
+        // Initialize the mapping and insert a key-value pair, as block 1.
+        finalize_store.start_atomic();
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        finalize_store.insert_key_value(&program_id, &mapping_name, key.clone(), value_1.clone()).unwrap();
+        finalize_store.record_preimage(1, 2).unwrap();
+        finalize_store.finish_atomic().unwrap();
+
+        // Update the key-value pair, as block 2.
+        finalize_store.start_atomic();
+        finalize_store.update_key_value(&program_id, &mapping_name, key.clone(), value_2.clone()).unwrap();
+        finalize_store.record_preimage(2, 2).unwrap();
+        // Ensure the pre-image cannot be recorded twice.
+        assert!(finalize_store.record_preimage(2, 2).is_err());
+        finalize_store.finish_atomic().unwrap();
+        assert!(finalize_store.contains_preimage_confirmed(1).unwrap());
+        assert!(finalize_store.contains_preimage_confirmed(2).unwrap());
+        assert_eq!(value_2, finalize_store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().unwrap());
+
+        // Revert block 2.
+        finalize_store.revert_preimage(2).unwrap();
+        assert!(!finalize_store.contains_preimage_confirmed(2).unwrap());
+        assert_eq!(value_1, finalize_store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().unwrap());
+        // Ensure block 2 cannot be reverted twice.
+        assert!(finalize_store.revert_preimage(2).is_err());
+
+        // Revert block 1.
+        finalize_store.revert_preimage(1).unwrap();
+        assert!(!finalize_store.contains_preimage_confirmed(1).unwrap());
+        assert!(!finalize_store.contains_program_confirmed(&program_id).unwrap());
+        assert!(!finalize_store.contains_mapping_confirmed(&program_id, &mapping_name).unwrap());
+        assert!(finalize_store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_prune_preimages() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("123456789field").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<CurrentNetwork>>::open(None).unwrap();
+
+        // Initialize the mapping, as block 1.
+        finalize_store.start_atomic();
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        finalize_store.record_preimage(1, 2).unwrap();
+        finalize_store.finish_atomic().unwrap();
+
+        // Update the key-value pair, as blocks 2 to 4.
+        for block_height in 2..=4 {
+            let value = Value::from_str(&format!("{block_height}u64")).unwrap();
+            finalize_store.start_atomic();
+            finalize_store.update_key_value(&program_id, &mapping_name, key.clone(), value).unwrap();
+            finalize_store.record_preimage(block_height, 2).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        }
+
+        // Ensure only the pre-images of the last 2 blocks are kept.
+        assert!(!finalize_store.contains_preimage_confirmed(1).unwrap());
+        assert!(!finalize_store.contains_preimage_confirmed(2).unwrap());
+        assert!(finalize_store.contains_preimage_confirmed(3).unwrap());
+        assert!(finalize_store.contains_preimage_confirmed(4).unwrap());
+        // Ensure a pruned block cannot be reverted.
+        finalize_store.revert_preimage(4).unwrap();
+        finalize_store.revert_preimage(3).unwrap();
+        assert!(finalize_store.revert_preimage(2).is_err());
+        let value = Value::from_str("2u64").unwrap();
+        assert_eq!(value, finalize_store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().unwrap());
+
+        // Ensure a pre-image is not kept without a reorg depth.
+        finalize_store.start_atomic();
+        finalize_store.remove_key_value(&program_id, &mapping_name, &key).unwrap();
+        finalize_store.record_preimage(3, 0).unwrap();
+        finalize_store.finish_atomic().unwrap();
+        assert!(!finalize_store.contains_preimage_confirmed(3).unwrap());
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
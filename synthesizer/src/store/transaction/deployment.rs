@@ -20,7 +20,7 @@ use crate::{
     program::Program,
     snark::{Certificate, VerifyingKey},
     store::{
-        helpers::{Map, MapRead},
+        helpers::{Map, MapRead, SnapshotReader, SnapshotWriter, StorageMode},
         FeeStorage,
         FeeStore,
     },
@@ -35,9 +35,11 @@ use core::marker::PhantomData;
 use std::borrow::Cow;
 
 /// A trait for deployment storage.
+/// Note: Deployments are keyed by `(program ID, edition)`, so a storage written before program editions
+/// cannot be read, and must be rebuilt from genesis.
 pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
-    /// The mapping of `transaction ID` to `program ID`.
-    type IDMap: for<'a> Map<'a, N::TransactionID, ProgramID<N>>;
+    /// The mapping of `transaction ID` to `(program ID, edition)`.
+    type IDMap: for<'a> Map<'a, N::TransactionID, (ProgramID<N>, u16)>;
     /// The mapping of `program ID` to `edition`.
     type EditionMap: for<'a> Map<'a, ProgramID<N>, u16>;
     /// The mapping of `(program ID, edition)` to `transaction ID`.
@@ -73,9 +75,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     /// Returns the fee storage.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
 
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        self.fee_store().storage_mode()
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
-        self.fee_store().dev()
+        self.storage_mode().dev()
     }
 
     /// Starts an atomic batch write operation.
@@ -162,6 +169,30 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().finish_atomic()
     }
 
+    /// Writes the maps of the deployment storage (excluding the fee store) into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        snapshot.write_map("deployment.id", self.id_map())?;
+        snapshot.write_map("deployment.edition", self.edition_map())?;
+        snapshot.write_map("deployment.reverse_id", self.reverse_id_map())?;
+        snapshot.write_map("deployment.owner", self.owner_map())?;
+        snapshot.write_map("deployment.program", self.program_map())?;
+        snapshot.write_map("deployment.verifying_key", self.verifying_key_map())?;
+        snapshot.write_map("deployment.certificate", self.certificate_map())?;
+        Ok(())
+    }
+
+    /// Reads the maps of the deployment storage (excluding the fee store) from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        snapshot.read_map("deployment.id", self.id_map())?;
+        snapshot.read_map("deployment.edition", self.edition_map())?;
+        snapshot.read_map("deployment.reverse_id", self.reverse_id_map())?;
+        snapshot.read_map("deployment.owner", self.owner_map())?;
+        snapshot.read_map("deployment.program", self.program_map())?;
+        snapshot.read_map("deployment.verifying_key", self.verifying_key_map())?;
+        snapshot.read_map("deployment.certificate", self.certificate_map())?;
+        Ok(())
+    }
+
     /// Stores the given `deployment transaction` pair into storage.
     fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
         // Ensure the transaction is a deployment.
@@ -183,9 +214,19 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the program ID.
         let program_id = *program.id();
 
+        // Ensure the edition follows the latest deployed edition of the program.
+        match self.get_edition(&program_id)? {
+            Some(latest) => ensure!(
+                latest.checked_add(1) == Some(edition),
+                "Failed to insert deployment of '{program_id}': expected edition {}, found {edition}",
+                latest.saturating_add(1)
+            ),
+            None => ensure!(edition == N::EDITION, "Failed to insert deployment of '{program_id}' (edition {edition})"),
+        }
+
         atomic_batch_scope!(self, {
-            // Store the program ID.
-            self.id_map().insert(*transaction_id, program_id)?;
+            // Store the program ID and edition.
+            self.id_map().insert(*transaction_id, (program_id, edition))?;
             // Store the edition.
             self.edition_map().insert(program_id, edition)?;
 
@@ -213,15 +254,23 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
 
     /// Removes the deployment transaction for the given `transaction ID`.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.id_map().get_confirmed(transaction_id)? {
+            Some(entry) => cow_to_copied!(entry),
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
+        // Ensure the deployment is the latest edition of the program.
+        match self.get_edition(&program_id)? {
+            Some(latest) => ensure!(
+                latest == edition,
+                "Failed to remove edition {edition} of '{program_id}', as edition {latest} is deployed"
+            ),
             None => bail!("Failed to locate the edition for program '{program_id}'"),
+        }
+        // Determine the previous edition, if one exists.
+        let previous_edition = match edition.checked_sub(1) {
+            Some(previous) => self.reverse_id_map().get_confirmed(&(program_id, previous))?.map(|_| previous),
+            None => None,
         };
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
@@ -232,8 +281,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Restore the previous edition, or remove the edition if this was the first deployment.
+            match previous_edition {
+                Some(previous) => self.edition_map().insert(program_id, previous)?,
+                None => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -283,7 +335,15 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         // Retrieve the program ID.
         match self.id_map().get_confirmed(transaction_id)? {
-            Some(program_id) => Ok(Some(cow_to_copied!(program_id))),
+            Some(entry) => Ok(Some(cow_to_copied!(entry).0)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the program ID and edition for the given `transaction ID`.
+    fn get_program_id_and_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<(ProgramID<N>, u16)>> {
+        match self.id_map().get_confirmed(transaction_id)? {
+            Some(entry) => Ok(Some(cow_to_copied!(entry))),
             None => Ok(None),
         }
     }
@@ -348,16 +408,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the deployment for the given `transaction ID`.
     fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.get_program_id_and_edition(transaction_id)? {
+            Some(entry) => entry,
             None => return Ok(None),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
-            None => bail!("Failed to get the edition for program '{program_id}'"),
-        };
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -392,9 +447,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().get_fee(transaction_id)
     }
 
-    /// Returns the owner for the given `program ID`.
+    /// Returns the owner of the latest edition for the given `program ID`.
     fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
-        // TODO (raychu86): Consider program upgrades and edition changes.
         // Retrieve the edition.
         let edition = match self.get_edition(program_id)? {
             Some(edition) => edition,
@@ -421,9 +475,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
         };
 
-        // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
-            Some(owner) => owner,
+        // Retrieve the owner of this edition.
+        let owner = match self.owner_map().get_confirmed(&(*deployment.program_id(), deployment.edition()))? {
+            Some(owner) => cow_to_copied!(owner),
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
 
@@ -505,6 +559,21 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the deployment storage (excluding the fee store) into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the deployment storage (excluding the fee store) from the given snapshot.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.storage.import_snapshot(snapshot)
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
@@ -532,11 +601,21 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_program_id(transaction_id)
     }
 
+    /// Returns the program ID and edition for the given `transaction ID`.
+    pub fn get_program_id_and_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<(ProgramID<N>, u16)>> {
+        self.storage.get_program_id_and_edition(transaction_id)
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.get_program(program_id)
     }
 
+    /// Returns the owner of the latest edition for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner(program_id)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
@@ -591,10 +670,7 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 
     /// Returns an iterator over the program IDs, for all deployments.
     pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
-        self.storage.id_map().values_confirmed().map(|id| match id {
-            Cow::Borrowed(id) => Cow::Borrowed(id),
-            Cow::Owned(id) => Cow::Owned(id),
-        })
+        self.storage.edition_map().keys_confirmed()
     }
 
     /// Returns an iterator over the programs, for all deployments.
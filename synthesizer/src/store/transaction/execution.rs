@@ -19,16 +19,17 @@ use crate::{
     cow_to_copied,
     snark::Proof,
     store::{
-        helpers::{Map, MapRead},
+        helpers::{Map, MapRead, SnapshotReader, SnapshotWriter, StorageMode},
         FeeStorage,
         FeeStore,
         TransitionStore,
     },
 };
-use console::network::prelude::*;
+use console::{network::prelude::*, program::ProgramID};
 
 use anyhow::Result;
 use core::marker::PhantomData;
+use indexmap::IndexMap;
 use std::borrow::Cow;
 
 /// A trait for execution storage.
@@ -39,6 +40,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     type ReverseIDMap: for<'a> Map<'a, N::TransitionID, N::TransactionID>;
     /// The mapping of `transaction ID` to `(global state root, (optional) inclusion proof)`.
     type InclusionMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    /// The mapping of `transaction ID` to `editions`, for the upgraded programs in the execution.
+    type EditionMap: for<'a> Map<'a, N::TransactionID, IndexMap<ProgramID<N>, u16>>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -51,6 +54,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_id_map(&self) -> &Self::ReverseIDMap;
     /// Returns the inclusion map.
     fn inclusion_map(&self) -> &Self::InclusionMap;
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap;
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
     /// Returns the transition store.
@@ -58,9 +63,14 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().transition_store()
     }
 
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        self.transition_store().storage_mode()
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
-        self.transition_store().dev()
+        self.storage_mode().dev()
     }
 
     /// Starts an atomic batch write operation.
@@ -68,6 +78,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().start_atomic();
         self.reverse_id_map().start_atomic();
         self.inclusion_map().start_atomic();
+        self.edition_map().start_atomic();
         self.fee_store().start_atomic();
     }
 
@@ -76,6 +87,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().is_atomic_in_progress()
             || self.reverse_id_map().is_atomic_in_progress()
             || self.inclusion_map().is_atomic_in_progress()
+            || self.edition_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
     }
 
@@ -84,6 +96,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_checkpoint();
         self.reverse_id_map().atomic_checkpoint();
         self.inclusion_map().atomic_checkpoint();
+        self.edition_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
     }
 
@@ -92,6 +105,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().clear_latest_checkpoint();
         self.reverse_id_map().clear_latest_checkpoint();
         self.inclusion_map().clear_latest_checkpoint();
+        self.edition_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
     }
 
@@ -100,6 +114,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_rewind();
         self.reverse_id_map().atomic_rewind();
         self.inclusion_map().atomic_rewind();
+        self.edition_map().atomic_rewind();
         self.fee_store().atomic_rewind();
     }
 
@@ -108,6 +123,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().abort_atomic();
         self.reverse_id_map().abort_atomic();
         self.inclusion_map().abort_atomic();
+        self.edition_map().abort_atomic();
         self.fee_store().abort_atomic();
     }
 
@@ -116,9 +132,28 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().finish_atomic()?;
         self.reverse_id_map().finish_atomic()?;
         self.inclusion_map().finish_atomic()?;
+        self.edition_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
+    /// Writes the maps of the execution storage (excluding the fee store) into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        snapshot.write_map("execution.id", self.id_map())?;
+        snapshot.write_map("execution.reverse_id", self.reverse_id_map())?;
+        snapshot.write_map("execution.inclusion", self.inclusion_map())?;
+        snapshot.write_map("execution.edition", self.edition_map())?;
+        Ok(())
+    }
+
+    /// Reads the maps of the execution storage (excluding the fee store) from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        snapshot.read_map("execution.id", self.id_map())?;
+        snapshot.read_map("execution.reverse_id", self.reverse_id_map())?;
+        snapshot.read_map("execution.inclusion", self.inclusion_map())?;
+        snapshot.read_map("execution.edition", self.edition_map())?;
+        Ok(())
+    }
+
     /// Stores the given `execution transaction` pair into storage.
     fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
         // Ensure the transaction is a execution.
@@ -152,6 +187,11 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             // Store the global state root and inclusion proof.
             self.inclusion_map().insert(*transaction_id, (global_state_root, inclusion_proof))?;
 
+            // Store the editions, if the execution ran against an upgraded program.
+            if !execution.editions().is_empty() {
+                self.edition_map().insert(*transaction_id, execution.editions().clone())?;
+            }
+
             // Store the fee.
             if let Some(fee) = fee {
                 // Store the fee.
@@ -184,6 +224,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
             // Remove the global state root and inclusion proof.
             self.inclusion_map().remove(transaction_id)?;
+            // Remove the editions.
+            self.edition_map().remove(transaction_id)?;
 
             // Remove the fee.
             if has_fee {
@@ -225,6 +267,12 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the inclusion proof for the transaction '{transaction_id}'"),
         };
 
+        // Retrieve the editions.
+        let editions = match self.edition_map().get_confirmed(transaction_id)? {
+            Some(editions) => cow_to_cloned!(editions),
+            None => IndexMap::new(),
+        };
+
         // Initialize a vector for the transitions.
         let mut transitions = Vec::new();
 
@@ -237,7 +285,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
 
         // Return the execution.
-        Ok(Some(Execution::from(transitions.into_iter(), global_state_root, inclusion_proof)?))
+        Ok(Some(Execution::from(transitions.into_iter(), editions, global_state_root, inclusion_proof)?))
     }
 
     /// Returns the transaction for the given `transaction ID`.
@@ -254,6 +302,12 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the inclusion proof for the transaction '{transaction_id}'"),
         };
 
+        // Retrieve the editions.
+        let editions = match self.edition_map().get_confirmed(transaction_id)? {
+            Some(editions) => cow_to_cloned!(editions),
+            None => IndexMap::new(),
+        };
+
         // Initialize a vector for the transitions.
         let mut transitions = Vec::new();
 
@@ -266,7 +320,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
 
         // Construct the execution.
-        let execution = Execution::from(transitions.into_iter(), global_state_root, inclusion_proof)?;
+        let execution = Execution::from(transitions.into_iter(), editions, global_state_root, inclusion_proof)?;
 
         // Construct the transaction.
         let transaction = match has_fee {
@@ -355,6 +409,21 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the execution storage (excluding the fee store) into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the execution storage (excluding the fee store) from the given snapshot.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.storage.import_snapshot(snapshot)
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
@@ -19,7 +19,7 @@ use crate::{
     cow_to_copied,
     snark::Proof,
     store::{
-        helpers::{Map, MapRead},
+        helpers::{Map, MapRead, SnapshotReader, SnapshotWriter, StorageMode},
         TransitionStorage,
         TransitionStore,
     },
@@ -49,9 +49,14 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
     /// Returns the transition storage.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage>;
 
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        self.transition_store().storage_mode()
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
-        self.transition_store().dev()
+        self.storage_mode().dev()
     }
 
     /// Starts an atomic batch write operation.
@@ -103,6 +108,20 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
         self.transition_store().finish_atomic()
     }
 
+    /// Writes the maps of the fee storage (excluding the transition store) into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        snapshot.write_map("fee.fee", self.fee_map())?;
+        snapshot.write_map("fee.reverse_fee", self.reverse_fee_map())?;
+        Ok(())
+    }
+
+    /// Reads the maps of the fee storage (excluding the transition store) from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        snapshot.read_map("fee.fee", self.fee_map())?;
+        snapshot.read_map("fee.reverse_fee", self.reverse_fee_map())?;
+        Ok(())
+    }
+
     /// Stores the given `(transaction ID, fee)` pair into storage.
     fn insert(&self, transaction_id: N::TransactionID, fee: &Fee<N>) -> Result<()> {
         atomic_batch_scope!(self, {
@@ -240,6 +259,21 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the fee storage (excluding the transition store) into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the fee storage (excluding the transition store) from the given snapshot.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.storage.import_snapshot(snapshot)
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
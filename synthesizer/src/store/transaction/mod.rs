@@ -28,14 +28,14 @@ use crate::{
     program::Program,
     snark::{Certificate, VerifyingKey},
     store::{
-        helpers::{Map, MapRead},
+        helpers::{Map, MapRead, SnapshotReader, SnapshotWriter, StorageMode},
         TransitionStorage,
         TransitionStore,
     },
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, ProgramID},
+    program::{Identifier, ProgramID, ProgramOwner},
 };
 
 use anyhow::Result;
@@ -83,9 +83,14 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().transition_store()
     }
 
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        self.transition_store().storage_mode()
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
-        self.transition_store().dev()
+        self.storage_mode().dev()
     }
 
     /// Starts an atomic batch write operation.
@@ -144,6 +149,26 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().finish_atomic()
     }
 
+    /// Writes the maps of the transaction storage into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        snapshot.write_map("transaction.id", self.id_map())?;
+        self.deployment_store().export_snapshot(snapshot)?;
+        self.execution_store().export_snapshot(snapshot)?;
+        self.fee_store().export_snapshot(snapshot)?;
+        self.transition_store().export_snapshot(snapshot)?;
+        Ok(())
+    }
+
+    /// Reads the maps of the transaction storage from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        snapshot.read_map("transaction.id", self.id_map())?;
+        self.deployment_store().import_snapshot(snapshot)?;
+        self.execution_store().import_snapshot(snapshot)?;
+        self.fee_store().import_snapshot(snapshot)?;
+        self.transition_store().import_snapshot(snapshot)?;
+        Ok(())
+    }
+
     /// Stores the given `transaction` into storage.
     fn insert(&self, transaction: &Transaction<N>) -> Result<()> {
         atomic_batch_scope!(self, {
@@ -303,6 +328,21 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the transaction storage into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the transaction storage from the given snapshot.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.storage.import_snapshot(snapshot)
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
@@ -361,11 +401,10 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         // Retrieve the edition.
         match transaction_type {
             TransactionType::Deploy => {
-                // Retrieve the program ID.
-                let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
-                // Return the edition.
-                match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
+                // Retrieve the program ID and edition.
+                match self.storage.deployment_store().get_program_id_and_edition(transaction_id)? {
+                    // Return the edition.
+                    Some((_, edition)) => Ok(Some(edition)),
                     None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
                 }
             }
@@ -386,6 +425,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().get_program(program_id)
     }
 
+    /// Returns the owner of the latest edition for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.deployment_store().get_owner(program_id)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
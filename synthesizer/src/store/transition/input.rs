@@ -15,7 +15,7 @@
 use crate::{
     atomic_batch_scope,
     block::Input,
-    store::helpers::{Map, MapRead},
+    store::helpers::{Map, MapRead, SnapshotReader, SnapshotWriter, StorageMode},
 };
 use console::{
     network::prelude::*,
@@ -46,7 +46,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     type ExternalRecordMap: for<'a> Map<'a, Field<N>, ()>;
 
     /// Initializes the transition input storage.
-    fn open(dev: Option<u16>) -> Result<Self>;
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
 
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
@@ -65,8 +65,13 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap;
 
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode;
+
     /// Returns the optional development ID.
-    fn dev(&self) -> Option<u16>;
+    fn dev(&self) -> Option<u16> {
+        self.storage_mode().dev()
+    }
 
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
@@ -152,6 +157,32 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.external_record_map().finish_atomic()
     }
 
+    /// Writes the maps of the input storage into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        snapshot.write_map("input.id", self.id_map())?;
+        snapshot.write_map("input.reverse_id", self.reverse_id_map())?;
+        snapshot.write_map("input.constant", self.constant_map())?;
+        snapshot.write_map("input.public", self.public_map())?;
+        snapshot.write_map("input.private", self.private_map())?;
+        snapshot.write_map("input.record", self.record_map())?;
+        snapshot.write_map("input.record_tag", self.record_tag_map())?;
+        snapshot.write_map("input.external_record", self.external_record_map())?;
+        Ok(())
+    }
+
+    /// Reads the maps of the input storage from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        snapshot.read_map("input.id", self.id_map())?;
+        snapshot.read_map("input.reverse_id", self.reverse_id_map())?;
+        snapshot.read_map("input.constant", self.constant_map())?;
+        snapshot.read_map("input.public", self.public_map())?;
+        snapshot.read_map("input.private", self.private_map())?;
+        snapshot.read_map("input.record", self.record_map())?;
+        snapshot.read_map("input.record_tag", self.record_tag_map())?;
+        snapshot.read_map("input.external_record", self.external_record_map())?;
+        Ok(())
+    }
+
     /// Stores the given `(transition ID, input)` pair into storage.
     fn insert(&self, transition_id: N::TransitionID, inputs: &[Input<N>]) -> Result<()> {
         atomic_batch_scope!(self, {
@@ -305,9 +336,9 @@ pub struct InputStore<N: Network, I: InputStorage<N>> {
 
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     /// Initializes the transition input store.
-    pub fn open(dev: Option<u16>) -> Result<Self> {
+    pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize a new transition input storage.
-        let storage = I::open(dev)?;
+        let storage = I::open(storage)?;
         // Return the transition input store.
         Ok(Self {
             constant: storage.constant_map().clone(),
@@ -378,6 +409,21 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the input storage into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the input storage from the given snapshot.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.storage.import_snapshot(snapshot)
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
@@ -24,7 +24,7 @@ use crate::{
     cow_to_cloned,
     cow_to_copied,
     snark::Proof,
-    store::helpers::{Map, MapRead},
+    store::helpers::{Map, MapRead, SnapshotReader, SnapshotWriter, StorageMode},
 };
 use console::{
     network::prelude::*,
@@ -57,7 +57,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
 
     /// Initializes the transition storage.
-    fn open(dev: Option<u16>) -> Result<Self>;
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
 
     /// Returns the transition program IDs and function names.
     fn locator_map(&self) -> &Self::LocatorMap;
@@ -78,10 +78,15 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// Returns the reverse `tcm` map.
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
 
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        debug_assert!(self.input_store().storage_mode() == self.output_store().storage_mode());
+        self.input_store().storage_mode()
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
-        debug_assert!(self.input_store().dev() == self.output_store().dev());
-        self.input_store().dev()
+        self.storage_mode().dev()
     }
 
     /// Starts an atomic batch write operation.
@@ -175,6 +180,34 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tcm_map().finish_atomic()
     }
 
+    /// Writes the maps of the transition storage into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        snapshot.write_map("transition.locator", self.locator_map())?;
+        snapshot.write_map("transition.finalize", self.finalize_map())?;
+        snapshot.write_map("transition.proof", self.proof_map())?;
+        snapshot.write_map("transition.tpk", self.tpk_map())?;
+        snapshot.write_map("transition.reverse_tpk", self.reverse_tpk_map())?;
+        snapshot.write_map("transition.tcm", self.tcm_map())?;
+        snapshot.write_map("transition.reverse_tcm", self.reverse_tcm_map())?;
+        self.input_store().export_snapshot(snapshot)?;
+        self.output_store().export_snapshot(snapshot)?;
+        Ok(())
+    }
+
+    /// Reads the maps of the transition storage from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        snapshot.read_map("transition.locator", self.locator_map())?;
+        snapshot.read_map("transition.finalize", self.finalize_map())?;
+        snapshot.read_map("transition.proof", self.proof_map())?;
+        snapshot.read_map("transition.tpk", self.tpk_map())?;
+        snapshot.read_map("transition.reverse_tpk", self.reverse_tpk_map())?;
+        snapshot.read_map("transition.tcm", self.tcm_map())?;
+        snapshot.read_map("transition.reverse_tcm", self.reverse_tcm_map())?;
+        self.input_store().import_snapshot(snapshot)?;
+        self.output_store().import_snapshot(snapshot)?;
+        Ok(())
+    }
+
     /// Stores the given `transition` into storage.
     fn insert(&self, transition: &Transition<N>) -> Result<()> {
         atomic_batch_scope!(self, {
@@ -311,9 +344,9 @@ pub struct TransitionStore<N: Network, T: TransitionStorage<N>> {
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     /// Initializes the transition store.
-    pub fn open(dev: Option<u16>) -> Result<Self> {
+    pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize the transition storage.
-        let storage = T::open(dev)?;
+        let storage = T::open(storage)?;
         // Return the transition store.
         Ok(Self {
             locator: storage.locator_map().clone(),
@@ -390,6 +423,27 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the transition storage into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the transition storage from the given snapshot.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.storage.import_snapshot(snapshot)
+    }
+
+    /// Returns the transition storage.
+    #[cfg(feature = "rocks")]
+    pub(crate) const fn storage(&self) -> &T {
+        &self.storage
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
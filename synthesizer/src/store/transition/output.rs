@@ -15,7 +15,7 @@
 use crate::{
     atomic_batch_scope,
     block::Output,
-    store::helpers::{Map, MapRead},
+    store::helpers::{Map, MapRead, SnapshotReader, SnapshotWriter, StorageMode},
 };
 use console::{
     network::prelude::*,
@@ -46,7 +46,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     type ExternalRecordMap: for<'a> Map<'a, Field<N>, ()>;
 
     /// Initializes the transition output storage.
-    fn open(dev: Option<u16>) -> Result<Self>;
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
 
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
@@ -65,8 +65,13 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap;
 
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode;
+
     /// Returns the optional development ID.
-    fn dev(&self) -> Option<u16>;
+    fn dev(&self) -> Option<u16> {
+        self.storage_mode().dev()
+    }
 
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
@@ -152,6 +157,32 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.external_record_map().finish_atomic()
     }
 
+    /// Writes the maps of the output storage into the given snapshot.
+    fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        snapshot.write_map("output.id", self.id_map())?;
+        snapshot.write_map("output.reverse_id", self.reverse_id_map())?;
+        snapshot.write_map("output.constant", self.constant_map())?;
+        snapshot.write_map("output.public", self.public_map())?;
+        snapshot.write_map("output.private", self.private_map())?;
+        snapshot.write_map("output.record", self.record_map())?;
+        snapshot.write_map("output.record_nonce", self.record_nonce_map())?;
+        snapshot.write_map("output.external_record", self.external_record_map())?;
+        Ok(())
+    }
+
+    /// Reads the maps of the output storage from the given snapshot.
+    fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        snapshot.read_map("output.id", self.id_map())?;
+        snapshot.read_map("output.reverse_id", self.reverse_id_map())?;
+        snapshot.read_map("output.constant", self.constant_map())?;
+        snapshot.read_map("output.public", self.public_map())?;
+        snapshot.read_map("output.private", self.private_map())?;
+        snapshot.read_map("output.record", self.record_map())?;
+        snapshot.read_map("output.record_nonce", self.record_nonce_map())?;
+        snapshot.read_map("output.external_record", self.external_record_map())?;
+        Ok(())
+    }
+
     /// Stores the given `(transition ID, output)` pair into storage.
     fn insert(&self, transition_id: N::TransitionID, outputs: &[Output<N>]) -> Result<()> {
         atomic_batch_scope!(self, {
@@ -309,9 +340,9 @@ pub struct OutputStore<N: Network, O: OutputStorage<N>> {
 
 impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     /// Initializes the transition output store.
-    pub fn open(dev: Option<u16>) -> Result<Self> {
+    pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize a new transition output storage.
-        let storage = O::open(dev)?;
+        let storage = O::open(storage)?;
         // Return the transition output store.
         Ok(Self {
             constant: storage.constant_map().clone(),
@@ -382,6 +413,21 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.finish_atomic()
     }
 
+    /// Writes the maps of the output storage into the given snapshot.
+    pub fn export_snapshot<W: Write>(&self, snapshot: &mut SnapshotWriter<W>) -> Result<()> {
+        self.storage.export_snapshot(snapshot)
+    }
+
+    /// Reads the maps of the output storage from the given snapshot.
+    pub fn import_snapshot<'a, R: Read>(&'a self, snapshot: &mut SnapshotReader<'a, R>) -> Result<()> {
+        self.storage.import_snapshot(snapshot)
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
@@ -32,10 +32,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ensure!(!deployment.program().functions().is_empty(), "Attempted to create an empty transaction deployment");
 
         // Determine the fee.
-        let fee_in_microcredits = deployment
-            .size_in_bytes()?
-            .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
-            .and_then(|deployment_fee| deployment_fee.checked_add(priority_fee_in_microcredits))
+        let fee_in_microcredits = self
+            .deployment_cost(&deployment)?
+            .checked_add(priority_fee_in_microcredits)
             .ok_or_else(|| anyhow!("Fee overflowed for a deployment transaction"))?;
 
         // Compute the fee.
@@ -49,6 +48,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Transaction::from_deployment(owner, deployment, fee)
     }
 
+    /// Returns the minimum fee in microcredits for the given deployment.
+    pub fn deployment_cost(&self, deployment: &Deployment<N>) -> Result<u64> {
+        self.process.read().deployment_cost(deployment)
+    }
+
     /// Returns a deployment for the given program.
     #[inline]
     pub fn deploy_raw<R: Rng + CryptoRng>(&self, program: &Program<N>, rng: &mut R) -> Result<Deployment<N>> {
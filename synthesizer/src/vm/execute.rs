@@ -17,7 +17,7 @@ use super::*;
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns a new execute transaction.
     ///
-    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution cost.
     pub fn execute<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -36,8 +36,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             None => None,
             Some((credits, priority_fee_in_microcredits)) => {
                 // Determine the fee.
-                let fee_in_microcredits = execution
-                    .size_in_bytes()?
+                let fee_in_microcredits = self
+                    .execution_cost(&execution)?
                     .checked_add(priority_fee_in_microcredits)
                     .ok_or_else(|| anyhow!("Fee overflowed for an execution transaction"))?;
                 // Compute the fee.
@@ -48,6 +48,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Transaction::from_execution(execution, fee)
     }
 
+    /// Returns the minimum fee in microcredits for the given execution.
+    pub fn execution_cost(&self, execution: &Execution<N>) -> Result<u64> {
+        self.process.read().execution_cost(execution)
+    }
+
     /// Returns a new execute transaction for the given authorization.
     pub fn execute_authorization<R: Rng + CryptoRng>(
         &self,
@@ -54,7 +54,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // TODO (raychu86): Ensure that the fee record is associated with the `credits.aleo` program
         // Ensure that the record has enough balance to pay the fee.
-        match fee_record.find(&[Identifier::from_str("microcredits")?]) {
+        match fee_record.find(&[Access::Member(Identifier::from_str("microcredits")?)]) {
             Ok(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => {
                 if *amount < fee_in_microcredits {
                     bail!("Fee record does not have enough balance to pay the fee")
@@ -106,6 +106,100 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Executes a fee for the given private key and fee amount (in microcredits),
+    /// which is paid from the public balance of the caller.
+    /// Returns the fee transaction.
+    #[inline]
+    pub fn execute_fee_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the fee.
+        let fee = self.execute_fee_public_raw(private_key, fee_in_microcredits, query, rng)?.1;
+        // Return the fee transaction.
+        Transaction::from_fee(fee)
+    }
+
+    /// Executes a fee for the given private key and fee amount (in microcredits),
+    /// which is paid from the public balance of the caller.
+    /// Returns the response, fee, and call metrics.
+    #[inline]
+    pub fn execute_fee_public_raw<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Fee<N>, Vec<CallMetrics<N>>)> {
+        let timer = timer!("VM::execute_fee_public_raw");
+
+        // Prepare the query.
+        let query = match query {
+            Some(query) => query,
+            None => Query::VM(self.block_store().clone()),
+        };
+        lap!(timer, "Prepare the query");
+
+        // Ensure that the public balance has enough balance to pay the fee.
+        if self.get_public_balance(&Address::try_from(private_key)?)? < fee_in_microcredits {
+            bail!("Public balance does not have enough balance to pay the fee")
+        }
+
+        // Retrieve the global state root.
+        // Note: A fee paid from the public balance does not consume records, and does not require an inclusion proof.
+        let global_state_root = query.current_state_root()?;
+        lap!(timer, "Retrieve the global state root");
+
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the private key.
+                let private_key = cast_ref!(&private_key as PrivateKey<$network>);
+                lap!(timer, "Prepare the private key");
+
+                // Execute the call to fee.
+                let (response, fee_transition, _inclusion, metrics) =
+                    $process.execute_fee_public::<$aleo, _>(private_key, fee_in_microcredits, rng)?;
+                lap!(timer, "Execute the call to fee");
+
+                // Prepare the return.
+                let response = cast_ref!(response as Response<N>).clone();
+                let fee_transition = cast_ref!(fee_transition as Transition<N>).clone();
+                let metrics = cast_ref!(metrics as Vec<CallMetrics<N>>).clone();
+                lap!(timer, "Prepare the response, fee transition, and metrics");
+
+                // Construct the fee.
+                let fee = Fee::from(fee_transition, global_state_root, None);
+
+                finish!(timer);
+
+                // Return the response, fee, metrics.
+                Ok((response, fee, metrics))
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
+
+    /// Returns the public balance (in microcredits) of the given address.
+    #[inline]
+    pub fn get_public_balance(&self, address: &Address<N>) -> Result<u64> {
+        // Prepare the program ID, mapping name, and key.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let mapping_name = Identifier::from_str("account")?;
+        let key = Plaintext::from(Literal::Address(*address));
+        // Retrieve the public balance.
+        match self.finalize_store().get_value_speculative(&program_id, &mapping_name, &key)? {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => Ok(*balance),
+            Some(..) => bail!("Malformed public balance for '{address}'"),
+            // If the address does not have a public balance, return zero.
+            None => Ok(0),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -46,15 +46,18 @@ impl FinalizeMode {
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Speculates on the given list of transactions in the VM, returning the confirmed transactions.
+    /// The given global state corresponds to the block that the transactions will be included in.
+    /// Note: A transaction whose fee fails to finalize (e.g. an insufficient public balance) is dropped.
     #[inline]
     pub fn speculate<'a>(
         &self,
+        state: FinalizeGlobalState,
         transactions: impl Iterator<Item = &'a Transaction<N>> + ExactSizeIterator,
     ) -> Result<Transactions<N>> {
         let timer = timer!("VM::speculate");
 
         // Performs a **dry-run** over the list of transactions.
-        let confirmed_transactions = self.atomic_speculate(transactions)?;
+        let confirmed_transactions = self.atomic_speculate(state, transactions)?;
 
         finish!(timer, "Finished dry-run of the transactions");
 
@@ -63,16 +66,31 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Finalizes the given transactions into the VM.
+    /// The given global state corresponds to the block that the transactions are included in.
     #[inline]
-    pub fn finalize(&self, transactions: &Transactions<N>) -> Result<()> {
+    pub fn finalize(&self, state: FinalizeGlobalState, transactions: &Transactions<N>) -> Result<()> {
         let timer = timer!("VM::finalize");
 
         // Performs a **real-run** of finalize over the list of transactions.
-        self.atomic_finalize(transactions)?;
+        self.atomic_finalize(state, transactions, FinalizeMode::RealRun)?;
 
         finish!(timer, "Finished real-run of finalize");
         Ok(())
     }
+
+    /// Re-speculates on the given confirmed transactions in the VM, and ensures the outcome of each transaction
+    /// (i.e. whether it is accepted or rejected, and its finalize operations) matches the given transactions.
+    /// The given global state corresponds to the block that the transactions are included in.
+    #[inline]
+    pub fn check_speculate(&self, state: FinalizeGlobalState, transactions: &Transactions<N>) -> Result<()> {
+        let timer = timer!("VM::check_speculate");
+
+        // Performs a **dry-run** of finalize over the list of transactions.
+        self.atomic_finalize(state, transactions, FinalizeMode::DryRun)?;
+
+        finish!(timer, "Finished dry-run of finalize");
+        Ok(())
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -81,6 +99,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[rustfmt::skip]
     fn atomic_speculate<'a>(
         &self,
+        state: FinalizeGlobalState,
         transactions: impl Iterator<Item = &'a Transaction<N>> + ExactSizeIterator,
     ) -> Result<Vec<ConfirmedTransaction<N>>> {
         let timer = timer!("VM::atomic_speculate");
@@ -102,10 +121,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let mut confirmed = Vec::with_capacity(num_transactions);
 
             // Finalize the transactions.
-            for (index, transaction) in transactions.enumerate() {
+            for transaction in transactions {
                 // Convert the transaction index to a u32.
+                // Note: The index is the position in the confirmed transactions, as dropped transactions are skipped.
                 // Note: On failure, this will abort the entire atomic batch.
-                let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
+                let index = u32::try_from(confirmed.len()).map_err(|_| "Failed to convert transaction index".to_string())?;
+
+                // Finalize the fee, which is paid regardless of whether the transaction is accepted or rejected.
+                // Note: On failure, the fee is rewound, and the transaction is dropped from the block.
+                let fee_finalize = match transaction.fee_transition() {
+                    Some(fee) => match process.finalize_fee(state, store, &fee) {
+                        Ok(fee_finalize) => fee_finalize,
+                        Err(error) => {
+                            warn!("Dropping transaction '{}' - Failed to finalize the fee - {error}", transaction.id());
+                            continue;
+                        }
+                    },
+                    None => Vec::new(),
+                };
 
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
@@ -115,21 +148,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // and adding the program to the finalize tree.
                     Transaction::Deploy(_, _, deployment, fee) => match process.finalize_deployment(store, deployment) {
                         // Construct the accepted deploy transaction.
-                        Ok((_, finalize)) => ConfirmedTransaction::accepted_deploy(index, transaction.clone(), finalize).map_err(|e| e.to_string()),
+                        Ok((_, finalize)) => ConfirmedTransaction::accepted_deploy(index, transaction.clone(), [fee_finalize, finalize].concat()).map_err(|e| e.to_string()),
                         // Construct the rejected deploy transaction.
                         Err(_error) => {
                             // Construct the fee transaction.
                             // Note: On failure, this will abort the entire atomic batch.
                             let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
                             // Construct the rejected deploy transaction.
-                            ConfirmedTransaction::rejected_deploy(index, fee_tx, *deployment.clone()).map_err(|e| e.to_string())
+                            ConfirmedTransaction::rejected_deploy(index, fee_tx, *deployment.clone(), fee_finalize).map_err(|e| e.to_string())
                         }
                     }
                     // The finalize operation here involves calling 'update_key_value',
                     // and update the respective leaves of the finalize tree.
-                    Transaction::Execute(_, execution, fee) => match process.finalize_execution(store, execution) {
+                    Transaction::Execute(_, execution, fee) => match process.finalize_execution(state, store, execution) {
                         // Construct the accepted execute transaction.
-                        Ok(finalize) => ConfirmedTransaction::accepted_execute(index, transaction.clone(), finalize).map_err(|e| e.to_string()),
+                        Ok(finalize) => ConfirmedTransaction::accepted_execute(index, transaction.clone(), [fee_finalize, finalize].concat()).map_err(|e| e.to_string()),
                         // Construct the rejected execute transaction.
                         Err(_error) => match fee {
                             Some(fee) => {
@@ -137,7 +170,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                 // Note: On failure, this will abort the entire atomic batch.
                                 let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
                                 // Construct the rejected execute transaction.
-                                ConfirmedTransaction::rejected_execute(index, fee_tx, execution.clone()).map_err(|e| e.to_string())
+                                ConfirmedTransaction::rejected_execute(index, fee_tx, execution.clone(), fee_finalize).map_err(|e| e.to_string())
                             },
                             // This is a foundational bug - the caller is violating protocol rules.
                             // Note: This will abort the entire atomic batch.
@@ -162,10 +195,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 }
             }
 
-            // Ensure all transactions were processed.
-            if confirmed.len() != num_transactions {
+            // Ensure no more transactions were processed than were given.
+            if confirmed.len() > num_transactions {
                 // Note: This will abort the entire atomic batch.
-                return Err("Not all transactions were processed in 'VM::atomic_speculate'".to_string());
+                return Err("Too many transactions were processed in 'VM::atomic_speculate'".to_string());
             }
 
             finish!(timer);
@@ -175,13 +208,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         })
     }
 
-    /// Performs atomic finalization over a list of transactions.
+    /// Performs atomic finalization over a list of transactions, on the given finalize mode.
+    /// On a dry run, the transactions are checked, and neither the finalize store nor the process is updated.
     #[inline]
-    fn atomic_finalize(&self, transactions: &Transactions<N>) -> Result<()> {
+    fn atomic_finalize(
+        &self,
+        state: FinalizeGlobalState,
+        transactions: &Transactions<N>,
+        mode: FinalizeMode,
+    ) -> Result<()> {
         let timer = timer!("VM::atomic_finalize");
 
-        // Perform the finalize operation on the preset finalize mode.
-        atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
+        // Perform the finalize operation on the given finalize mode.
+        atomic_finalize!(self.finalize_store(), mode, {
             // Acquire the write lock on the process.
             // Note: Due to the highly-sensitive nature of processing all `finalize` calls,
             // we choose to acquire the write lock for the entire duration of this atomic batch.
@@ -199,6 +238,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Note: On failure, this will abort the entire atomic batch.
                 let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
 
+                // Finalize the fee, which is paid regardless of whether the transaction is accepted or rejected.
+                // Note: On failure, this will abort the entire atomic batch.
+                let fee_finalize = match transaction.transaction().fee_transition() {
+                    Some(fee) => match process.finalize_fee(state, store, &fee) {
+                        Ok(fee_finalize) => fee_finalize,
+                        Err(error) => return Err(format!("Failed to finalize the fee - {error}")),
+                    },
+                    None => Vec::new(),
+                };
+
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
@@ -207,7 +256,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         // Ensure the index matches the expected index.
                         if index != *idx {
                             // Note: This will abort the entire atomic batch.
-                            return Err("Mismatch in accepted deploy transaction index".to_string());
+                            return Err(format!("Mismatch in accepted deploy transaction index ({idx} != {index})"));
                         }
                         // Extract the deployment from the transaction.
                         let deployment = match transaction {
@@ -218,15 +267,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         // The finalize operation here involves appending the 'stack',
                         // and adding the program to the finalize tree.
                         match process.finalize_deployment(store, deployment) {
-                            // Ensure the finalize operations match the expected.
-                            Ok((stack, finalize_operations)) => match finalize == &finalize_operations {
-                                // Store the stack.
-                                true => stacks.push(stack),
-                                // Note: This will abort the entire atomic batch.
-                                false => {
-                                    return Err("Mismatch in finalize operations for an accepted deploy".to_string());
+                            // Ensure the finalize operations match the expected, preceded by those of the fee.
+                            Ok((stack, finalize_operations)) => {
+                                let finalize_operations = [fee_finalize, finalize_operations].concat();
+                                match finalize == &finalize_operations {
+                                    // Store the stack.
+                                    true => stacks.push(stack),
+                                    // Note: This will abort the entire atomic batch.
+                                    false => {
+                                        return Err(format!(
+                                            "Mismatch in finalize operations for an accepted deploy ({} != {})",
+                                            finalize.len(),
+                                            finalize_operations.len()
+                                        ));
+                                    }
                                 }
-                            },
+                            }
                             // Note: This will abort the entire atomic batch.
                             Err(error) => {
                                 return Err(format!("Failed to finalize an accepted deploy transaction - {error}"));
@@ -238,7 +294,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         // Ensure the index matches the expected index.
                         if index != *idx {
                             // Note: This will abort the entire atomic batch.
-                            return Err("Mismatch in accepted execute transaction index".to_string());
+                            return Err(format!("Mismatch in accepted execute transaction index ({idx} != {index})"));
                         }
                         // Extract the execution from the transaction.
                         let execution = match transaction {
@@ -248,12 +304,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         };
                         // The finalize operation here involves calling 'update_key_value',
                         // and update the respective leaves of the finalize tree.
-                        match process.finalize_execution(store, execution) {
-                            // Ensure the finalize operations match the expected.
+                        match process.finalize_execution(state, store, execution) {
+                            // Ensure the finalize operations match the expected, preceded by those of the fee.
                             Ok(finalize_operations) => {
+                                let finalize_operations = [fee_finalize, finalize_operations].concat();
                                 if finalize != &finalize_operations {
                                     // Note: This will abort the entire atomic batch.
-                                    return Err("Mismatch in finalize operations for an accepted execute".to_string());
+                                    return Err(format!(
+                                        "Mismatch in finalize operations for an accepted execute ({} != {})",
+                                        finalize.len(),
+                                        finalize_operations.len()
+                                    ));
                                 }
                             }
                             // Note: This will abort the entire atomic batch.
@@ -263,13 +324,39 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         }
                         Ok(())
                     }
-                    ConfirmedTransaction::RejectedDeploy(idx, _fee_transaction, deployment) => {
+                    ConfirmedTransaction::RejectedDeploy(idx, fee_transaction, deployment, finalize) => {
                         // Ensure the index matches the expected index.
                         if index != *idx {
                             // Note: This will abort the entire atomic batch.
-                            return Err("Mismatch in rejected deploy transaction index".to_string());
+                            return Err(format!("Mismatch in rejected deploy transaction index ({idx} != {index})"));
+                        }
+                        // Ensure the rejected deployment is paid for with a fee transaction.
+                        if !fee_transaction.is_fee() {
+                            // Note: This will abort the entire atomic batch.
+                            return Err("Expected a fee transaction for a rejected deploy".to_string());
+                        }
+                        // Ensure the finalize operations match those of the fee.
+                        if finalize != &fee_finalize {
+                            // Note: This will abort the entire atomic batch.
+                            return Err(format!(
+                                "Mismatch in finalize operations for a rejected deploy ({} != {})",
+                                finalize.len(),
+                                fee_finalize.len()
+                            ));
+                        }
+                        // Ensure the fee covers the cost of the rejected deployment.
+                        let cost = match process.deployment_cost(deployment) {
+                            Ok(cost) => cost,
+                            // Note: This will abort the entire atomic batch.
+                            Err(error) => {
+                                return Err(format!("Failed to compute the cost of a rejected deploy - {error}"));
+                            }
+                        };
+                        let amount = fee_transaction.fee_transition().and_then(|fee| fee.amount().ok());
+                        if !matches!(amount, Some(amount) if *amount >= cost) {
+                            // Note: This will abort the entire atomic batch.
+                            return Err(format!("Insufficient fee for a rejected deploy (minimum {cost} microcredits)"));
                         }
-                        // TODO (howardwu): Ensure this fee corresponds to the deployment.
                         // Attempt to finalize the deployment, which should fail.
                         if let Ok(..) = process.finalize_deployment(store, deployment) {
                             // Note: This will abort the entire atomic batch.
@@ -277,15 +364,41 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         }
                         Ok(())
                     }
-                    ConfirmedTransaction::RejectedExecute(idx, _fee_transaction, execution) => {
+                    ConfirmedTransaction::RejectedExecute(idx, fee_transaction, execution, finalize) => {
                         // Ensure the index matches the expected index.
                         if index != *idx {
                             // Note: This will abort the entire atomic batch.
-                            return Err("Mismatch in rejected execute transaction index".to_string());
+                            return Err(format!("Mismatch in rejected execute transaction index ({idx} != {index})"));
+                        }
+                        // Ensure the rejected execution is paid for with a fee transaction.
+                        if !fee_transaction.is_fee() {
+                            // Note: This will abort the entire atomic batch.
+                            return Err("Expected a fee transaction for a rejected execute".to_string());
+                        }
+                        // Ensure the finalize operations match those of the fee.
+                        if finalize != &fee_finalize {
+                            // Note: This will abort the entire atomic batch.
+                            return Err(format!(
+                                "Mismatch in finalize operations for a rejected execute ({} != {})",
+                                finalize.len(),
+                                fee_finalize.len()
+                            ));
+                        }
+                        // Ensure the fee covers the cost of the rejected execution.
+                        let cost = match process.execution_cost(execution) {
+                            Ok(cost) => cost,
+                            // Note: This will abort the entire atomic batch.
+                            Err(error) => {
+                                return Err(format!("Failed to compute the cost of a rejected execute - {error}"));
+                            }
+                        };
+                        let amount = fee_transaction.fee_transition().and_then(|fee| fee.amount().ok());
+                        if !matches!(amount, Some(amount) if *amount >= cost) {
+                            // Note: This will abort the entire atomic batch.
+                            return Err(format!("Insufficient fee for a rejected execute (minimum {cost} microcredits)"));
                         }
-                        // TODO (howardwu): Ensure this fee corresponds to the execution.
                         // Attempt to finalize the execution, which should fail.
-                        if let Ok(..) = process.finalize_execution(store, execution) {
+                        if let Ok(..) = process.finalize_execution(state, store, execution) {
                             // Note: This will abort the entire atomic batch.
                             return Err("Failed to reject a rejected execute transaction".to_string());
                         }
@@ -299,13 +412,29 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     Ok(()) => (),
                     // If the transaction failed to finalize, abort and continue to the next transaction.
                     Err(error) => {
-                        eprintln!("Critical bug in finalize: {error}\n\n{transaction}");
+                        // Note: On a dry run, a failure indicates the transactions are invalid, rather than a bug.
+                        if mode == FinalizeMode::RealRun {
+                            eprintln!("Critical bug in finalize: {error}\n\n{transaction}");
+                        }
                         // Note: This will abort the entire atomic batch.
-                        return Err(format!("Failed to finalize on transaction - {error}"));
+                        return Err(format!("Failed to finalize on transaction {index} - {error}"));
                     }
                 }
             }
 
+            // If this is a dry run, return without committing.
+            if mode == FinalizeMode::DryRun {
+                finish!(timer);
+                return Ok(());
+            }
+
+            // Record the finalize pre-image of the block, so that the block can be reverted,
+            // and prune the finalize pre-images beyond the maximum reorg depth.
+            // Note: On failure, this will abort the entire atomic batch.
+            if let Err(error) = store.record_preimage(state.block_height(), self.max_reorg_depth()) {
+                return Err(format!("Failed to record the finalize pre-image - {error}"));
+            }
+
             /* Start the commit process. */
 
             // Commit all of the stacks to the process.
@@ -421,7 +550,8 @@ finalize transfer_public:
         rng: &mut R,
     ) -> Result<Block<CurrentNetwork>> {
         // Construct the new block header.
-        let transactions = vm.speculate(transactions.iter())?;
+        let transactions =
+            vm.speculate(test_helpers::sample_finalize_state(previous_block.height() + 1), transactions.iter())?;
         // Construct the metadata associated with the block.
         let metadata = Metadata::new(
             CurrentNetwork::ID,
@@ -475,7 +605,7 @@ finalize transfer_public:
             let record = unspent_records.pop().unwrap().decrypt(&view_key)?;
 
             // Fetch the record balance and divide it in half.
-            let split_balance = match record.find(&[Identifier::from_str("microcredits")?]) {
+            let split_balance = match record.find(&[Access::Member(Identifier::from_str("microcredits")?)]) {
                 Ok(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => *amount / 2,
                 _ => bail!("fee record does not contain a microcredits entry"),
             };
@@ -575,6 +705,7 @@ finalize transfer_public:
                 index,
                 Transaction::from_fee(fee.clone().unwrap()).unwrap(),
                 crate::Rejected(execution.clone()),
+                vec![],
             ),
             _ => panic!("only reject execution transactions"),
         }
@@ -593,29 +724,81 @@ finalize transfer_public:
         let program_id = ProgramID::from_str("testing.aleo").unwrap();
 
         // Prepare the confirmed transactions.
-        let confirmed_transactions = vm.speculate([deployment_transaction.clone()].iter()).unwrap();
+        let confirmed_transactions =
+            vm.speculate(test_helpers::sample_finalize_state(1), [deployment_transaction.clone()].iter()).unwrap();
 
         // Ensure the VM does not contain this program.
         assert!(!vm.contains_program(&program_id));
 
         // Finalize the transaction.
-        assert!(vm.finalize(&confirmed_transactions).is_ok());
+        assert!(vm.finalize(test_helpers::sample_finalize_state(1), &confirmed_transactions).is_ok());
 
         // Ensure the VM contains this program.
         assert!(vm.contains_program(&program_id));
 
         // Ensure the VM can't redeploy the same transaction.
-        assert!(vm.finalize(&confirmed_transactions).is_err());
+        assert!(vm.finalize(test_helpers::sample_finalize_state(1), &confirmed_transactions).is_err());
 
         // Ensure the VM contains this program.
         assert!(vm.contains_program(&program_id));
 
         // Ensure the dry run of the redeployment will cause a reject transaction to be created.
-        let candidate_transactions = vm.atomic_speculate([deployment_transaction].iter()).unwrap();
+        let candidate_transactions =
+            vm.atomic_speculate(test_helpers::sample_finalize_state(1), [deployment_transaction].iter()).unwrap();
         assert_eq!(candidate_transactions.len(), 1);
         assert!(matches!(candidate_transactions[0], ConfirmedTransaction::RejectedDeploy(..)));
     }
 
+    #[test]
+    fn test_check_speculate() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm();
+
+        // Fetch a deployment transaction.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Construct the program name.
+        let program_id = ProgramID::from_str("testing.aleo").unwrap();
+
+        // Prepare the finalize state.
+        let state = test_helpers::sample_finalize_state(1);
+
+        // Prepare the confirmed transactions.
+        let confirmed_transactions = vm.speculate(state, [deployment_transaction.clone()].iter()).unwrap();
+
+        // Ensure the confirmed transactions match the speculation.
+        vm.check_speculate(state, &confirmed_transactions).unwrap();
+
+        // Ensure the check does not update the VM.
+        assert!(!vm.contains_program(&program_id));
+
+        // Retrieve the finalize operations of the accepted deployment.
+        let finalize = match confirmed_transactions.iter().next().unwrap() {
+            ConfirmedTransaction::AcceptedDeploy(_, _, finalize) => finalize.clone(),
+            _ => panic!("Expected an accepted deploy transaction"),
+        };
+
+        // Ensure a mismatched transaction index fails the check.
+        let mismatched = [ConfirmedTransaction::AcceptedDeploy(1, deployment_transaction.clone(), finalize)];
+        assert!(vm.check_speculate(state, &Transactions::from(&mismatched)).is_err());
+
+        // Ensure mismatched finalize operations fail the check.
+        let mismatched = [ConfirmedTransaction::AcceptedDeploy(0, deployment_transaction.clone(), vec![])];
+        assert!(vm.check_speculate(state, &Transactions::from(&mismatched)).is_err());
+
+        // Finalize the transaction.
+        vm.finalize(state, &confirmed_transactions).unwrap();
+
+        // Ensure the accepted redeployment fails the check, as the redeployment is now rejected.
+        assert!(vm.check_speculate(state, &confirmed_transactions).is_err());
+
+        // Ensure the rejected redeployment passes the check.
+        let rejected_transactions = vm.speculate(state, [deployment_transaction].iter()).unwrap();
+        assert!(rejected_transactions.iter().next().unwrap().is_rejected());
+        vm.check_speculate(state, &rejected_transactions).unwrap();
+    }
+
     #[test]
     fn test_atomic_finalize_many() {
         let rng = &mut TestRng::default();
@@ -708,7 +891,7 @@ finalize transfer_public:
         // Transfer_20 -> Balance = 20 - 20 = 0
         {
             let transactions = [mint_10.clone(), transfer_10.clone(), transfer_20.clone()];
-            let confirmed_transactions = vm.atomic_speculate(transactions.iter()).unwrap();
+            let confirmed_transactions = vm.atomic_speculate(test_helpers::sample_finalize_state(1), transactions.iter()).unwrap();
 
             // Assert that all the transactions are accepted.
             assert_eq!(confirmed_transactions.len(), 3);
@@ -726,7 +909,7 @@ finalize transfer_public:
         // Transfer_30 -> Balance = 30 - 30 = 0
         {
             let transactions = [transfer_20.clone(), mint_10.clone(), mint_20.clone(), transfer_30.clone()];
-            let confirmed_transactions = vm.atomic_speculate(transactions.iter()).unwrap();
+            let confirmed_transactions = vm.atomic_speculate(test_helpers::sample_finalize_state(1), transactions.iter()).unwrap();
 
             // Assert that all the transactions are accepted.
             assert_eq!(confirmed_transactions.len(), 4);
@@ -744,7 +927,7 @@ finalize transfer_public:
         // Transfer_10 -> Balance = 0 - 10 = -10 (should be rejected)
         {
             let transactions = [transfer_20.clone(), transfer_10.clone()];
-            let confirmed_transactions = vm.atomic_speculate(transactions.iter()).unwrap();
+            let confirmed_transactions = vm.atomic_speculate(test_helpers::sample_finalize_state(1), transactions.iter()).unwrap();
 
             // Assert that the accepted and rejected transactions are correct.
             assert_eq!(confirmed_transactions.len(), 2);
@@ -763,7 +946,7 @@ finalize transfer_public:
         // Transfer_10 -> Balance = 10 - 10 = 0
         {
             let transactions = [mint_20.clone(), transfer_30.clone(), transfer_20.clone(), transfer_10.clone()];
-            let confirmed_transactions = vm.atomic_speculate(transactions.iter()).unwrap();
+            let confirmed_transactions = vm.atomic_speculate(test_helpers::sample_finalize_state(1), transactions.iter()).unwrap();
 
             // Assert that the accepted and rejected transactions are correct.
             assert_eq!(confirmed_transactions.len(), 4);
@@ -780,6 +963,90 @@ finalize transfer_public:
         }
     }
 
+    #[test]
+    fn test_revert_to() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Add the deployment block to the VM.
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Generate more records to use for the next block.
+        let splits_block =
+            generate_splits(&vm, &caller_private_key, &deployment_block, &mut unspent_records, rng).unwrap();
+
+        // Add the splits block to the VM.
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Construct the initial mint.
+        let initial_mint =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+        let initial_mint_block =
+            sample_next_block(&vm, &caller_private_key, &[initial_mint], &splits_block, &mut unspent_records, rng)
+                .unwrap();
+
+        // Add the block to the vm.
+        vm.add_next_block(&initial_mint_block).unwrap();
+
+        // Ensure the mint is finalized.
+        let program_id = ProgramID::from_str(&program_id).unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str(&caller_address.to_string()).unwrap();
+        let value = vm.finalize_store().get_value_speculative(&program_id, &mapping_name, &key).unwrap();
+        assert_eq!(value, Some(Value::from_str("20u64").unwrap()));
+
+        // Ensure the VM cannot be reverted to the latest block, or beyond it.
+        assert!(vm.revert_to(3).is_err());
+        assert!(vm.revert_to(4).is_err());
+
+        // Revert the mint block.
+        vm.revert_to(2).unwrap();
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 2);
+        assert_eq!(vm.block_store().current_state_root(), vm.block_store().get_state_root(2).unwrap().unwrap());
+        assert!(
+            !vm.transaction_store()
+                .contains_transaction_id(&initial_mint_block.transaction_ids().next().unwrap())
+                .unwrap()
+        );
+        assert!(vm.finalize_store().get_value_speculative(&program_id, &mapping_name, &key).unwrap().is_none());
+        assert!(vm.contains_program(&program_id));
+
+        // Revert the deployment block.
+        vm.revert_to(0).unwrap();
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 0);
+        assert!(!vm.contains_program(&program_id));
+        assert!(!vm.finalize_store().contains_program_confirmed(&program_id).unwrap());
+
+        // Ensure the reverted blocks can be added again.
+        vm.add_next_block(&deployment_block).unwrap();
+        vm.add_next_block(&splits_block).unwrap();
+        vm.add_next_block(&initial_mint_block).unwrap();
+        let value = vm.finalize_store().get_value_speculative(&program_id, &mapping_name, &key).unwrap();
+        assert_eq!(value, Some(Value::from_str("20u64").unwrap()));
+    }
+
     #[test]
     fn test_finalize_catch_halt() {
         let rng = &mut TestRng::default();
@@ -857,7 +1124,7 @@ function ped_hash:
                 create_execution(&vm, caller_private_key, program_id, "ped_hash", inputs, &mut unspent_records, rng);
 
             // Speculatively execute the transaction. Ensure that this call does not panic and returns a rejected transaction.
-            let confirmed_transactions = vm.speculate([transaction.clone()].iter()).unwrap();
+            let confirmed_transactions = vm.speculate(test_helpers::sample_finalize_state(2), [transaction.clone()].iter()).unwrap();
 
             // Ensure that the transaction is rejected.
             assert_eq!(confirmed_transactions.len(), 1);
@@ -865,7 +1132,7 @@ function ped_hash:
             if let Transaction::Execute(_, execution, fee) = transaction {
                 let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
                 let expected_confirmed_transaction =
-                    ConfirmedTransaction::RejectedExecute(0, fee_transaction, crate::Rejected(execution));
+                    ConfirmedTransaction::RejectedExecute(0, fee_transaction, crate::Rejected(execution), vec![]);
 
                 let confirmed_transaction = confirmed_transactions.iter().next().unwrap();
                 assert_eq!(confirmed_transaction, &expected_confirmed_transaction);
@@ -1005,4 +1272,96 @@ finalize compute:
         let expected = Value::<CurrentNetwork>::from_str("3u8").unwrap();
         assert_eq!(value, expected);
     }
+
+    #[test]
+    fn test_speculate_drops_unpaid_public_fee() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Sample an address for the recipient.
+        let recipient_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Get the unspent records.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Prepare two public transfers of 1 microcredit from the caller.
+        let executions = (0..2)
+            .map(|_| {
+                let inputs = [
+                    Value::<CurrentNetwork>::from_str(&recipient_address.to_string()).unwrap(),
+                    Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+                ];
+                let authorization = vm
+                    .authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs.into_iter(), rng)
+                    .unwrap();
+                vm.execute_authorization_raw(authorization, None, rng).unwrap().1
+            })
+            .collect::<Vec<_>>();
+
+        // Compute the fee of each transfer.
+        let fee_in_microcredits = vm.execution_cost(&executions[0]).unwrap();
+
+        // Fund the public balance of the caller, with enough to pay only one of the two fees in full.
+        let record = unspent_records.pop().unwrap().decrypt(&caller_view_key).unwrap();
+        let inputs = vec![
+            Value::<CurrentNetwork>::Record(record),
+            Value::<CurrentNetwork>::from_str(&caller_address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str(&format!("{}u64", 2 * fee_in_microcredits)).unwrap(),
+        ];
+        let funding = create_execution(
+            &vm,
+            caller_private_key,
+            "credits.aleo",
+            "transfer_private_to_public",
+            inputs,
+            &mut unspent_records,
+            rng,
+        );
+        let funding_block =
+            sample_next_block(&vm, &caller_private_key, &[funding], &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&funding_block).unwrap();
+        assert_eq!(vm.get_public_balance(&caller_address).unwrap(), 2 * fee_in_microcredits);
+
+        // Construct the two transactions, each paying its fee from the public balance of the caller.
+        let transactions = executions
+            .into_iter()
+            .map(|execution| {
+                let (_, fee, _) =
+                    vm.execute_fee_public_raw(&caller_private_key, fee_in_microcredits, None, rng).unwrap();
+                Transaction::from_execution(execution, Some(fee)).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the second transaction is dropped, as the first transfer leaves too little to pay its fee.
+        let confirmed_transactions = vm.speculate(test_helpers::sample_finalize_state(2), transactions.iter()).unwrap();
+        assert_eq!(confirmed_transactions.len(), 1);
+        let confirmed = confirmed_transactions.iter().next().unwrap();
+        assert!(confirmed.is_accepted());
+        assert_eq!(confirmed.transaction().id(), transactions[0].id());
+
+        // Construct and add the next block.
+        let next_block =
+            sample_next_block(&vm, &caller_private_key, &transactions, &funding_block, &mut unspent_records, rng)
+                .unwrap();
+        assert_eq!(next_block.transactions().len(), 1);
+        vm.add_next_block(&next_block).unwrap();
+
+        // Ensure only the first fee and transfer were paid.
+        assert_eq!(vm.get_public_balance(&caller_address).unwrap(), fee_in_microcredits - 1);
+        assert_eq!(vm.get_public_balance(&recipient_address).unwrap(), 1);
+    }
 }
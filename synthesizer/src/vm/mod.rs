@@ -24,11 +24,12 @@ mod verify;
 pub use finalize::FinalizeMode;
 
 use crate::{
+    atomic_batch_scope,
     atomic_finalize,
     block::{Block, ConfirmedTransaction, Deployment, Execution, Fee, Header, Transaction, Transactions, Transition},
     cast_ref,
     process,
-    process::{Authorization, Inclusion, InclusionAssignment, Process, Query},
+    process::{Authorization, FinalizeGlobalState, Inclusion, InclusionAssignment, Process, Query},
     program::Program,
     store::{BlockStore, ConsensusStorage, ConsensusStore, FinalizeStore, TransactionStore, TransitionStore},
     CallMetrics,
@@ -36,13 +37,19 @@ use crate::{
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Entry, Identifier, Literal, Plaintext, ProgramID, ProgramOwner, Record, Response, Value},
+    program::{Access, Entry, Identifier, Literal, Plaintext, ProgramID, ProgramOwner, Record, Response, Value},
     types::Field,
 };
 
 use aleo_std::prelude::{finish, lap, timer};
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+/// The default maximum number of blocks that can be reverted.
+pub const DEFAULT_MAX_REORG_DEPTH: u32 = 100;
 
 #[derive(Clone)]
 pub struct VM<N: Network, C: ConsensusStorage<N>> {
@@ -50,6 +57,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     process: Arc<RwLock<Process<N>>>,
     /// The VM store.
     store: ConsensusStore<N, C>,
+    /// The maximum number of blocks that can be reverted.
+    max_reorg_depth: Arc<AtomicU32>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -69,20 +78,43 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
-        // Retrieve the transaction store.
-        let transaction_store = store.transaction_store();
-        // Load the deployments from the store.
-        for transaction_id in transaction_store.deployment_transaction_ids() {
-            // Retrieve the deployment.
-            match transaction_store.get_deployment(&transaction_id)? {
-                // Load the deployment.
-                Some(deployment) => process.load_deployment(&deployment)?,
-                None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
-            };
+        // Load the accepted deployments, in the order they were accepted.
+        if let Some(latest_height) = store.block_store().heights().max().map(|height| *height) {
+            for deployment in Self::accepted_deployments(&store, latest_height)? {
+                process.load_deployment(&deployment)?;
+            }
         }
 
         // Return the new VM.
-        Ok(Self { process: Arc::new(RwLock::new(process)), store })
+        Ok(Self {
+            process: Arc::new(RwLock::new(process)),
+            store,
+            max_reorg_depth: Arc::new(AtomicU32::new(DEFAULT_MAX_REORG_DEPTH)),
+        })
+    }
+
+    /// Returns the accepted deployments in the blocks up to the given height, in block and transaction order.
+    /// Note: Replaying the deployments in this order loads each program after its imports and its prior editions.
+    fn accepted_deployments(store: &ConsensusStore<N, C>, height: u32) -> Result<Vec<Deployment<N>>> {
+        let mut deployments = Vec::new();
+        for block_height in 0..=height {
+            // Retrieve the transactions of the block.
+            let transactions = match store.block_store().get_block_hash(block_height)? {
+                Some(block_hash) => store.block_store().get_block_transactions(&block_hash)?,
+                None => None,
+            };
+            let transactions =
+                transactions.ok_or_else(|| anyhow!("Missing the transactions of block {block_height}"))?;
+            // Collect the accepted deployments.
+            for transaction in transactions.iter() {
+                if let ConfirmedTransaction::AcceptedDeploy(_, Transaction::Deploy(_, _, deployment, _), _) =
+                    transaction
+                {
+                    deployments.push(*deployment.clone());
+                }
+            }
+        }
+        Ok(deployments)
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -96,6 +128,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn process(&self) -> Arc<RwLock<Process<N>>> {
         self.process.clone()
     }
+
+    /// Returns the maximum number of blocks that can be reverted.
+    #[inline]
+    pub fn max_reorg_depth(&self) -> u32 {
+        self.max_reorg_depth.load(Ordering::SeqCst)
+    }
+
+    /// Sets the maximum number of blocks that can be reverted.
+    /// The finalize pre-images of the blocks beyond this depth are pruned as each new block is finalized.
+    #[inline]
+    pub fn set_max_reorg_depth(&self, max_reorg_depth: u32) {
+        self.max_reorg_depth.store(max_reorg_depth, Ordering::SeqCst);
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -166,14 +211,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Adds the given block into the VM.
     #[inline]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
+        // Prepare the global state for finalize.
+        let state = FinalizeGlobalState::new::<N>(
+            block.height(),
+            block.timestamp(),
+            block.previous_hash(),
+            block.transactions().iter().flat_map(ConfirmedTransaction::unconfirmed_transition_ids),
+        )?;
         // First, insert the block.
         self.block_store().insert(block)?;
         // Next, finalize the transactions.
-        match self.finalize(block.transactions()) {
-            Ok(_) => {
-                // TODO (howardwu): Check the accepted, rejected, and finalize operations match the block.
-                Ok(())
-            }
+        // Note: This ensures the accepted and rejected transactions, and their finalize operations, match the block.
+        // To check the block before it is inserted, use `VM::check_speculate`.
+        match self.finalize(state, block.transactions()) {
+            Ok(_) => Ok(()),
             Err(error) => {
                 // Rollback the block.
                 self.block_store().remove_last_n(1)?;
@@ -182,6 +233,91 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
     }
+
+    /// Reverts the VM to the block at the given height, removing every block after it.
+    /// The finalize state is restored from the finalize pre-images of the removed blocks,
+    /// and the programs deployed in the removed blocks are unloaded from the process.
+    pub fn revert_to(&self, height: u32) -> Result<()> {
+        let timer = timer!("VM::revert_to");
+
+        // Retrieve the latest block height.
+        let latest_height = match self.block_store().heights().max() {
+            Some(latest_height) => *latest_height,
+            None => bail!("Cannot revert to block {height}, as there are no blocks in storage"),
+        };
+        // Ensure the height is before the latest height.
+        ensure!(height < latest_height, "Cannot revert to block {height}, as the latest block is {latest_height}");
+        // Ensure the height is within the maximum reorg depth.
+        let max_reorg_depth = self.max_reorg_depth();
+        ensure!(
+            latest_height - height <= max_reorg_depth,
+            "Cannot revert to block {height}, as it is more than {max_reorg_depth} blocks before the latest block"
+        );
+
+        // Acquire the write lock on the process.
+        // Note: This prevents any transactions from being finalized while the blocks are reverted.
+        let mut process = self.process.write();
+
+        // Collect the programs deployed in the blocks to revert.
+        let mut reverted_programs = Vec::new();
+        for block_height in height + 1..=latest_height {
+            // Ensure the finalize pre-image of the block exists.
+            ensure!(
+                self.finalize_store().contains_preimage_confirmed(block_height)?,
+                "Cannot revert block {block_height}, as its finalize pre-image is missing"
+            );
+            // Retrieve the transactions of the block.
+            let transactions = match self.block_store().get_block_hash(block_height)? {
+                Some(block_hash) => self.block_store().get_block_transactions(&block_hash)?,
+                None => None,
+            };
+            let transactions =
+                transactions.ok_or_else(|| anyhow!("Missing the transactions of block {block_height}"))?;
+            // Collect the accepted deployments.
+            for transaction in transactions.iter() {
+                if let ConfirmedTransaction::AcceptedDeploy(_, Transaction::Deploy(_, _, deployment, _), _) =
+                    transaction
+                {
+                    reverted_programs.push(*deployment.program_id());
+                }
+            }
+        }
+        lap!(timer, "Collect the deployments of the reverted blocks");
+
+        // Prepare the process, by removing the reverted programs (and their importers),
+        // and reloading the prior deployments of the removed programs.
+        let mut reverted_process = process.clone();
+        if !reverted_programs.is_empty() {
+            // Remove the reverted programs.
+            let removed_programs = reverted_process.remove_programs(&reverted_programs)?;
+
+            // Reload the prior deployments of the removed programs, in the order they were accepted.
+            for deployment in Self::accepted_deployments(&self.store, height)? {
+                if removed_programs.contains(deployment.program_id()) {
+                    reverted_process.load_deployment(&deployment)?;
+                }
+            }
+        }
+        lap!(timer, "Prepare the process");
+
+        // Revert the finalize state and remove the blocks, in a single atomic batch.
+        atomic_batch_scope!(self.store, {
+            // Restore the finalize pre-images, in descending order.
+            for block_height in (height + 1..=latest_height).rev() {
+                self.finalize_store().revert_preimage(block_height)?;
+            }
+            // Remove the blocks, along with their transactions and transitions.
+            // Note: This is performed last, as it updates the block tree on success.
+            self.block_store().remove_last_n(latest_height - height)
+        })?;
+        lap!(timer, "Revert the finalize state and remove the blocks");
+
+        // Update the process.
+        *process = reverted_process;
+
+        finish!(timer);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -214,6 +350,17 @@ pub(crate) mod test_helpers {
         VM::from(ConsensusStore::open(None).unwrap()).unwrap()
     }
 
+    pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
+        // Initialize the global state for finalize at the given block height.
+        FinalizeGlobalState::new::<CurrentNetwork>(
+            block_height,
+            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+            Default::default(),
+            [],
+        )
+        .unwrap()
+    }
+
     pub(crate) fn sample_genesis_private_key(rng: &mut TestRng) -> PrivateKey<CurrentNetwork> {
         static INSTANCE: OnceCell<PrivateKey<CurrentNetwork>> = OnceCell::new();
         *INSTANCE.get_or_init(|| {
@@ -411,11 +558,15 @@ function compute:
                 let authorization = vm.authorize(&caller_private_key, "credits.aleo", "mint", inputs, rng).unwrap();
                 assert_eq!(authorization.len(), 1);
 
-                // Execute the fee.
-                let fee = vm.execute_fee_raw(&caller_private_key, record, 100, None, rng).unwrap().1;
-
                 // Execute.
-                let transaction = vm.execute_authorization(authorization, Some(fee), None, rng).unwrap();
+                let (_, execution, _) = vm.execute_authorization_raw(authorization, None, rng).unwrap();
+
+                // Execute the fee, for the cost of the execution.
+                let fee_in_microcredits = vm.execution_cost(&execution).unwrap();
+                let fee = vm.execute_fee_raw(&caller_private_key, record, fee_in_microcredits, None, rng).unwrap().1;
+
+                // Construct the transaction.
+                let transaction = Transaction::from_execution(execution, Some(fee)).unwrap();
                 // Verify.
                 assert!(vm.verify_transaction(&transaction));
                 // Return the transaction.
@@ -484,7 +635,7 @@ function compute:
         let previous_block = vm.block_store().get_block(&block_hash).unwrap().unwrap();
 
         // Construct the new block header.
-        let transactions = vm.speculate(transactions.iter())?;
+        let transactions = vm.speculate(sample_finalize_state(previous_block.height() + 1), transactions.iter())?;
         // Construct the metadata associated with the block.
         let metadata = Metadata::new(
             Testnet3::ID,
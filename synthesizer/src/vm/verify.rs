@@ -112,7 +112,26 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 }
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(*id), "Invalid signature for the deployment transaction '{id}'");
-                // Verify the fee.
+                // If the deployment is an upgrade, ensure it is signed by the owner of the existing program.
+                if deployment.edition() != N::EDITION {
+                    // Retrieve the program ID.
+                    let program_id = deployment.program_id();
+                    // Retrieve the owner of the existing program.
+                    let existing_owner = match self.transaction_store().get_owner(program_id)? {
+                        Some(existing_owner) => existing_owner,
+                        None => bail!("Cannot upgrade program '{program_id}', as it has not been deployed"),
+                    };
+                    ensure!(
+                        existing_owner.address() == owner.address(),
+                        "Only the owner of program '{program_id}' may deploy a new edition"
+                    );
+                }
+                // Ensure the fee covers the cost of the deployment, and verify the fee.
+                let cost = self.deployment_cost(deployment)?;
+                ensure!(
+                    *fee.amount()? >= cost,
+                    "Insufficient fee (deployment) - the minimum fee is {cost} microcredits"
+                );
                 self.check_fee(fee)?;
                 // Verify the deployment.
                 self.check_deployment(deployment)?;
@@ -122,14 +141,26 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if let Err(error) = Transaction::check_execution_size(execution) {
                     bail!("Invalid transaction size (execution): {error}");
                 }
+                // Compute the minimum fee of the execution.
                 // TODO (raychu86): Remove `is_split` check once batch executions are supported.
-                // Ensure the fee is present, if the transaction is not a coinbase or split.
-                if !transaction.is_coinbase() && !transaction.is_split() && fee.is_none() {
-                    bail!("Transaction is missing a fee (execution)");
-                }
-                // Verify the fee.
-                if let Some(fee) = fee {
-                    self.check_fee(fee)?;
+                // Note: A coinbase or split transaction does not require a fee.
+                let cost = match transaction.is_coinbase() || transaction.is_split() {
+                    true => 0,
+                    false => self.execution_cost(execution)?,
+                };
+                // Ensure the fee covers the cost of the execution, and verify the fee.
+                match fee {
+                    Some(fee) => {
+                        ensure!(
+                            *fee.amount()? >= cost,
+                            "Insufficient fee (execution) - the minimum fee is {cost} microcredits"
+                        );
+                        self.check_fee(fee)?;
+                    }
+                    None => ensure!(
+                        cost == 0,
+                        "Transaction is missing a fee (execution) - the minimum fee is {cost} microcredits"
+                    ),
                 }
                 // Verify the execution.
                 self.check_execution(execution)?;
@@ -149,6 +180,42 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Ok(())
     }
 
+    /// Verifies the rejected deployment or execution of the given confirmed transaction, and ensures the fee
+    /// covers its cost. If the confirmed transaction is accepted, this is a no-op. On failure, returns an error.
+    ///
+    /// Note: A rejected deployment is not verified against the process, as it may conflict with a deployed program.
+    pub fn check_rejected_transaction(&self, transaction: &ConfirmedTransaction<N>) -> Result<()> {
+        // Retrieve the fee, and compute the cost of the rejected deployment or execution.
+        let (fee, cost) = match transaction {
+            ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::AcceptedExecute(..) => return Ok(()),
+            ConfirmedTransaction::RejectedDeploy(_, fee_transaction, deployment, _) => {
+                // Check the deployment size.
+                if let Err(error) = Transaction::check_deployment_size(deployment) {
+                    bail!("Invalid rejected deployment size: {error}");
+                }
+                (fee_transaction.fee_transition(), self.deployment_cost(deployment)?)
+            }
+            ConfirmedTransaction::RejectedExecute(_, fee_transaction, execution, _) => {
+                // Check the execution size.
+                if let Err(error) = Transaction::check_execution_size(execution) {
+                    bail!("Invalid rejected execution size: {error}");
+                }
+                // Verify the execution.
+                self.check_execution(execution)?;
+                (fee_transaction.fee_transition(), self.execution_cost(execution)?)
+            }
+        };
+        // Ensure the fee covers the cost of the rejected deployment or execution.
+        match fee {
+            Some(fee) => ensure!(
+                *fee.amount()? >= cost,
+                "Insufficient fee (rejected transaction) - the minimum fee is {cost} microcredits"
+            ),
+            None => bail!("Rejected transaction '{}' is missing a fee", transaction.id()),
+        }
+        Ok(())
+    }
+
     /// Verifies the given deployment. On failure, returns an error.
     #[inline]
     fn check_deployment(&self, deployment: &Deployment<N>) -> Result<()> {
@@ -214,13 +281,33 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         match verification {
             // Ensure the global state root exists in the block store.
             Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
-                Ok(true) => Ok(()),
+                Ok(true) => match fee.is_fee_public() {
+                    // Ensure the public balance is sufficient to pay the fee.
+                    true => self.check_fee_public(fee),
+                    false => Ok(()),
+                },
                 Ok(false) => bail!("Fee verification failed: global state root not found"),
                 Err(error) => bail!("Fee verification failed: {error}"),
             },
             Err(error) => bail!("Fee verification failed: {error}"),
         }
     }
+
+    /// Verifies the public balance of the caller is sufficient to pay the given fee.
+    /// Note: This checks against the committed balance, so it does not account for other fees in the same block.
+    #[inline]
+    fn check_fee_public(&self, fee: &Fee<N>) -> Result<()> {
+        // Retrieve the caller of the public fee.
+        let caller = match fee.payer()? {
+            Some(caller) => caller,
+            None => bail!("Fee verification failed: missing the caller of the public fee"),
+        };
+        // Ensure the public balance is sufficient to pay the fee.
+        match self.get_public_balance(&caller)? >= *fee.amount()? {
+            true => Ok(()),
+            false => bail!("Fee verification failed: public balance of '{caller}' is insufficient"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -386,7 +473,8 @@ mod tests {
         let deployment_transaction = vm.deploy(&caller_private_key, &program, fee, None, rng).unwrap();
 
         // Construct the new block header.
-        let transactions = vm.speculate([deployment_transaction].iter()).unwrap();
+        let transactions =
+            vm.speculate(test_helpers::sample_finalize_state(1), [deployment_transaction].iter()).unwrap();
 
         // Construct the metadata associated with the block.
         let deployment_metadata = Metadata::new(
@@ -424,10 +512,6 @@ mod tests {
 
         // Prepare the fee.
         let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-        let fee_in_microcredits = 10;
-
-        // Execute the fee.
-        let fee = vm.execute_fee_raw(&caller_private_key, credits, fee_in_microcredits, None, rng).unwrap().1;
 
         // Prepare the inputs.
         let inputs = [
@@ -441,7 +525,19 @@ mod tests {
         assert_eq!(authorization.len(), 1);
 
         // Execute.
-        let transaction = vm.execute_authorization(authorization, Some(fee), None, rng).unwrap();
+        let (_, execution, _) = vm.execute_authorization_raw(authorization, None, rng).unwrap();
+        // Compute the minimum fee.
+        let fee_in_microcredits = vm.execution_cost(&execution).unwrap();
+
+        // Ensure a fee below the execution cost is rejected.
+        let fee =
+            vm.execute_fee_raw(&caller_private_key, credits.clone(), fee_in_microcredits - 1, None, rng).unwrap().1;
+        let transaction = Transaction::from_execution(execution.clone(), Some(fee)).unwrap();
+        assert!(vm.check_transaction(&transaction).is_err());
+
+        // Execute the fee.
+        let fee = vm.execute_fee_raw(&caller_private_key, credits, fee_in_microcredits, None, rng).unwrap().1;
+        let transaction = Transaction::from_execution(execution, Some(fee)).unwrap();
 
         // Verify.
         assert!(vm.check_transaction(&transaction).is_ok());
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, New, Run, Update};
+use crate::cli::commands::{Account, Build, Clean, Deploy, Devnet, Execute, Fmt, Lint, New, Run, Update};
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
@@ -38,10 +38,22 @@ pub struct CLI {
 
 #[derive(Debug, Parser)]
 pub enum Command {
+    #[clap(subcommand)]
+    Account(Account),
     #[clap(name = "build")]
     Build(Build),
     #[clap(name = "clean")]
     Clean(Clean),
+    #[clap(name = "deploy")]
+    Deploy(Deploy),
+    #[clap(name = "devnet")]
+    Devnet(Devnet),
+    #[clap(name = "execute")]
+    Execute(Execute),
+    #[clap(name = "fmt")]
+    Fmt(Fmt),
+    #[clap(name = "lint")]
+    Lint(Lint),
     #[clap(name = "new")]
     New(New),
     #[clap(name = "run")]
@@ -54,8 +66,14 @@ impl Command {
     /// Parse the command.
     pub fn parse(self) -> Result<String> {
         match self {
+            Self::Account(command) => command.parse(),
             Self::Build(command) => command.parse(),
             Self::Clean(command) => command.parse(),
+            Self::Deploy(command) => command.parse(),
+            Self::Devnet(command) => command.parse(),
+            Self::Execute(command) => command.parse(),
+            Self::Fmt(command) => command.parse(),
+            Self::Lint(command) => command.parse(),
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
             Self::Update(command) => command.parse(),
@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::prelude::{Address, Ciphertext, PrivateKey, Record, Signature, ViewKey};
+
+use rand::{
+    rngs::{OsRng, StdRng},
+    SeedableRng,
+};
+use serde_json::json;
+
+/// A 32-byte RNG seed, given as a hex string.
+#[derive(Clone, Debug)]
+pub struct Seed([u8; 32]);
+
+impl FromStr for Seed {
+    type Err = anyhow::Error;
+
+    /// Parses a seed from a string of 64 hex characters.
+    fn from_str(seed: &str) -> Result<Self> {
+        let seed = seed.strip_prefix("0x").unwrap_or(seed);
+        ensure!(
+            seed.len() == 64 && seed.chars().all(|c| c.is_ascii_hexdigit()),
+            "The seed must be 32 bytes, given as 64 hex characters"
+        );
+        let mut bytes = [0u8; 32];
+        for (byte, chunk) in bytes.iter_mut().zip(seed.as_bytes().chunks(2)) {
+            *byte = u8::from_str_radix(std::str::from_utf8(chunk)?, 16)?;
+        }
+        Ok(Self(bytes))
+    }
+}
+
+/// Commands to manage Aleo accounts.
+#[derive(Debug, Parser)]
+pub enum Account {
+    /// Generates a new Aleo account.
+    New {
+        /// Seeds the RNG with the given 32-byte hex value, for a deterministic account (not for real funds).
+        #[clap(short = 's', long)]
+        seed: Option<Seed>,
+        /// Prints the output as JSON.
+        #[clap(long)]
+        json: bool,
+    },
+    /// Signs a message with the given private key.
+    Sign {
+        /// The private key used for signing.
+        #[clap(long)]
+        private_key: PrivateKey<CurrentNetwork>,
+        /// The message to sign.
+        #[clap(long)]
+        message: String,
+        /// Prints the output as JSON.
+        #[clap(long)]
+        json: bool,
+    },
+    /// Verifies the signature of a message for the given address.
+    Verify {
+        /// The address of the signer.
+        #[clap(long)]
+        address: Address<CurrentNetwork>,
+        /// The signature to verify.
+        #[clap(long)]
+        signature: Signature<CurrentNetwork>,
+        /// The message that was signed.
+        #[clap(long)]
+        message: String,
+        /// Prints the output as JSON.
+        #[clap(long)]
+        json: bool,
+    },
+    /// Decrypts a record ciphertext with the given view key.
+    Decrypt {
+        /// The view key of the record owner.
+        #[clap(long)]
+        view_key: ViewKey<CurrentNetwork>,
+        /// The record ciphertext to decrypt.
+        #[clap(long)]
+        ciphertext: Record<CurrentNetwork, Ciphertext<CurrentNetwork>>,
+        /// Prints the output as JSON.
+        #[clap(long)]
+        json: bool,
+    },
+    /// Derives the view key and address of the given private key.
+    ViewKey {
+        /// The private key of the account.
+        #[clap(long)]
+        private_key: PrivateKey<CurrentNetwork>,
+        /// Prints the output as JSON.
+        #[clap(long)]
+        json: bool,
+    },
+}
+
+impl Account {
+    /// Runs the account command.
+    pub fn parse(self) -> Result<String> {
+        match self {
+            Self::New { seed, json } => {
+                // Initialize the RNG, warning that a seeded account is only as secret as its seed.
+                let mut rng = match seed {
+                    Some(seed) => {
+                        eprintln!("⚠️  This account is derived from the given seed - do not use it for real funds");
+                        StdRng::from_seed(seed.0)
+                    }
+                    None => StdRng::from_entropy(),
+                };
+                // Sample a new private key.
+                let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+                // Print the account.
+                Self::print_account(&private_key, json)
+            }
+            Self::Sign { private_key, message, json } => {
+                // Sign the message.
+                let signature = private_key.sign_bytes(message.as_bytes(), &mut OsRng)?;
+                // Print the signature.
+                match json {
+                    true => Ok(json!({ "signature": signature.to_string() }).to_string()),
+                    false => Ok(signature.to_string()),
+                }
+            }
+            Self::Verify { address, signature, message, json } => {
+                // Verify the signature.
+                let is_valid = signature.verify_bytes(&address, message.as_bytes());
+                // Print the result.
+                match (json, is_valid) {
+                    (true, _) => Ok(json!({ "valid": is_valid }).to_string()),
+                    (false, true) => Ok(format!("✅ The signature is valid for '{}'", address.to_string().bold())),
+                    (false, false) => bail!("The signature is invalid for '{address}'"),
+                }
+            }
+            Self::Decrypt { view_key, ciphertext, json } => {
+                // Ensure the view key owns the record.
+                ensure!(ciphertext.is_owner(&view_key), "The record is not owned by the given view key");
+                // Decrypt the record.
+                let record = ciphertext.decrypt(&view_key)?;
+                // Print the record.
+                match json {
+                    true => Ok(json!({ "record": record.to_string() }).to_string()),
+                    false => Ok(record.to_string()),
+                }
+            }
+            Self::ViewKey { private_key, json } => Self::print_account(&private_key, json),
+        }
+    }
+
+    /// Returns the private key, view key, and address of the account, as a string.
+    fn print_account(private_key: &PrivateKey<CurrentNetwork>, json: bool) -> Result<String> {
+        // Derive the view key and address.
+        let view_key = ViewKey::try_from(private_key)?;
+        let address = Address::try_from(&view_key)?;
+
+        match json {
+            true => Ok(json!({
+                "private_key": private_key.to_string(),
+                "view_key": view_key.to_string(),
+                "address": address.to_string(),
+            })
+            .to_string()),
+            false => Ok(format!(
+                "\n {:>12}  {private_key}\n {:>12}  {view_key}\n {:>12}  {address}\n",
+                "Private Key".cyan().bold(),
+                "View Key".cyan().bold(),
+                "Address".cyan().bold(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_seed() {
+        let seed = Seed::from_str(&"01".repeat(32)).unwrap();
+        let first = Account::New { seed: Some(seed.clone()), json: true }.parse().unwrap();
+        let second = Account::New { seed: Some(seed), json: true }.parse().unwrap();
+        assert_eq!(first, second);
+
+        // Ensure the derived view key and address match.
+        let account: serde_json::Value = serde_json::from_str(&first).unwrap();
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(account["private_key"].as_str().unwrap()).unwrap();
+        let view_key = Account::ViewKey { private_key, json: true }.parse().unwrap();
+        assert_eq!(first, view_key);
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut OsRng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let message = "hello world".to_string();
+
+        // Sign the message.
+        let signature = Account::Sign { private_key, message: message.clone(), json: false }.parse().unwrap();
+        let signature = Signature::from_str(&signature).unwrap();
+
+        // Verify the signature.
+        let output = Account::Verify { address, signature, message: message.clone(), json: true }.parse().unwrap();
+        assert_eq!(output, json!({ "valid": true }).to_string());
+
+        // Ensure a different message does not verify.
+        let message = "goodbye world".to_string();
+        assert!(Account::Verify { address, signature, message, json: false }.parse().is_err());
+    }
+
+    #[test]
+    fn test_seed_must_be_32_bytes() {
+        assert!(Seed::from_str(&"ab".repeat(32)).is_ok());
+        assert!(Seed::from_str(&format!("0x{}", "ab".repeat(32))).is_ok());
+        assert!(Seed::from_str("1").is_err());
+        assert!(Seed::from_str(&"ab".repeat(31)).is_err());
+        assert!(Seed::from_str(&"zz".repeat(32)).is_err());
+    }
+}
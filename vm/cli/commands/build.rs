@@ -23,6 +23,9 @@ pub struct Build {
     /// Toggles offline mode.
     #[clap(long)]
     offline: bool,
+    /// Rewrites the lockfile with the currently resolved imports.
+    #[clap(long)]
+    update_lockfile: bool,
 }
 
 impl Build {
@@ -34,6 +37,11 @@ impl Build {
         // Load the package.
         let package = Package::open(&path)?;
 
+        // Rewrite the lockfile, if requested.
+        if self.update_lockfile {
+            package.update_lockfile()?;
+        }
+
         // Build the package, if the package requires building.
         package.build::<Aleo>(self.endpoint)?;
 
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::prelude::PrivateKey;
+
+/// Deploys the Aleo program in the current directory, producing a transaction
+#[derive(Debug, Parser)]
+pub struct Deploy {
+    /// The private key used to deploy the program and pay the fee.
+    #[clap(long)]
+    private_key: PrivateKey<CurrentNetwork>,
+    /// The record used to pay the fee.
+    #[clap(long)]
+    fee_record: Record<CurrentNetwork, Plaintext<CurrentNetwork>>,
+    /// The priority fee in microcredits.
+    #[clap(long, default_value = "0")]
+    priority_fee: u64,
+    /// The ledger used to look up imports and state paths.
+    #[clap(flatten)]
+    ledger: LedgerArgs,
+    /// Writes the transaction to the specified path.
+    #[clap(short = 'o', long)]
+    output: Option<PathBuf>,
+    /// Writes the transaction as bytes, instead of JSON.
+    #[clap(long)]
+    bytes: bool,
+}
+
+impl Deploy {
+    /// Deploys the Aleo program in the current directory.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::<CurrentNetwork>::open(&path)?;
+        // Retrieve the program.
+        let program = package.program();
+
+        println!("⏳ Deploying '{}'...\n", program.id().to_string().bold());
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Compute the deployment transaction.
+        let transaction = with_ledger!(self.ledger, |vm, query| {
+            // Add the program imports to the VM.
+            add_imports(&vm, program, &query)?;
+            // Deploy the program.
+            vm.deploy(&self.private_key, program, (self.fee_record, self.priority_fee), Some(query), rng)?
+        });
+
+        // Write the transaction.
+        let path = write_transaction(&transaction, self.output, self.bytes)?;
+
+        Ok(transaction_summary(&transaction, &path))
+    }
+}
@@ -0,0 +1,396 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::{
+        network::prelude::{FromBytes, Network},
+        types::Field,
+    },
+    ledger::Ledger,
+    prelude::{Address, PrivateKey},
+    synthesizer::{
+        block::Block,
+        store::{helpers::memory::ConsensusMemory, ConsensusStore},
+    },
+};
+
+use rand::{rngs::StdRng, CryptoRng, Rng, SeedableRng};
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::mpsc,
+    time::Duration,
+};
+
+/// Runs a local development chain, sealing blocks from a directory (or stdin) of transactions
+#[derive(Debug, Parser)]
+pub struct Devnet {
+    /// The development ID of the ledger, stored in `.ledger-{network}-{id}`.
+    #[clap(long, default_value = "0")]
+    dev: u16,
+    /// The private key used to sign blocks (defaults to the development key for the given ID).
+    #[clap(long)]
+    private_key: Option<PrivateKey<CurrentNetwork>>,
+    /// Watches the specified directory for transaction files (as JSON or bytes).
+    #[clap(long, conflicts_with = "stdin")]
+    transactions: Option<PathBuf>,
+    /// Reads transactions from stdin, as one JSON transaction per line.
+    #[clap(long)]
+    stdin: bool,
+    /// The interval between blocks, in seconds.
+    #[clap(long, default_value = "5")]
+    interval: u64,
+    /// Stops the devnet after sealing the specified number of blocks.
+    #[clap(long)]
+    max_blocks: Option<u32>,
+    /// Serves queries over the ledger at the specified address, as the `--endpoint` for `deploy` and `execute`.
+    #[clap(long, default_value = "127.0.0.1:3030")]
+    rest: SocketAddr,
+}
+
+impl Devnet {
+    /// Runs the devnet.
+    pub fn parse(self) -> Result<String> {
+        #[cfg(feature = "rocks")]
+        {
+            self.run::<crate::synthesizer::store::helpers::rocksdb::ConsensusDB<CurrentNetwork>>()
+        }
+        #[cfg(not(feature = "rocks"))]
+        {
+            bail!("The devnet requires the 'rocks' feature")
+        }
+    }
+
+    /// Runs the devnet over a ledger with the given storage.
+    pub fn run<C: ConsensusStorage<CurrentNetwork>>(self) -> Result<String> {
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Prepare the private key of the block producer.
+        let private_key = match self.private_key {
+            Some(private_key) => private_key,
+            None => DevnetLedger::<C>::development_private_key(self.dev)?,
+        };
+        // Load the genesis block, or create it if it does not exist yet.
+        let genesis = Self::load_genesis(&private_key, self.dev, rng)?;
+        // Load the ledger.
+        let devnet = DevnetLedger::<C>::load(private_key, genesis, self.dev)?;
+        // Serve queries over the ledger, as other processes cannot open it while the devnet is running.
+        let rest = devnet.serve(self.rest)?;
+
+        println!(
+            "🌱 Started devnet {} at block {} (producer {})\n",
+            self.dev.to_string().bold(),
+            devnet.ledger().latest_height(),
+            devnet.address().to_string().bold()
+        );
+        println!("🌐 Serving queries at {}\n", format!("http://{rest}").bold());
+
+        // If requested, read transactions from stdin in the background.
+        let (sender, receiver) = mpsc::channel();
+        if self.stdin {
+            std::thread::spawn(move || {
+                for line in std::io::stdin().lock().lines().map_while(Result::ok) {
+                    if !line.trim().is_empty() && sender.send(line).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        let mut num_blocks = 0u32;
+        while self.max_blocks.map_or(true, |max_blocks| num_blocks < max_blocks) {
+            // Wait for the next block interval.
+            std::thread::sleep(Duration::from_secs(self.interval));
+
+            // Collect the pending transactions, along with the files they were read from.
+            let files = match &self.transactions {
+                Some(directory) => Self::read_directory(&devnet, directory)?,
+                None => Vec::new(),
+            };
+            let mut transactions = files.iter().map(|(_, transaction)| transaction.clone()).collect::<Vec<_>>();
+            while let Ok(line) = receiver.try_recv() {
+                match Transaction::from_str(&line).and_then(|transaction| devnet.check_transaction(transaction)) {
+                    Ok(transaction) => transactions.push(transaction),
+                    Err(error) => eprintln!("⚠️  Rejected a transaction from stdin - {error}"),
+                }
+            }
+
+            // Skip the block if there are no transactions.
+            if transactions.is_empty() {
+                continue;
+            }
+
+            // Seal the next block.
+            match devnet.seal_next_block(&transactions, rng) {
+                Ok(block) => {
+                    println!(
+                        "📦 Sealed block {} ('{}') with {} transaction(s)",
+                        block.height().to_string().bold(),
+                        block.hash(),
+                        block.transactions().len()
+                    );
+                    num_blocks += 1;
+
+                    // Move the transaction files into `sealed/` if they are in the block, or `rejected/` otherwise.
+                    if let Some(directory) = &self.transactions {
+                        for (path, transaction) in files {
+                            match block.transaction_ids().any(|id| *id == transaction.id()) {
+                                true => Self::move_file(directory, &path, "sealed")?,
+                                false => {
+                                    eprintln!("⚠️  Rejected '{}' - it was not sealed in the block", path.display());
+                                    Self::move_file(directory, &path, "rejected")?
+                                }
+                            }
+                        }
+                    }
+                }
+                // Note: The transaction files are left in the directory, to be retried in the next block.
+                Err(error) => eprintln!("⚠️  Failed to seal the next block - {error}"),
+            }
+        }
+
+        Ok(format!("✅ Stopped devnet {} at block {}", self.dev.to_string().bold(), devnet.ledger().latest_height()))
+    }
+
+    /// Loads the genesis block of the devnet with the given ID, creating it if it does not exist yet.
+    fn load_genesis<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<CurrentNetwork>,
+        dev: u16,
+        rng: &mut R,
+    ) -> Result<Block<CurrentNetwork>> {
+        // Prepare the path of the genesis block, inside the ledger directory.
+        let directory = aleo_std::aleo_ledger_dir(CurrentNetwork::ID, Some(dev));
+        std::fs::create_dir_all(&directory)?;
+        let path = directory.join(format!(".genesis-{}-{dev}", CurrentNetwork::ID));
+        // If the genesis block exists, load it.
+        if path.exists() {
+            return Block::from_bytes_le(&std::fs::read(&path)?);
+        }
+
+        // Initialize a VM to create the genesis block.
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None)?)?;
+        let genesis = vm.genesis(private_key, rng)?;
+        // Write the genesis block.
+        std::fs::write(&path, genesis.to_bytes_le()?)?;
+        Ok(genesis)
+    }
+
+    /// Reads and checks the transaction files in the given directory, returning the valid transactions and their paths.
+    /// Invalid files are moved into `rejected/`, while valid files are left in place until they are sealed.
+    fn read_directory<C: ConsensusStorage<CurrentNetwork>>(
+        devnet: &DevnetLedger<C>,
+        directory: &Path,
+    ) -> Result<Vec<(PathBuf, Transaction<CurrentNetwork>)>> {
+        // Collect the transaction files, in order of their file names.
+        let mut paths = std::fs::read_dir(directory)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .filter(|path| path.is_file())
+            .filter(|path| !path.file_name().and_then(|name| name.to_str()).map_or(true, |name| name.starts_with('.')))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let mut transactions = Vec::with_capacity(paths.len());
+        for path in paths {
+            // Parse the transaction, as JSON or as bytes.
+            let bytes = std::fs::read(&path)?;
+            let transaction = match std::str::from_utf8(&bytes) {
+                Ok(string) => Transaction::from_str(string.trim()),
+                Err(_) => Transaction::from_bytes_le(&bytes),
+            };
+            // Check the transaction, and move the file into `rejected/` if it is invalid.
+            match transaction.and_then(|transaction| devnet.check_transaction(transaction)) {
+                Ok(transaction) => transactions.push((path, transaction)),
+                Err(error) => {
+                    eprintln!("⚠️  Rejected '{}' - {error}", path.display());
+                    Self::move_file(directory, &path, "rejected")?;
+                }
+            }
+        }
+        Ok(transactions)
+    }
+
+    /// Moves the given file into the given subdirectory of the directory.
+    fn move_file(directory: &Path, path: &Path, subdirectory: &str) -> Result<()> {
+        let subdirectory = directory.join(subdirectory);
+        std::fs::create_dir_all(&subdirectory)?;
+        if let Some(file_name) = path.file_name() {
+            std::fs::rename(path, subdirectory.join(file_name))?;
+        }
+        Ok(())
+    }
+}
+
+/// A single-process development chain, which seals blocks signed by a development key.
+pub struct DevnetLedger<C: ConsensusStorage<CurrentNetwork>> {
+    /// The ledger.
+    ledger: Ledger<CurrentNetwork, C>,
+    /// The private key of the block producer.
+    private_key: PrivateKey<CurrentNetwork>,
+    /// The address of the block producer.
+    address: Address<CurrentNetwork>,
+}
+
+impl<C: ConsensusStorage<CurrentNetwork>> DevnetLedger<C> {
+    /// Returns the development private key for the given development ID.
+    pub fn development_private_key(dev: u16) -> Result<PrivateKey<CurrentNetwork>> {
+        PrivateKey::new(&mut StdRng::seed_from_u64(u64::from(dev)))
+    }
+
+    /// Loads the development ledger with the given ID, initializing it with the genesis block if it is empty.
+    pub fn load(private_key: PrivateKey<CurrentNetwork>, genesis: Block<CurrentNetwork>, dev: u16) -> Result<Self> {
+        // Derive the address of the block producer.
+        let address = Address::try_from(&private_key)?;
+        Ok(Self { ledger: Ledger::load(genesis, Some(dev))?, private_key, address })
+    }
+
+    /// Returns the ledger.
+    pub const fn ledger(&self) -> &Ledger<CurrentNetwork, C> {
+        &self.ledger
+    }
+
+    /// Returns the address of the block producer.
+    pub const fn address(&self) -> &Address<CurrentNetwork> {
+        &self.address
+    }
+
+    /// Returns a query over the devnet ledger.
+    pub fn query(&self) -> Query<CurrentNetwork, C::BlockStorage> {
+        Query::from(self.ledger.vm().block_store())
+    }
+
+    /// Serves the routes of a REST `Query` over the devnet ledger at the given address, returning the bound address.
+    pub fn serve(&self, address: SocketAddr) -> Result<SocketAddr> {
+        // Bind the listener.
+        let listener = TcpListener::bind(address)?;
+        let address = listener.local_addr()?;
+        // Answer the requests in the background.
+        let query = self.query();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if let Err(error) = stream.map_err(Into::into).and_then(|stream| Self::respond(&query, stream)) {
+                    eprintln!("⚠️  Failed to answer a query - {error}");
+                }
+            }
+        });
+        Ok(address)
+    }
+
+    /// Answers the HTTP request on the given stream, with the result of the query as JSON.
+    fn respond(query: &Query<CurrentNetwork, C::BlockStorage>, stream: TcpStream) -> Result<()> {
+        // Read the request line, and skip the headers.
+        let mut reader = BufReader::new(&stream);
+        let mut request = String::new();
+        reader.read_line(&mut request)?;
+        let mut header = String::new();
+        while reader.read_line(&mut header)? > 0 && !header.trim().is_empty() {
+            header.clear();
+        }
+
+        // Route the request to the query.
+        let path = request.split_whitespace().nth(1).unwrap_or_default();
+        let (status, body) = match Self::route(query, path) {
+            Ok(body) => ("200 OK", body),
+            Err(error) => ("404 Not Found", error.to_string()),
+        };
+        // Write the response.
+        let header = format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        (&stream).write_all(header.as_bytes())?;
+        (&stream).write_all(body.as_bytes())?;
+        Ok(())
+    }
+
+    /// Returns the result of the query at the given path, as JSON.
+    fn route(query: &Query<CurrentNetwork, C::BlockStorage>, path: &str) -> Result<String> {
+        match path.trim_start_matches('/').split('/').collect::<Vec<_>>().as_slice() {
+            ["testnet3", "program", program_id] => {
+                Ok(serde_json::to_string(&query.get_program(&ProgramID::from_str(program_id)?)?)?)
+            }
+            ["testnet3", "latest", "stateRoot"] => Ok(serde_json::to_string(&query.current_state_root()?)?),
+            ["testnet3", "statePath", commitment] => {
+                Ok(serde_json::to_string(&query.get_state_path_for_commitment(&Field::from_str(commitment)?)?)?)
+            }
+            _ => bail!("Unknown query '{path}'"),
+        }
+    }
+
+    /// Checks the given transaction against the devnet ledger, returning it if it is valid.
+    pub fn check_transaction(&self, transaction: Transaction<CurrentNetwork>) -> Result<Transaction<CurrentNetwork>> {
+        // Ensure the transaction does not already exist.
+        ensure!(
+            !self.ledger.contains_transaction_id(&transaction.id())?,
+            "Transaction '{}' already exists in the ledger",
+            transaction.id()
+        );
+        // Ensure the transaction is valid.
+        self.ledger.vm().check_transaction(&transaction)?;
+        Ok(transaction)
+    }
+
+    /// Speculates the given transactions, and seals them into the next block.
+    pub fn seal_next_block<R: Rng + CryptoRng>(
+        &self,
+        transactions: &[Transaction<CurrentNetwork>],
+        rng: &mut R,
+    ) -> Result<Block<CurrentNetwork>> {
+        // Prepare the next block.
+        let block = self.ledger.prepare_advance_to_next_block(&self.private_key, transactions.to_vec(), vec![], rng)?;
+        // Add the block to the ledger.
+        // Note: The block is checked to be a valid next block, before it is added.
+        self.ledger.add_next_block(&block)?;
+        Ok(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_next_block() {
+        let rng = &mut rand::thread_rng();
+
+        // Initialize the devnet.
+        let private_key = DevnetLedger::<ConsensusMemory<CurrentNetwork>>::development_private_key(0).unwrap();
+        let vm =
+            VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap()).unwrap();
+        let genesis = vm.genesis(&private_key, rng).unwrap();
+        let devnet = DevnetLedger::<ConsensusMemory<CurrentNetwork>>::load(private_key, genesis, 0).unwrap();
+        assert_eq!(devnet.ledger().latest_height(), 0);
+
+        // Serve queries over the devnet ledger.
+        let address = devnet.serve("127.0.0.1:0".parse().unwrap()).unwrap();
+        let query = Query::from(format!("http://{address}"));
+        assert_eq!(query.current_state_root().unwrap(), devnet.ledger().latest_state_root());
+
+        // Transfer credits back to the producer, querying the state paths through the devnet.
+        let transaction = devnet.ledger().create_transfer(&private_key, *devnet.address(), 1, 0, Some(query)).unwrap();
+        let transaction = devnet.check_transaction(transaction).unwrap();
+
+        // Seal the next block.
+        let block = devnet.seal_next_block(std::slice::from_ref(&transaction), rng).unwrap();
+        assert_eq!(block.height(), 1);
+        assert_eq!(devnet.ledger().latest_height(), 1);
+        assert!(devnet.ledger().contains_transaction_id(&transaction.id()).unwrap());
+
+        // Ensure the same transaction cannot be sealed again.
+        assert!(devnet.check_transaction(transaction).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::prelude::PrivateKey;
+
+/// Executes an Aleo program function, producing a transaction
+#[derive(Debug, Parser)]
+pub struct Execute {
+    /// The program ID.
+    program_id: ProgramID<CurrentNetwork>,
+    /// The function name.
+    function: Identifier<CurrentNetwork>,
+    /// The function inputs.
+    inputs: Vec<Value<CurrentNetwork>>,
+    /// The private key used to execute the function and pay the fee.
+    #[clap(long)]
+    private_key: PrivateKey<CurrentNetwork>,
+    /// The record used to pay the fee.
+    #[clap(long)]
+    fee_record: Option<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>,
+    /// The priority fee in microcredits.
+    #[clap(long, default_value = "0", requires = "fee_record")]
+    priority_fee: u64,
+    /// The ledger used to look up programs and state paths.
+    #[clap(flatten)]
+    ledger: LedgerArgs,
+    /// Writes the transaction to the specified path.
+    #[clap(short = 'o', long)]
+    output: Option<PathBuf>,
+    /// Writes the transaction as bytes, instead of JSON.
+    #[clap(long)]
+    bytes: bool,
+}
+
+impl Execute {
+    /// Executes the Aleo program function with the specified name.
+    pub fn parse(self) -> Result<String> {
+        // Prepare the locator.
+        let locator = Locator::<CurrentNetwork>::from_str(&format!("{}/{}", self.program_id, self.function))?;
+
+        println!("⏳ Executing '{}'...\n", locator.to_string().bold());
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Prepare the fee.
+        let fee = self.fee_record.map(|fee_record| (fee_record, self.priority_fee));
+
+        // Compute the execution transaction.
+        let transaction = with_ledger!(self.ledger, |vm, query| {
+            // Add the program (and its imports) to the VM, if it is not already loaded.
+            if !vm.contains_program(&self.program_id) {
+                let program = query.get_program(&self.program_id)?;
+                add_imports(&vm, &program, &query)?;
+                vm.process().write().add_program(&program)?;
+            }
+            // Execute the function.
+            let transaction = vm.execute(
+                &self.private_key,
+                (self.program_id, self.function),
+                self.inputs.into_iter(),
+                fee,
+                Some(query),
+                rng,
+            )?;
+            // Ensure a fee is specified, if the execution has a cost.
+            // Note: A coinbase or split transaction does not require a fee.
+            if let Transaction::Execute(_, execution, None) = &transaction {
+                if !transaction.is_coinbase() && !transaction.is_split() {
+                    let cost = vm.execution_cost(execution)?;
+                    ensure!(
+                        cost == 0,
+                        "Executing '{locator}' costs {cost} microcredits, please specify a '--fee-record' to pay the fee"
+                    );
+                }
+            }
+            transaction
+        });
+
+        // Write the transaction.
+        let path = write_transaction(&transaction, self.output, self.bytes)?;
+
+        Ok(transaction_summary(&transaction, &path))
+    }
+}
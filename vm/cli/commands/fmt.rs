@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::file::AleoFile;
+
+/// Formats an Aleo program, retaining its comments.
+#[derive(Debug, Parser)]
+pub struct Fmt {
+    /// Checks that the program is formatted, without modifying it.
+    #[clap(long)]
+    check: bool,
+}
+
+impl Fmt {
+    /// Formats the Aleo program in the current directory.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::<CurrentNetwork>::open(&path)?;
+        // Retrieve the main program file name.
+        let file_name = AleoFile::<CurrentNetwork>::main_file_name();
+
+        // Format the program.
+        let source = package.program_file().program_string();
+        let formatted = Program::<CurrentNetwork>::format(source)?;
+
+        // Log the program as formatted, if it is unchanged.
+        if formatted == source {
+            return Ok(format!("✅ '{}' is formatted", file_name.bold()));
+        }
+
+        match self.check {
+            true => bail!("'{file_name}' is not formatted. Run 'snarkvm fmt' to format it"),
+            false => {
+                // Write the formatted program.
+                std::fs::write(path.join(&file_name), formatted)?;
+                Ok(format!("✅ Formatted '{}'", file_name.bold()))
+            }
+        }
+    }
+}
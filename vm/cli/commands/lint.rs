@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Reports suspicious code in an Aleo program.
+#[derive(Debug, Parser)]
+pub struct Lint {
+    /// Fails if the program has any warnings.
+    #[clap(long)]
+    deny_warnings: bool,
+}
+
+impl Lint {
+    /// Lints the Aleo program in the current directory.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::<CurrentNetwork>::open(&path)?;
+        // Retrieve the program ID.
+        let program_id = package.program_id();
+
+        // Lint the program.
+        let process = package.get_process()?;
+        let lints = process.get_stack(program_id)?.lint()?;
+
+        // Log the lint as successful, if there are no warnings.
+        if lints.is_empty() {
+            return Ok(format!("✅ No warnings in '{}'", program_id.to_string().bold()));
+        }
+
+        // Prepare the report.
+        let report = lints.iter().map(|lint| format!("⚠️  {lint}")).collect::<Vec<_>>().join("\n");
+        let summary = format!("'{}' has {} warning(s)", program_id.to_string().bold(), lints.len());
+        match self.deny_warnings {
+            true => bail!("{report}\n\n{summary}"),
+            false => Ok(format!("{report}\n\n⚠️  {summary}")),
+        }
+    }
+}
@@ -12,12 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod account;
+pub use account::*;
+
 pub mod build;
 pub use build::*;
 
 pub mod clean;
 pub use clean::*;
 
+pub mod deploy;
+pub use deploy::*;
+
+pub mod devnet;
+pub use devnet::*;
+
+pub mod execute;
+pub use execute::*;
+
+pub mod fmt;
+pub use fmt::*;
+
+pub mod lint;
+pub use lint::*;
+
 pub mod new;
 pub use new::*;
 
@@ -29,14 +47,133 @@ pub use update::*;
 
 use crate::{
     package::Package,
-    prelude::{Identifier, Locator, ProgramID, Value},
+    prelude::{
+        ConsensusStorage,
+        Identifier,
+        Locator,
+        Plaintext,
+        Program,
+        ProgramID,
+        Query,
+        Record,
+        ToBytes,
+        Transaction,
+        Value,
+        VM,
+    },
+    synthesizer::store::helpers::StorageMode,
 };
 
-use anyhow::Result;
-use clap::Parser;
+use anyhow::{bail, ensure, Result};
+use clap::{Args, Parser};
 use colored::Colorize;
 use core::str::FromStr;
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
 pub(crate) type CurrentNetwork = crate::prelude::Testnet3;
 pub(crate) type Aleo = crate::circuit::AleoV0;
+
+/// The ledger used to look up programs and state paths when constructing a transaction.
+#[derive(Debug, Args)]
+pub struct LedgerArgs {
+    /// Queries the ledger through the specified REST endpoint.
+    #[clap(long, conflicts_with_all = ["dev", "path"])]
+    endpoint: Option<String>,
+    /// Queries the local development ledger with the specified ID, stored in `.ledger-{network}-{id}`.
+    #[clap(long, conflicts_with = "path")]
+    dev: Option<u16>,
+    /// Queries the local ledger stored in the specified directory.
+    #[clap(long)]
+    path: Option<PathBuf>,
+}
+
+impl LedgerArgs {
+    /// Returns the storage mode of the local ledger, if one is specified.
+    fn storage_mode(&self) -> Option<StorageMode> {
+        match (self.dev, &self.path) {
+            (Some(dev), _) => Some(StorageMode::Development(dev)),
+            (None, Some(path)) => Some(StorageMode::Custom(path.clone())),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Runs the given logic with a VM and query over the ledger selected by the given `LedgerArgs`.
+macro_rules! with_ledger {
+    ($ledger:expr, |$vm:ident, $query:ident| $logic:expr) => {{
+        use crate::synthesizer::{store::helpers::memory::ConsensusMemory, ConsensusStore};
+
+        match (&$ledger.endpoint, $ledger.storage_mode()) {
+            (Some(endpoint), _) => {
+                // Initialize an in-memory VM, which loads programs through the endpoint.
+                let $vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None)?)?;
+                let $query = Query::from(endpoint);
+                $logic
+            }
+            #[cfg(feature = "rocks")]
+            (None, Some(storage_mode)) => {
+                use crate::synthesizer::store::helpers::rocksdb::ConsensusDB;
+
+                // Initialize a VM over the local ledger.
+                let $vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(storage_mode)?)?;
+                let $query = Query::from($vm.block_store());
+                $logic
+            }
+            #[cfg(not(feature = "rocks"))]
+            (None, Some(_)) => bail!("Querying a local ledger requires the 'rocks' feature"),
+            (None, None) => bail!("Please specify an '--endpoint', or a local '--dev' or '--path' ledger"),
+        }
+    }};
+}
+pub(crate) use with_ledger;
+
+/// Adds the imports of the given program to the VM, retrieving any missing programs through the query.
+pub(crate) fn add_imports<C: ConsensusStorage<CurrentNetwork>>(
+    vm: &VM<CurrentNetwork, C>,
+    program: &Program<CurrentNetwork>,
+    query: &Query<CurrentNetwork, C::BlockStorage>,
+) -> Result<()> {
+    for program_id in program.imports().keys() {
+        if !vm.contains_program(program_id) {
+            // Retrieve the imported program.
+            let import = query.get_program(program_id)?;
+            // Add the imports of the imported program.
+            add_imports(vm, &import, query)?;
+            // Add the imported program.
+            vm.process().write().add_program(&import)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the transaction to the given path (or a default path in the current directory), as JSON or as bytes.
+pub(crate) fn write_transaction(
+    transaction: &Transaction<CurrentNetwork>,
+    output: Option<PathBuf>,
+    as_bytes: bool,
+) -> Result<PathBuf> {
+    // Determine the output path.
+    let path = match output {
+        Some(path) => path,
+        None => std::env::current_dir()?.join(match as_bytes {
+            true => format!("{}.transaction", transaction.id()),
+            false => format!("{}.transaction.json", transaction.id()),
+        }),
+    };
+    // Write the transaction.
+    match as_bytes {
+        true => std::fs::write(&path, transaction.to_bytes_le()?)?,
+        false => std::fs::write(&path, serde_json::to_string_pretty(transaction)?)?,
+    }
+    Ok(path)
+}
+
+/// Returns a string describing the written transaction.
+pub(crate) fn transaction_summary(transaction: &Transaction<CurrentNetwork>, path: &Path) -> String {
+    // Prepare the path string.
+    let path_string = format!("(in \"{}\")", path.display());
+    format!("✅ Created transaction '{}' {}", transaction.id().to_string().bold(), path_string.dimmed())
+}
@@ -13,6 +13,10 @@
 // limitations under the License.
 
 use super::*;
+use crate::prelude::{CallMetrics, Trace};
+
+use clap::ValueEnum;
+use std::cmp::Reverse;
 
 pub const LOCALE: &num_format::Locale = &num_format::Locale::en;
 
@@ -29,6 +33,33 @@ pub struct Run {
     /// Toggles offline mode.
     #[clap(long)]
     offline: bool,
+    /// Prints every evaluated instruction, with its operand and register values.
+    #[clap(long)]
+    trace: bool,
+    /// Prints the circuit cost of every instruction in each function call.
+    #[clap(long)]
+    profile: bool,
+    /// Sorts the profile by the given column.
+    #[clap(long, value_enum, default_value_t = ProfileSort::Constraints, requires = "profile")]
+    sort_by: ProfileSort,
+    /// Writes the profile as JSON to the given path.
+    #[clap(long, requires = "profile")]
+    profile_output: Option<PathBuf>,
+}
+
+/// The column to sort a profile by.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ProfileSort {
+    /// The order of the instructions in the function.
+    Order,
+    /// The number of constraints, in descending order.
+    Constraints,
+    /// The number of public variables, in descending order.
+    Public,
+    /// The number of private variables, in descending order.
+    Private,
+    /// The total number of non-zero entries, in descending order.
+    Nonzeros,
 }
 
 impl Run {
@@ -44,14 +75,31 @@ impl Run {
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
 
+        // Prepare the private key.
+        let private_key = package.manifest_file().development_private_key();
+
         // Execute the request.
-        let (response, _transition, _inclusion, metrics) = package.run::<Aleo, _>(
+        let trace = self.trace.then(Trace::new);
+        let result = package.run_with_options::<Aleo, _>(
             self.endpoint,
-            package.manifest_file().development_private_key(),
+            private_key,
             self.function,
             &self.inputs,
+            trace.as_ref(),
+            self.profile,
             rng,
-        )?;
+        );
+        // Log the trace, which ends at the failing instruction if the run failed.
+        if let Some(trace) = trace {
+            println!("\n🔍 Trace\n");
+            for entry in trace.entries() {
+                match entry.error() {
+                    Some(_) => println!(" • {}", entry.to_string().red()),
+                    None => println!(" • {entry}"),
+                }
+            }
+        }
+        let (response, _transition, _inclusion, metrics) = result?;
 
         // Count the number of times a function is called.
         let mut program_frequency = HashMap::<String, usize>::new();
@@ -88,6 +136,17 @@ impl Run {
             println!(" •  {function_constraints} {counter_string}",)
         }
 
+        // Log the profile.
+        if self.profile {
+            println!("\n📊 Profile (sorted by {:?})\n", self.sort_by);
+            print!("{}", profile_report(&metrics, self.sort_by));
+            // Write the profile, if requested.
+            if let Some(path) = &self.profile_output {
+                std::fs::write(path, serde_json::to_string_pretty(&profile_json(&metrics))?)?;
+                println!("\n📝 Wrote the profile to {}", path.display());
+            }
+        }
+
         // Log the outputs.
         match response.outputs().len() {
             0 => (),
@@ -107,3 +166,132 @@ impl Run {
         Ok(format!("✅ Executed '{}' {}", locator.to_string().bold(), path_string.dimmed()))
     }
 }
+
+/// Returns a table of the circuit cost of every instruction in each function call, sorted by the given column.
+pub(crate) fn profile_report(metrics: &[CallMetrics<CurrentNetwork>], sort_by: ProfileSort) -> String {
+    use num_format::ToFormattedString;
+
+    let mut report = String::new();
+    for metric in metrics {
+        // Print the function call.
+        report += &format!(
+            " • {} - {} constraints ({} request, {} response)\n",
+            format!("'{}/{}'", metric.program_id, metric.function_name).bold(),
+            metric.num_function_constraints.to_formatted_string(LOCALE),
+            metric.num_request_constraints.to_formatted_string(LOCALE),
+            metric.num_response_constraints.to_formatted_string(LOCALE),
+        );
+
+        // Sort the instructions.
+        let mut instructions = metric.instructions.iter().collect::<Vec<_>>();
+        match sort_by {
+            ProfileSort::Order => instructions.sort_by_key(|instruction| instruction.index),
+            ProfileSort::Constraints => instructions.sort_by_key(|instruction| Reverse(instruction.num_constraints)),
+            ProfileSort::Public => instructions.sort_by_key(|instruction| Reverse(instruction.num_public)),
+            ProfileSort::Private => instructions.sort_by_key(|instruction| Reverse(instruction.num_private)),
+            ProfileSort::Nonzeros => instructions.sort_by_key(|instruction| {
+                let (a, b, c) = instruction.num_nonzeros;
+                Reverse(a + b + c)
+            }),
+        }
+
+        // Print the instructions.
+        report += &format!(
+            "   {:>4}  {:>12}  {:>8}  {:>10}  {:>26}  {}\n",
+            "#", "constraints", "public", "private", "nonzeros (A, B, C)", "instruction"
+        )
+        .dimmed()
+        .to_string();
+        for instruction in instructions {
+            let (a, b, c) = instruction.num_nonzeros;
+            let nonzeros = format!("({a}, {b}, {c})");
+            report += &format!(
+                "   {:>4}  {:>12}  {:>8}  {:>10}  {:>26}  {}\n",
+                instruction.index,
+                instruction.num_constraints.to_formatted_string(LOCALE),
+                instruction.num_public.to_formatted_string(LOCALE),
+                instruction.num_private.to_formatted_string(LOCALE),
+                nonzeros,
+                instruction.instruction,
+            );
+        }
+    }
+    report
+}
+
+/// Returns the profile of each function call as JSON, for tracking regressions across runs.
+pub(crate) fn profile_json(metrics: &[CallMetrics<CurrentNetwork>]) -> serde_json::Value {
+    serde_json::Value::Array(
+        metrics
+            .iter()
+            .map(|metric| {
+                serde_json::json!({
+                    "program_id": metric.program_id.to_string(),
+                    "function": metric.function_name.to_string(),
+                    "num_request_constraints": metric.num_request_constraints,
+                    "num_function_constraints": metric.num_function_constraints,
+                    "num_response_constraints": metric.num_response_constraints,
+                    "instructions": metric.instructions.iter().map(|instruction| serde_json::json!({
+                        "index": instruction.index,
+                        "instruction": instruction.instruction,
+                        "closure": instruction.closure.map(|closure| closure.to_string()),
+                        "num_constraints": instruction.num_constraints,
+                        "num_public": instruction.num_public,
+                        "num_private": instruction.num_private,
+                        "num_nonzeros": [instruction.num_nonzeros.0, instruction.num_nonzeros.1, instruction.num_nonzeros.2],
+                    })).collect::<Vec<_>>(),
+                })
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::InstructionMetrics;
+
+    fn sample_metrics() -> Vec<CallMetrics<CurrentNetwork>> {
+        let instruction = |index: usize, instruction: &str, num_constraints: u64| InstructionMetrics {
+            index,
+            instruction: instruction.to_string(),
+            closure: None,
+            num_constraints,
+            num_public: 0,
+            num_private: num_constraints,
+            num_nonzeros: (num_constraints, num_constraints, num_constraints),
+        };
+        vec![CallMetrics {
+            program_id: ProgramID::from_str("token.aleo").unwrap(),
+            function_name: Identifier::from_str("transfer").unwrap(),
+            num_instructions: 2,
+            num_request_constraints: 100,
+            num_function_constraints: 12,
+            num_response_constraints: 50,
+            instructions: vec![instruction(0, "sub r0 r1 into r2;", 2), instruction(1, "hash.bhp256 r2 into r3;", 10)],
+        }]
+    }
+
+    #[test]
+    fn test_profile_report() {
+        colored::control::set_override(false);
+        let metrics = sample_metrics();
+
+        // Ensure the report is sorted by the number of constraints.
+        let report = profile_report(&metrics, ProfileSort::Constraints);
+        assert!(report.find("hash.bhp256").unwrap() < report.find("sub r0").unwrap());
+
+        // Ensure the report can be sorted in program order.
+        let report = profile_report(&metrics, ProfileSort::Order);
+        assert!(report.find("sub r0").unwrap() < report.find("hash.bhp256").unwrap());
+    }
+
+    #[test]
+    fn test_profile_json() {
+        let profile = profile_json(&sample_metrics());
+        assert_eq!(profile[0]["program_id"], "token.aleo");
+        assert_eq!(profile[0]["function"], "transfer");
+        assert_eq!(profile[0]["instructions"][1]["num_constraints"], 10);
+        assert_eq!(profile[0]["instructions"][1]["num_nonzeros"], serde_json::json!([10, 10, 10]));
+    }
+}
@@ -229,8 +229,9 @@ impl<N: Network> AleoFile<N> {
 
         // Read the program string.
         let program_string = fs::read_to_string(file)?;
-        // Parse the program string.
-        let program = Program::from_str(&program_string)?;
+        // Parse the program string, reporting every syntax error against the file.
+        let program = Program::from_source(&program_string)
+            .map_err(|diagnostics| anyhow!("{}", diagnostics.render(&program_string, &file.display().to_string())))?;
 
         Ok(Self { file_name, program_string, program })
     }
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    prelude::{Network, ProgramID},
+    synthesizer::Program,
+};
+use snarkvm_utilities::{FromBits, ToBits, ToBytes};
+
+use anyhow::{anyhow, ensure, Result};
+use core::str::FromStr;
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const LOCKFILE_NAME: &str = "program.lock";
+
+/// A resolved dependency, as recorded in the lockfile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockedDependency<N: Network> {
+    /// The program ID of the dependency.
+    program_id: ProgramID<N>,
+    /// The (optional) version of the dependency, as declared in the manifest.
+    version: Option<String>,
+    /// The checksum of the dependency.
+    checksum: String,
+}
+
+impl<N: Network> LockedDependency<N> {
+    /// Initializes a new locked dependency.
+    pub const fn new(program_id: ProgramID<N>, version: Option<String>, checksum: String) -> Self {
+        Self { program_id, version, checksum }
+    }
+
+    /// Returns the program ID of the dependency.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the version of the dependency.
+    pub const fn version(&self) -> Option<&String> {
+        self.version.as_ref()
+    }
+
+    /// Returns the checksum of the dependency.
+    pub fn checksum(&self) -> &str {
+        &self.checksum
+    }
+}
+
+pub struct Lockfile<N: Network> {
+    /// The file path.
+    path: PathBuf,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The resolved dependencies, in topological order.
+    dependencies: Vec<LockedDependency<N>>,
+}
+
+impl<N: Network> Lockfile<N> {
+    /// Creates (or overwrites) the lockfile with the given directory path, program ID, and dependencies.
+    pub fn create(directory: &Path, program_id: &ProgramID<N>, dependencies: Vec<LockedDependency<N>>) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The program directory does not exist: '{}'", directory.display());
+
+        // Construct the lockfile.
+        let json = serde_json::json!({
+            "program": program_id.to_string(),
+            "dependencies": dependencies
+                .iter()
+                .map(|dependency| {
+                    let mut json = serde_json::json!({
+                        "program": dependency.program_id.to_string(),
+                        "checksum": dependency.checksum,
+                    });
+                    if let Some(version) = &dependency.version {
+                        json["version"] = serde_json::Value::from(version.as_str());
+                    }
+                    json
+                })
+                .collect::<Vec<_>>(),
+        });
+
+        // Construct the file path.
+        let path = directory.join(LOCKFILE_NAME);
+        // Write the file.
+        File::create(&path)?.write_all(format!("{}\n", serde_json::to_string_pretty(&json)?).as_bytes())?;
+
+        // Return the lockfile.
+        Ok(Self { path, program_id: *program_id, dependencies })
+    }
+
+    /// Opens the lockfile for reading.
+    pub fn open(directory: &Path) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The program directory does not exist: '{}'", directory.display());
+
+        // Construct the file path.
+        let path = directory.join(LOCKFILE_NAME);
+        // Ensure the file path exists.
+        ensure!(path.exists(), "Lockfile is missing: '{}'", path.display());
+
+        // Read the file to a string.
+        let lockfile_string = fs::read_to_string(&path)?;
+        let json: serde_json::Value = serde_json::from_str(&lockfile_string)?;
+
+        // Retrieve the program ID.
+        let id_string = json["program"].as_str().ok_or_else(|| anyhow!("Program ID not found."))?;
+        let program_id = ProgramID::from_str(id_string)?;
+
+        // Retrieve the dependencies.
+        let dependencies = json["dependencies"]
+            .as_array()
+            .ok_or_else(|| anyhow!("Dependencies not found."))?
+            .iter()
+            .map(|dependency| {
+                let id_string = dependency["program"].as_str().ok_or_else(|| anyhow!("Dependency ID not found."))?;
+                let checksum = dependency["checksum"].as_str().ok_or_else(|| anyhow!("Checksum not found."))?;
+                let version = dependency["version"].as_str().map(str::to_string);
+                Ok(LockedDependency::new(ProgramID::from_str(id_string)?, version, checksum.to_string()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Return the lockfile.
+        Ok(Self { path, program_id, dependencies })
+    }
+
+    /// Returns `true` if the lockfile exists at the given path.
+    pub fn exists_at(directory: &Path) -> bool {
+        // Construct the file path.
+        let path = directory.join(LOCKFILE_NAME);
+        // Return the result.
+        path.is_file() && path.exists()
+    }
+
+    /// Returns the lockfile name.
+    pub const fn file_name() -> &'static str {
+        LOCKFILE_NAME
+    }
+
+    /// Returns the file path.
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the resolved dependencies, in topological order.
+    pub fn dependencies(&self) -> &[LockedDependency<N>] {
+        &self.dependencies
+    }
+
+    /// Returns the checksum of the given program, as the hex-encoded SHA3-256 hash of the program bytes.
+    pub fn checksum(program: &Program<N>) -> Result<String> {
+        // Hash the program bytes.
+        let hash = N::hash_sha3_256(&program.to_bytes_le()?.to_bits_le())?;
+        // Encode the hash as a hex string.
+        hash.chunks(8).map(|bits| Ok(format!("{:02x}", u8::from_bits_le(bits)?))).collect()
+    }
+}
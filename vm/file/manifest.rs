@@ -17,8 +17,9 @@ use crate::{
     synthesizer::Program,
 };
 
-use anyhow::{anyhow, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 use core::str::FromStr;
+use indexmap::IndexMap;
 use std::{
     fs::{self, File},
     io::Write,
@@ -27,6 +28,32 @@ use std::{
 
 const MANIFEST_FILE_NAME: &str = "program.json";
 
+/// A dependency declared in the manifest file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Dependency {
+    /// The (optional) version of the dependency.
+    version: Option<String>,
+    /// The (optional) checksum of the dependency.
+    checksum: Option<String>,
+}
+
+impl Dependency {
+    /// Initializes a new dependency with the given (optional) version and checksum.
+    pub const fn new(version: Option<String>, checksum: Option<String>) -> Self {
+        Self { version, checksum }
+    }
+
+    /// Returns the version of the dependency.
+    pub const fn version(&self) -> Option<&String> {
+        self.version.as_ref()
+    }
+
+    /// Returns the checksum of the dependency.
+    pub const fn checksum(&self) -> Option<&String> {
+        self.checksum.as_ref()
+    }
+}
+
 pub struct Manifest<N: Network> {
     /// The file path.
     path: PathBuf,
@@ -36,6 +63,8 @@ pub struct Manifest<N: Network> {
     development_private_key: PrivateKey<N>,
     /// The development address.
     development_address: Address<N>,
+    /// The declared dependencies.
+    dependencies: IndexMap<ProgramID<N>, Dependency>,
 }
 
 impl<N: Network> Manifest<N> {
@@ -77,7 +106,13 @@ impl<N: Network> Manifest<N> {
         File::create(&path)?.write_all(manifest_string.as_bytes())?;
 
         // Return the manifest file.
-        Ok(Self { path, program_id: *id, development_private_key: private_key, development_address: address })
+        Ok(Self {
+            path,
+            program_id: *id,
+            development_private_key: private_key,
+            development_address: address,
+            dependencies: IndexMap::new(),
+        })
     }
 
     /// Opens the manifest file for reading.
@@ -115,8 +150,25 @@ impl<N: Network> Manifest<N> {
             "Development address does not match development private key."
         );
 
+        // Retrieve the dependencies, if they are declared.
+        let dependencies = match json.get("dependencies") {
+            Some(serde_json::Value::Object(dependencies)) => dependencies
+                .iter()
+                .map(|(program_id, dependency)| {
+                    // Parse the program ID of the dependency.
+                    let program_id = ProgramID::from_str(program_id)?;
+                    // Retrieve the (optional) version and checksum.
+                    let version = dependency["version"].as_str().map(str::to_string);
+                    let checksum = dependency["checksum"].as_str().map(str::to_string);
+                    Ok((program_id, Dependency::new(version, checksum)))
+                })
+                .collect::<Result<IndexMap<_, _>>>()?,
+            Some(_) => bail!("Dependencies must be declared as an object."),
+            None => IndexMap::new(),
+        };
+
         // Return the manifest file.
-        Ok(Self { path, program_id: id, development_private_key, development_address })
+        Ok(Self { path, program_id: id, development_private_key, development_address, dependencies })
     }
 
     /// Returns `true` if the manifest file exists at the given path.
@@ -151,4 +203,9 @@ impl<N: Network> Manifest<N> {
     pub const fn development_address(&self) -> &Address<N> {
         &self.development_address
     }
+
+    /// Returns the declared dependencies.
+    pub const fn dependencies(&self) -> &IndexMap<ProgramID<N>, Dependency> {
+        &self.dependencies
+    }
 }
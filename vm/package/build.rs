@@ -153,6 +153,11 @@ impl<N: Network> Package<N> {
         &self,
         endpoint: Option<String>,
     ) -> Result<()> {
+        // Write the lockfile, if it does not exist.
+        if !Lockfile::<N>::exists_at(self.directory()) {
+            self.update_lockfile()?;
+        }
+
         // Skip the 'build' if the program is already built.
         if !self.is_build_required::<A>() {
             return Ok(());
@@ -16,13 +16,14 @@ mod build;
 mod clean;
 mod deploy;
 mod is_build_required;
+mod resolve;
 mod run;
 
 pub use build::{BuildRequest, BuildResponse};
 pub use deploy::{DeployRequest, DeployResponse};
 
 use crate::{
-    file::{AVMFile, AleoFile, Manifest, ProverFile, VerifierFile, README},
+    file::{AVMFile, AleoFile, Lockfile, Manifest, ProverFile, VerifierFile, README},
     prelude::{
         Deserialize,
         Deserializer,
@@ -40,15 +41,18 @@ use crate::{
     synthesizer::{
         snark::{ProvingKey, VerifyingKey},
         CallOperator,
+        Diagnostic,
+        Diagnostics,
         Execution,
         Inclusion,
         Instruction,
         Process,
         Program,
+        SourceMap,
     },
 };
 
-use anyhow::{bail, ensure, Error, Result};
+use anyhow::{bail, ensure, Result};
 use core::str::FromStr;
 use rand::{CryptoRng, Rng};
 use std::path::{Path, PathBuf};
@@ -162,17 +166,24 @@ impl<N: Network> Package<N> {
         // Create the process.
         let mut process = Process::load()?;
 
-        // Prepare the imports directory.
-        let imports_directory = self.imports_directory();
-
-        // Add all import programs (in order) to the process.
-        self.program().imports().keys().try_for_each(|program_id| {
-            // Open the Aleo program file.
-            let import_program_file = AleoFile::open(&imports_directory, program_id, false)?;
-            // Add the import program.
-            process.add_program(import_program_file.program())?;
-            Ok::<_, Error>(())
-        })?;
+        // Add all import programs (in topological order) to the process.
+        for import in self.resolve_imports()? {
+            process.add_program(&import)?;
+        }
+
+        // Type-check the program, reporting every error against the program file.
+        let errors = process.check_program(self.program());
+        if !errors.is_empty() {
+            // Retrieve the program source.
+            let source = self.program_file.program_string();
+            // Map each error to the statement that caused it.
+            let source_map = SourceMap::<N>::new(source);
+            let diagnostics =
+                errors.iter().map(|error| Diagnostic::from_error(source, &source_map, error)).collect::<Diagnostics>();
+            // Construct the program file path.
+            let path = self.directory.join(AleoFile::<N>::main_file_name());
+            bail!("{}", diagnostics.render(source, &path.display().to_string()))
+        }
 
         // Add the program to the process.
         process.add_program(self.program())?;
@@ -0,0 +1,283 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::file::LockedDependency;
+
+use indexmap::IndexMap;
+
+impl<N: Network> Package<N> {
+    /// Resolves the (transitive) imports of the main program from the imports directory,
+    /// ensuring they match the manifest dependencies and the lockfile (if it exists).
+    /// Returns the imported programs in topological order.
+    pub fn resolve_imports(&self) -> Result<Vec<Program<N>>> {
+        // Resolve the imports.
+        let (imports, dependencies) = self.resolve_dependencies()?;
+
+        // If the lockfile exists, ensure the resolved dependencies match it.
+        if Lockfile::<N>::exists_at(&self.directory) {
+            let lockfile = Lockfile::<N>::open(&self.directory)?;
+            ensure!(
+                lockfile.program_id() == &self.program_id,
+                "The program ID in '{}' does not match ('{}' != '{}')",
+                Lockfile::<N>::file_name(),
+                lockfile.program_id(),
+                self.program_id
+            );
+            Self::check_lockfile(&lockfile, &dependencies)?;
+        }
+
+        Ok(imports)
+    }
+
+    /// Resolves the (transitive) imports of the main program, and writes the lockfile.
+    pub fn update_lockfile(&self) -> Result<Lockfile<N>> {
+        // Resolve the imports.
+        let (_, dependencies) = self.resolve_dependencies()?;
+        // Write the lockfile.
+        Lockfile::create(&self.directory, &self.program_id, dependencies)
+    }
+
+    /// Resolves the (transitive) imports of the main program, ensuring they match the manifest dependencies.
+    /// Returns the imported programs and their locked dependencies, in topological order.
+    #[allow(clippy::type_complexity)]
+    fn resolve_dependencies(&self) -> Result<(Vec<Program<N>>, Vec<LockedDependency<N>>)> {
+        // Prepare the imports directory.
+        let imports_directory = self.imports_directory();
+
+        // Resolve the imports in post-order, starting from the main program.
+        let mut resolved = IndexMap::new();
+        let mut stack = vec![self.program_id];
+        for program_id in self.program().imports().keys() {
+            Self::resolve_import(&imports_directory, program_id, &mut stack, &mut resolved)?;
+        }
+
+        // Retrieve the declared dependencies.
+        let declared = self.manifest_file().dependencies();
+        // Ensure every declared dependency is imported.
+        for program_id in declared.keys() {
+            ensure!(
+                resolved.contains_key(program_id),
+                "Dependency '{program_id}' is declared in '{}', but is not imported",
+                Manifest::<N>::file_name()
+            );
+        }
+
+        // Lock each import, ensuring it matches its declared dependency (if dependencies are declared).
+        let dependencies = resolved
+            .values()
+            .map(|program| {
+                // Compute the checksum of the import.
+                let checksum = Lockfile::checksum(program)?;
+                // Retrieve the declared dependency.
+                let dependency = declared.get(program.id());
+                ensure!(
+                    declared.is_empty() || dependency.is_some(),
+                    "Import '{}' is not declared as a dependency in '{}'",
+                    program.id(),
+                    Manifest::<N>::file_name()
+                );
+                // Ensure the checksum matches, if one is declared.
+                if let Some(expected) = dependency.and_then(|dependency| dependency.checksum()) {
+                    ensure!(
+                        *expected == checksum,
+                        "Checksum mismatch for '{}' (expected '{expected}', found '{checksum}')",
+                        program.id()
+                    );
+                }
+                let version = dependency.and_then(|dependency| dependency.version()).cloned();
+                Ok(LockedDependency::new(*program.id(), version, checksum))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((resolved.into_values().collect(), dependencies))
+    }
+
+    /// Resolves the given import and its own imports, inserting them into `resolved` in topological order.
+    /// The `stack` contains the chain of programs currently being resolved, and is used to detect cycles.
+    fn resolve_import(
+        imports_directory: &Path,
+        program_id: &ProgramID<N>,
+        stack: &mut Vec<ProgramID<N>>,
+        resolved: &mut IndexMap<ProgramID<N>, Program<N>>,
+    ) -> Result<()> {
+        // If the import is already resolved, skip it.
+        if resolved.contains_key(program_id) {
+            return Ok(());
+        }
+        // Ensure the import does not form a cycle.
+        if stack.contains(program_id) {
+            let cycle = stack.iter().chain([program_id]).map(ToString::to_string).collect::<Vec<_>>();
+            bail!("Found a cycle in the program imports: {}", cycle.join(" -> "))
+        }
+
+        // Open the Aleo program file.
+        let import_program_file = AleoFile::open(imports_directory, program_id, false)?;
+        let program = import_program_file.program();
+        // Ensure the program ID matches.
+        ensure!(
+            program.id() == program_id,
+            "The program ID in the import file for '{program_id}' does not match ('{}')",
+            program.id()
+        );
+
+        // Resolve the imports of the imported program.
+        stack.push(*program_id);
+        for import_id in program.imports().keys() {
+            Self::resolve_import(imports_directory, import_id, stack, resolved)?;
+        }
+        stack.pop();
+
+        // Insert the imported program, after its own imports.
+        resolved.insert(*program_id, program.clone());
+        Ok(())
+    }
+
+    /// Ensures the given resolved dependencies match the lockfile.
+    fn check_lockfile(lockfile: &Lockfile<N>, dependencies: &[LockedDependency<N>]) -> Result<()> {
+        // Prepare the error suffix.
+        let suffix = format!("'{}' is out of date (rebuild with '--update-lockfile')", Lockfile::<N>::file_name());
+
+        // Ensure every resolved dependency is locked, with a matching checksum.
+        for dependency in dependencies {
+            match lockfile.dependencies().iter().find(|locked| locked.program_id() == dependency.program_id()) {
+                Some(locked) => ensure!(
+                    locked.checksum() == dependency.checksum(),
+                    "Checksum mismatch for '{}' (locked '{}', found '{}') - {suffix}",
+                    dependency.program_id(),
+                    locked.checksum(),
+                    dependency.checksum()
+                ),
+                None => bail!("Import '{}' is not locked - {suffix}", dependency.program_id()),
+            }
+        }
+        // Ensure every locked dependency is still imported.
+        for locked in lockfile.dependencies() {
+            ensure!(
+                dependencies.iter().any(|dependency| dependency.program_id() == locked.program_id()),
+                "Locked dependency '{}' is no longer imported - {suffix}",
+                locked.program_id()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+    use std::{fs::File, io::Write};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Writes a program with the given ID and imports to the given path.
+    fn write_program(path: &Path, program_id: &str, imports: &[&str]) {
+        let imports = imports.iter().map(|import| format!("import {import};\n")).collect::<String>();
+        let program_string = format!(
+            "{imports}
+program {program_id};
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;"
+        );
+        File::create(path).unwrap().write_all(program_string.as_bytes()).unwrap();
+    }
+
+    /// Samples a (temporary) package, where the imports map each program ID to its own imports.
+    fn sample_package(main_imports: &[&str], imports: &[(&str, &[&str])]) -> (PathBuf, Package<CurrentNetwork>) {
+        // Initialize a temporary directory.
+        let directory = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+
+        // Write the imported programs.
+        let imports_directory = directory.join("imports");
+        std::fs::create_dir_all(&imports_directory).unwrap();
+        for (program_id, program_imports) in imports {
+            write_program(&imports_directory.join(program_id), program_id, program_imports);
+        }
+
+        // Write the main program.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("main.aleo").unwrap();
+        write_program(&directory.join("main.aleo"), "main.aleo", main_imports);
+        // Create the manifest file.
+        let _manifest_file = Manifest::create(&directory, &program_id).unwrap();
+
+        // Open the package at the temporary directory.
+        let package = Package::<CurrentNetwork>::open(&directory).unwrap();
+        (directory, package)
+    }
+
+    #[test]
+    fn test_resolve_imports_in_topological_order() {
+        let (directory, package) = sample_package(&["b.aleo", "a.aleo"], &[
+            ("a.aleo", &["c.aleo"]),
+            ("b.aleo", &["a.aleo", "c.aleo"]),
+            ("c.aleo", &[]),
+        ]);
+
+        // Ensure the imports are resolved in topological order.
+        let imports = package.resolve_imports().unwrap();
+        let program_ids = imports.iter().map(|program| program.id().to_string()).collect::<Vec<_>>();
+        assert_eq!(program_ids, ["c.aleo", "a.aleo", "b.aleo"]);
+
+        // Ensure the process loads the transitive imports.
+        assert!(package.get_process().is_ok());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_imports_with_a_cycle() {
+        let (directory, package) = sample_package(&["a.aleo"], &[("a.aleo", &["b.aleo"]), ("b.aleo", &["a.aleo"])]);
+
+        // Ensure the cycle is detected.
+        let error = package.resolve_imports().unwrap_err().to_string();
+        assert!(error.contains("main.aleo -> a.aleo -> b.aleo -> a.aleo"), "{error}");
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_lockfile() {
+        let (directory, package) = sample_package(&["a.aleo"], &[("a.aleo", &[])]);
+
+        // Write the lockfile.
+        let lockfile = package.update_lockfile().unwrap();
+        assert_eq!(lockfile.dependencies().len(), 1);
+        assert_eq!(lockfile.dependencies()[0].program_id().to_string(), "a.aleo");
+
+        // Ensure the lockfile reads back identically.
+        let lockfile = Lockfile::<CurrentNetwork>::open(&directory).unwrap();
+        assert_eq!(lockfile.dependencies()[0].checksum().len(), 64);
+        assert!(package.resolve_imports().is_ok());
+
+        // Modify the import, and ensure the lockfile rejects it.
+        let import_path = directory.join("imports").join("a.aleo");
+        let program_string = std::fs::read_to_string(&import_path).unwrap().replace("add r0 r0", "mul r0 r0");
+        std::fs::write(&import_path, program_string).unwrap();
+        let error = package.resolve_imports().unwrap_err().to_string();
+        assert!(error.contains("Checksum mismatch for 'a.aleo'"), "{error}");
+
+        // Update the lockfile, and ensure the import resolves again.
+        package.update_lockfile().unwrap();
+        assert!(package.resolve_imports().is_ok());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
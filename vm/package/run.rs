@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_synthesizer::CallMetrics;
+use snarkvm_synthesizer::{CallMetrics, Trace};
 
 impl<N: Network> Package<N> {
     /// Runs a program function with the given inputs.
@@ -25,6 +25,39 @@ impl<N: Network> Package<N> {
         function_name: Identifier<N>,
         inputs: &[Value<N>],
         rng: &mut R,
+    ) -> Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        self.run_with_options::<A, R>(endpoint, private_key, function_name, inputs, None, false, rng)
+    }
+
+    /// Runs a program function with the given inputs, recording a trace of every evaluated instruction.
+    /// The trace is returned even if the run fails, and ends at the failing instruction, if any.
+    #[allow(clippy::type_complexity)]
+    pub fn run_with_trace<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        endpoint: Option<String>,
+        private_key: &PrivateKey<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> (Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)>, Trace<N>) {
+        let trace = Trace::new();
+        let result =
+            self.run_with_options::<A, R>(endpoint, private_key, function_name, inputs, Some(&trace), false, rng);
+        (result, trace)
+    }
+
+    /// Runs a program function with the given inputs, recording into the trace if it is given.
+    /// If `profile` is `true`, the circuit cost of each instruction is recorded into the call metrics.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub fn run_with_options<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        endpoint: Option<String>,
+        private_key: &PrivateKey<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        trace: Option<&Trace<N>>,
+        profile: bool,
+        rng: &mut R,
     ) -> Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
         // Retrieve the main program.
         let program = self.program();
@@ -94,8 +127,16 @@ impl<N: Network> Package<N> {
         // Adds the verifying key to the process.
         process.insert_verifying_key(program_id, &function_name, verifier.verifying_key().clone())?;
 
+        // Evaluate the function with tracing, so that a failing instruction is recorded before the circuit is synthesized.
+        if let Some(trace) = trace {
+            process.evaluate_with_trace::<A>(authorization.replicate(), trace)?;
+        }
+
         // Execute the circuit.
-        let (response, execution, inclusion, metrics) = process.execute::<A, R>(authorization, rng)?;
+        let (response, execution, inclusion, metrics) = match profile {
+            true => process.execute_with_profile::<A, R>(authorization, rng)?,
+            false => process.execute::<A, R>(authorization, rng)?,
+        };
 
         Ok((response, execution, inclusion, metrics))
     }
@@ -156,4 +197,32 @@ mod tests {
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
     }
+
+    #[test]
+    fn test_run_with_trace() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package_with_import();
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Sample the function inputs.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        // Run the program function.
+        let (result, trace) = package.run_with_trace::<CurrentAleo, _>(None, &private_key, function_name, &inputs, rng);
+        let (response, _execution, _inclusion, _metrics) = result.unwrap();
+
+        // Ensure every instruction was recorded, including those of the external call.
+        let entries = trace.entries();
+        assert!(!entries.is_empty());
+        assert!(entries.iter().all(|entry| entry.error().is_none()));
+        assert!(entries.iter().any(|entry| entry.depth() == 1 && entry.program_id() != package.program_id()));
+
+        // Ensure the output of the main function appears in the trace.
+        let outputs = entries.iter().filter(|entry| entry.depth() == 0).flat_map(|entry| entry.outputs());
+        assert!(outputs.map(|(_, value)| value).any(|value| response.outputs().contains(value)));
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
 }